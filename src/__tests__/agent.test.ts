@@ -322,6 +322,30 @@ describe("runAgentUnion", () => {
     expect(result.completionDetected).toBe(false);
   });
 
+  it("warns once that path policies are not enforced for process agents", async () => {
+    const options: UnionRunAgentOptions = {
+      config: {
+        kind: "process",
+        command: "sh",
+        args: ["-c", 'echo "<promise>COMPLETE</promise>"'],
+        completion_signal: "<promise>COMPLETE</promise>",
+      },
+      cwd: tempDir,
+      prompt: "test prompt",
+      logger: mockLogger,
+      onStdoutChunk: () => {},
+      pathPolicy: { deny: [".env"] },
+    };
+
+    await runAgentUnion(options);
+    await runAgentUnion(options);
+
+    expect(mockLogger.warn).toHaveBeenCalledTimes(1);
+    expect(mockLogger.warn).toHaveBeenCalledWith(
+      "path_policies are not enforced for process agents; their writes are not checked",
+    );
+  });
+
   it("dry-run mode works with claude_sdk kind", async () => {
     const config: AgentConfigUnion = {
       kind: "claude_sdk",
@@ -0,0 +1,112 @@
+import {
+  describe,
+  expect,
+  it,
+  beforeEach,
+  afterEach,
+  mock,
+  vi,
+} from "bun:test";
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import * as os from "node:os";
+import { DEFAULT_CONFIG, type ConfigResolved } from "../config";
+import { BudgetExceededError } from "../errors";
+import type { Logger } from "../logging";
+import type { Item } from "../schemas";
+
+// A failed run that used a million input tokens: $1 at the prices below
+mock.module("../agent/claude-sdk-runner", () => ({
+  runClaudeSdkAgent: async () => ({
+    success: false,
+    output: "Tests failed",
+    timedOut: false,
+    exitCode: 1,
+    completionDetected: false,
+    usage: {
+      input_tokens: 1_000_000,
+      output_tokens: 0,
+      cache_read_tokens: 0,
+      cache_write_tokens: 0,
+      model: "test-model",
+    },
+  }),
+}));
+
+const { runPhaseResearch } = await import("../workflow");
+
+function createMockLogger() {
+  return {
+    debug: vi.fn(),
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    json: vi.fn(),
+  } satisfies Logger;
+}
+
+const ITEM: Item = {
+  schema_version: 1,
+  id: "001-test",
+  title: "Test",
+  state: "idea",
+  overview: "A test item",
+  branch: null,
+  pr_url: null,
+  pr_number: null,
+  last_error: null,
+  created_at: "2025-01-12T00:00:00Z",
+  updated_at: "2025-01-12T00:00:00Z",
+  rollback_sha: null,
+};
+
+describe("budgets across a recorded phase", () => {
+  let root: string;
+
+  beforeEach(async () => {
+    root = await fs.mkdtemp(path.join(os.tmpdir(), "wreckit-budget-test-"));
+    const itemDir = path.join(root, ".wreckit", "items", ITEM.id);
+    await fs.mkdir(itemDir, { recursive: true });
+    await fs.writeFile(
+      path.join(itemDir, "item.json"),
+      JSON.stringify(ITEM, null, 2),
+    );
+    await Bun.$`cd ${root} && git init -q`.quiet();
+  });
+
+  afterEach(async () => {
+    await fs.rm(root, { recursive: true, force: true });
+  });
+
+  function makeConfig(perPhaseUsd: number): ConfigResolved {
+    return {
+      ...DEFAULT_CONFIG,
+      prices: { "test-model": { input: 1, output: 1 } },
+      budget: { per_phase_usd: perPhaseUsd },
+    };
+  }
+
+  it("counts the last run once against the phase cap", async () => {
+    const result = await runPhaseResearch(ITEM.id, {
+      root,
+      config: makeConfig(1.5),
+      logger: createMockLogger(),
+      noHealing: true,
+    });
+
+    expect(result.success).toBe(false);
+    expect(result.error).toContain("exit code 1");
+    expect(result.item.usage?.research?.cost_usd).toBe(1);
+  });
+
+  it("fails with BUDGET_EXCEEDED once the run reaches the cap", async () => {
+    await expect(
+      runPhaseResearch(ITEM.id, {
+        root,
+        config: makeConfig(0.9),
+        logger: createMockLogger(),
+        noHealing: true,
+      }),
+    ).rejects.toBeInstanceOf(BudgetExceededError);
+  });
+});
@@ -0,0 +1,213 @@
+import { describe, expect, it, beforeEach, afterEach, vi } from "bun:test";
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import * as os from "node:os";
+import { approveCommand, rejectCommand } from "../../commands/approve";
+import { getNextIncompleteItem } from "../../commands/orchestrator";
+import { readHistory } from "../../fs/history";
+import {
+  applyApprovalDecision,
+  formatReviewFeedback,
+  getOutstandingRejections,
+  requiresApproval,
+} from "../../domain";
+import { WreckitError } from "../../errors";
+import type { Logger } from "../../logging";
+import type { Item } from "../../schemas";
+
+function createMockLogger() {
+  return {
+    debug: vi.fn(),
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    json: vi.fn(),
+  } satisfies Logger;
+}
+
+function makeItem(overrides: Partial<Item> = {}): Item {
+  return {
+    schema_version: 1,
+    id: "001-test",
+    title: "test",
+    state: "planned",
+    overview: "",
+    branch: null,
+    pr_url: null,
+    pr_number: null,
+    last_error: null,
+    created_at: "2025-01-01T00:00:00Z",
+    updated_at: "2025-01-01T00:00:00Z",
+    ...overrides,
+  };
+}
+
+async function writeTestItem(root: string, item: Item): Promise<string> {
+  const itemDir = path.join(root, ".wreckit", "items", item.id);
+  await fs.mkdir(itemDir, { recursive: true });
+  await fs.writeFile(
+    path.join(itemDir, "item.json"),
+    JSON.stringify(item, null, 2),
+  );
+  return itemDir;
+}
+
+async function readTestItem(root: string, id: string): Promise<Item> {
+  const content = await fs.readFile(
+    path.join(root, ".wreckit", "items", id, "item.json"),
+    "utf-8",
+  );
+  return JSON.parse(content);
+}
+
+describe("approval gates", () => {
+  it("default to auto", () => {
+    expect(requiresApproval({}, "plan")).toBe(false);
+    expect(requiresApproval({ gates: { plan: "human" } }, "plan")).toBe(true);
+    expect(requiresApproval({ gates: { plan: "human" } }, "research")).toBe(
+      false,
+    );
+  });
+
+  it("applyApprovalDecision requires a pending gate and a rejection comment", () => {
+    expect(
+      applyApprovalDecision(makeItem(), "approved", { by: "a" }).error,
+    ).toBeDefined();
+
+    const pending = makeItem({ pending_approval: "plan" });
+    expect(
+      applyApprovalDecision(pending, "rejected", { by: "a", comment: " " })
+        .error,
+    ).toBeDefined();
+
+    const result = applyApprovalDecision(pending, "approved", {
+      by: "alex",
+      now: "2025-01-02T00:00:00Z",
+    });
+    expect(result.nextItem?.pending_approval).toBeNull();
+    expect(result.nextItem?.approvals).toEqual([
+      {
+        phase: "plan",
+        decision: "approved",
+        by: "alex",
+        at: "2025-01-02T00:00:00Z",
+        comment: null,
+      },
+    ]);
+  });
+
+  it("only rejections since the last approval are outstanding", () => {
+    const item = makeItem({
+      approvals: [
+        { phase: "plan", decision: "rejected", by: "a", at: "t1", comment: "x" },
+        { phase: "plan", decision: "approved", by: "a", at: "t2" },
+        { phase: "plan", decision: "rejected", by: "b", at: "t3", comment: "y" },
+        {
+          phase: "research",
+          decision: "rejected",
+          by: "b",
+          at: "t4",
+          comment: "z",
+        },
+      ],
+    });
+
+    expect(
+      getOutstandingRejections(item, "plan").map((a) => a.comment),
+    ).toEqual(["y"]);
+    expect(formatReviewFeedback(item, "plan")).toBe("- y — b, t3");
+    expect(formatReviewFeedback(makeItem(), "plan")).toBeUndefined();
+  });
+});
+
+describe("approveCommand / rejectCommand", () => {
+  let tempDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(
+      path.join(os.tmpdir(), "wreckit-approve-test-"),
+    );
+    await fs.mkdir(path.join(tempDir, ".git"), { recursive: true });
+  });
+
+  afterEach(async () => {
+    await fs.rm(tempDir, { recursive: true, force: true });
+  });
+
+  it("approves a pending plan and records history", async () => {
+    await writeTestItem(tempDir, makeItem({ pending_approval: "plan" }));
+    const logger = createMockLogger();
+
+    await approveCommand(
+      "001-test",
+      { by: "alex", comment: "ship it", cwd: tempDir },
+      logger,
+    );
+
+    const item = await readTestItem(tempDir, "001-test");
+    expect(item.state).toBe("planned");
+    expect(item.pending_approval).toBeNull();
+    expect(item.approvals?.[0]).toMatchObject({
+      phase: "plan",
+      decision: "approved",
+      by: "alex",
+      comment: "ship it",
+    });
+
+    const history = await readHistory(tempDir, "001-test");
+    expect(history.at(-1)).toMatchObject({
+      event: "approved",
+      actor: "alex",
+      phase: "plan",
+    });
+  });
+
+  it("rejects a plan back to researched and archives it", async () => {
+    const itemDir = await writeTestItem(
+      tempDir,
+      makeItem({ pending_approval: "plan" }),
+    );
+    await fs.writeFile(path.join(itemDir, "research.md"), "# Research");
+    await fs.writeFile(path.join(itemDir, "plan.md"), "# Plan");
+    const logger = createMockLogger();
+
+    const result = await rejectCommand(
+      "001-test",
+      { by: "alex", comment: "Stories are too coarse", cwd: tempDir },
+      logger,
+    );
+
+    expect(result.state).toBe("researched");
+    const item = await readTestItem(tempDir, "001-test");
+    expect(item.state).toBe("researched");
+    expect(item.pending_approval).toBeNull();
+    expect(formatReviewFeedback(item, "plan")).toContain(
+      "Stories are too coarse",
+    );
+    await expect(fs.access(path.join(itemDir, "plan.md"))).rejects.toThrow();
+    await expect(
+      fs.access(path.join(itemDir, "research.md")),
+    ).resolves.toBeUndefined();
+
+    const events = (await readHistory(tempDir, "001-test")).map((e) => e.event);
+    expect(events).toEqual(["rejected", "transition"]);
+  });
+
+  it("refuses to approve items that are not gated", async () => {
+    await writeTestItem(tempDir, makeItem());
+
+    await expect(
+      approveCommand("001-test", { cwd: tempDir }, createMockLogger()),
+    ).rejects.toThrow(WreckitError);
+  });
+
+  it("getNextIncompleteItem skips items awaiting approval", async () => {
+    await writeTestItem(
+      tempDir,
+      makeItem({ id: "001-gated", pending_approval: "plan" }),
+    );
+    await writeTestItem(tempDir, makeItem({ id: "002-ready", state: "idea" }));
+
+    expect(await getNextIncompleteItem(tempDir)).toBe("002-ready");
+  });
+});
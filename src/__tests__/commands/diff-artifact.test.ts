@@ -0,0 +1,197 @@
+import { describe, expect, it, beforeEach, afterEach, vi } from "bun:test";
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import * as os from "node:os";
+import { diffArtifactCommand } from "../../commands/diff-artifact";
+import {
+  listRevisions,
+  readRevision,
+  recordRevision,
+} from "../../fs/revisions";
+import { diffLines, diffPrd, formatUnifiedDiff } from "../../domain";
+import { WreckitError } from "../../errors";
+import type { Logger } from "../../logging";
+import type { Item, Prd, Story } from "../../schemas";
+
+function createMockLogger() {
+  return {
+    debug: vi.fn(),
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    json: vi.fn(),
+  } satisfies Logger;
+}
+
+function makeItem(): Item {
+  return {
+    schema_version: 1,
+    id: "001-test",
+    title: "test",
+    state: "planned",
+    overview: "",
+    branch: null,
+    pr_url: null,
+    pr_number: null,
+    last_error: null,
+    created_at: "2025-01-01T00:00:00Z",
+    updated_at: "2025-01-01T00:00:00Z",
+  };
+}
+
+function makeStory(overrides: Partial<Story> = {}): Story {
+  return {
+    id: "US-001",
+    title: "Story one",
+    acceptance_criteria: ["AC 1"],
+    priority: 1,
+    status: "pending",
+    notes: "",
+    ...overrides,
+  };
+}
+
+function makePrd(stories: Story[]): Prd {
+  return {
+    schema_version: 1,
+    id: "001-test",
+    branch_name: "wreckit/001-test",
+    user_stories: stories,
+  };
+}
+
+describe("artifact diffs", () => {
+  it("diffLines keeps common lines and marks changes", () => {
+    expect(diffLines("a\nb\nc", "a\nx\nc")).toEqual([
+      { op: " ", text: "a" },
+      { op: "-", text: "b" },
+      { op: "+", text: "x" },
+      { op: " ", text: "c" },
+    ]);
+  });
+
+  it("formatUnifiedDiff renders hunks with line numbers", () => {
+    const before = ["1", "2", "3", "4", "5", "6", "7", "8"].join("\n");
+    const after = ["1", "2", "3", "4", "five", "6", "7", "8"].join("\n");
+
+    expect(formatUnifiedDiff(diffLines(before, after), "r1", "r2", 1)).toEqual(
+      ["--- r1", "+++ r2", "@@ -4,3 +4,3 @@", " 4", "-5", "+five", " 6"],
+    );
+    expect(formatUnifiedDiff(diffLines(before, before), "r1", "r2")).toEqual(
+      [],
+    );
+  });
+
+  it("diffPrd reports added, removed and changed stories", () => {
+    const before = makePrd([
+      makeStory(),
+      makeStory({ id: "US-002", title: "Story two" }),
+    ]);
+    const after = makePrd([
+      makeStory({
+        acceptance_criteria: ["AC 1 revised"],
+        priority: 2,
+        status: "done",
+      }),
+      makeStory({ id: "US-003", title: "Story three" }),
+    ]);
+
+    const diff = diffPrd(before, after);
+    expect(diff.added.map((s) => s.id)).toEqual(["US-003"]);
+    expect(diff.removed.map((s) => s.id)).toEqual(["US-002"]);
+    expect(diff.changed).toEqual([
+      {
+        id: "US-001",
+        title: "Story one",
+        fields: [{ field: "priority", from: "1", to: "2" }],
+        criteriaAdded: ["AC 1 revised"],
+        criteriaRemoved: ["AC 1"],
+      },
+    ]);
+  });
+});
+
+describe("revisions and diffArtifactCommand", () => {
+  let tempDir: string;
+  let itemDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), "wreckit-diff-test-"));
+    await fs.mkdir(path.join(tempDir, ".git"), { recursive: true });
+    itemDir = path.join(tempDir, ".wreckit", "items", "001-test");
+    await fs.mkdir(itemDir, { recursive: true });
+    await fs.writeFile(
+      path.join(itemDir, "item.json"),
+      JSON.stringify(makeItem(), null, 2),
+    );
+  });
+
+  afterEach(async () => {
+    await fs.rm(tempDir, { recursive: true, force: true });
+  });
+
+  it("records a revision only when the artifact changed", async () => {
+    expect(await recordRevision(tempDir, "001-test", "plan")).toBeNull();
+
+    await fs.writeFile(path.join(itemDir, "plan.md"), "# Plan v1");
+    expect(await recordRevision(tempDir, "001-test", "plan")).toBe(1);
+    expect(await recordRevision(tempDir, "001-test", "plan")).toBeNull();
+
+    await fs.writeFile(path.join(itemDir, "plan.md"), "# Plan v2");
+    expect(await recordRevision(tempDir, "001-test", "plan")).toBe(2);
+
+    expect(await listRevisions(tempDir, "001-test", "plan")).toEqual([1, 2]);
+    expect(await readRevision(tempDir, "001-test", "plan", 1)).toBe(
+      "# Plan v1",
+    );
+  });
+
+  it("diffs the latest two plan revisions by default", async () => {
+    await fs.writeFile(path.join(itemDir, "plan.md"), "# Plan\nold step");
+    await recordRevision(tempDir, "001-test", "plan");
+    await fs.writeFile(path.join(itemDir, "plan.md"), "# Plan\nnew step");
+    await recordRevision(tempDir, "001-test", "plan");
+
+    const logger = createMockLogger();
+    await diffArtifactCommand("001-test", "plan", { cwd: tempDir }, logger);
+
+    const lines = logger.info.mock.calls.map((call) => call[0]);
+    expect(lines).toContain("-old step");
+    expect(lines).toContain("+new step");
+  });
+
+  it("diffs PRD revisions story by story", async () => {
+    const prdPath = path.join(itemDir, "prd.json");
+    await fs.writeFile(prdPath, JSON.stringify(makePrd([makeStory()])));
+    await recordRevision(tempDir, "001-test", "prd");
+    await fs.writeFile(
+      prdPath,
+      JSON.stringify(
+        makePrd([makeStory({ acceptance_criteria: ["AC 1", "AC 2"] })]),
+      ),
+    );
+    await recordRevision(tempDir, "001-test", "prd");
+
+    const logger = createMockLogger();
+    await diffArtifactCommand(
+      "001-test",
+      "prd",
+      { from: 1, to: 2, cwd: tempDir },
+      logger,
+    );
+
+    const lines = logger.info.mock.calls.map((call) => call[0]);
+    expect(lines).toContain("~ US-001: Story one");
+    expect(lines).toContain("    + AC 2");
+  });
+
+  it("rejects unknown artifacts and missing revisions", async () => {
+    const logger = createMockLogger();
+    await expect(
+      diffArtifactCommand("001-test", "notes", { cwd: tempDir }, logger),
+    ).rejects.toThrow(WreckitError);
+    await expect(
+      diffArtifactCommand("001-test", "plan", { cwd: tempDir }, logger),
+    ).rejects.toThrow(WreckitError);
+  });
+});
@@ -0,0 +1,165 @@
+import { describe, expect, it, beforeEach, afterEach, vi } from "bun:test";
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import * as os from "node:os";
+import { logCommand, formatHistoryEntry } from "../../commands/log";
+import { appendHistory, readHistory } from "../../fs/history";
+import { createAdvanceEntries } from "../../domain";
+import type { Logger } from "../../logging";
+import type { Item } from "../../schemas";
+
+function createMockLogger() {
+  return {
+    debug: vi.fn(),
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    json: vi.fn(),
+  } satisfies Logger;
+}
+
+function makeItem(overrides: Partial<Item> = {}): Item {
+  return {
+    schema_version: 1,
+    id: "001-test",
+    title: "test",
+    state: "idea",
+    overview: "",
+    branch: null,
+    pr_url: null,
+    pr_number: null,
+    last_error: null,
+    created_at: "2025-01-01T00:00:00Z",
+    updated_at: "2025-01-01T00:00:00Z",
+    ...overrides,
+  };
+}
+
+describe("item history", () => {
+  let tempDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), "wreckit-log-test-"));
+    await fs.mkdir(path.join(tempDir, ".git"), { recursive: true });
+    const itemDir = path.join(tempDir, ".wreckit", "items", "001-test");
+    await fs.mkdir(itemDir, { recursive: true });
+    await fs.writeFile(
+      path.join(itemDir, "item.json"),
+      JSON.stringify(makeItem(), null, 2),
+    );
+  });
+
+  afterEach(async () => {
+    await fs.rm(tempDir, { recursive: true, force: true });
+  });
+
+  it("appends entries and reads them back in order", async () => {
+    await appendHistory(tempDir, "001-test", {
+      event: "phase_started",
+      actor: "claude_sdk",
+      phase: "research",
+    });
+    await appendHistory(tempDir, "001-test", {
+      event: "transition",
+      actor: "claude_sdk",
+      from: "idea",
+      to: "researched",
+    });
+
+    const history = await readHistory(tempDir, "001-test");
+    expect(history.map((e) => e.event)).toEqual(["phase_started", "transition"]);
+    expect(history[1].to).toBe("researched");
+    expect(typeof history[0].ts).toBe("string");
+  });
+
+  it("skips malformed lines", async () => {
+    const historyPath = path.join(
+      tempDir,
+      ".wreckit",
+      "items",
+      "001-test",
+      "history.jsonl",
+    );
+    await fs.writeFile(
+      historyPath,
+      '{"ts":"2025-01-01T00:00:00Z","event":"transition","actor":"human"}\n{"ts":\n',
+    );
+
+    expect(await readHistory(tempDir, "001-test")).toHaveLength(1);
+  });
+
+  it("returns an empty history for new items", async () => {
+    expect(await readHistory(tempDir, "001-test")).toEqual([]);
+  });
+
+  it("records each state a phase advances an item past", () => {
+    const entries = createAdvanceEntries("planned", "critique", "claude_sdk");
+
+    expect(entries.map((e) => [e.from, e.to])).toEqual([
+      ["planned", "implementing"],
+      ["implementing", "critique"],
+    ]);
+    expect(entries.every((e) => e.actor === "claude_sdk")).toBe(true);
+  });
+
+  it("formats entries as timeline lines", () => {
+    expect(
+      formatHistoryEntry({
+        ts: "2025-01-01T10:00:00.000Z",
+        event: "phase_failed",
+        actor: "claude_sdk",
+        phase: "plan",
+        duration_ms: 125_000,
+        error: "Plan too short",
+        error_code: "PLAN_QUALITY",
+      }),
+    ).toBe(
+      "2025-01-01 10:00:00Z  ✗ plan failed in 2m 5s [PLAN_QUALITY]: Plan too short",
+    );
+    expect(
+      formatHistoryEntry({
+        ts: "2025-01-01T10:00:00.000Z",
+        event: "agent_fallback",
+        actor: "system",
+        phase: "implement",
+        agent: "codex_sdk/codex-1",
+        reason: "claude_sdk rate_limit",
+      }),
+    ).toBe(
+      "2025-01-01 10:00:00Z  ↪ implement fell back to codex_sdk/codex-1: claude_sdk rate_limit",
+    );
+  });
+
+  it("logCommand prints the timeline", async () => {
+    await appendHistory(tempDir, "001-test", {
+      ts: "2025-01-01T10:00:00.000Z",
+      event: "transition",
+      actor: "human",
+      from: "critique",
+      to: "planned",
+      reason: "too complex",
+    });
+
+    const logger = createMockLogger();
+    await logCommand("001-test", { cwd: tempDir }, logger);
+
+    const lines = logger.info.mock.calls.map((call) => call[0]);
+    expect(lines).toContain(
+      "2025-01-01 10:00:00Z  → critique → planned (human): too complex",
+    );
+  });
+
+  it("logCommand outputs JSON when requested", async () => {
+    await appendHistory(tempDir, "001-test", {
+      event: "phase_started",
+      actor: "claude_sdk",
+      phase: "research",
+    });
+
+    const logger = createMockLogger();
+    await logCommand("001-test", { cwd: tempDir, json: true }, logger);
+
+    expect(logger.json).toHaveBeenCalledTimes(1);
+    expect(logger.json.mock.calls[0][0]).toHaveLength(1);
+  });
+});
@@ -0,0 +1,165 @@
+import { describe, expect, it, beforeEach, afterEach, vi } from "bun:test";
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import * as os from "node:os";
+import { parkCommand, resumeCommand } from "../../commands/park";
+import { getNextIncompleteItem } from "../../commands/orchestrator";
+import { readHistory } from "../../fs/history";
+import {
+  applyPark,
+  applyResume,
+  isActionableState,
+  validatePipelineConfig,
+} from "../../domain";
+import { WreckitError } from "../../errors";
+import type { Logger } from "../../logging";
+import type { Item } from "../../schemas";
+
+function createMockLogger() {
+  return {
+    debug: vi.fn(),
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    json: vi.fn(),
+  } satisfies Logger;
+}
+
+function makeItem(overrides: Partial<Item> = {}): Item {
+  return {
+    schema_version: 1,
+    id: "001-test",
+    title: "test",
+    state: "planned",
+    overview: "",
+    branch: null,
+    pr_url: null,
+    pr_number: null,
+    last_error: null,
+    created_at: "2025-01-01T00:00:00Z",
+    updated_at: "2025-01-01T00:00:00Z",
+    ...overrides,
+  };
+}
+
+async function writeTestItem(root: string, item: Item): Promise<void> {
+  const itemDir = path.join(root, ".wreckit", "items", item.id);
+  await fs.mkdir(itemDir, { recursive: true });
+  await fs.writeFile(
+    path.join(itemDir, "item.json"),
+    JSON.stringify(item, null, 2),
+  );
+}
+
+async function readTestItem(root: string, id: string): Promise<Item> {
+  const content = await fs.readFile(
+    path.join(root, ".wreckit", "items", id, "item.json"),
+    "utf-8",
+  );
+  return JSON.parse(content);
+}
+
+describe("parked states", () => {
+  it("are not actionable", () => {
+    expect(isActionableState("blocked")).toBe(false);
+    expect(isActionableState("paused")).toBe(false);
+    expect(isActionableState("wontfix")).toBe(false);
+    expect(isActionableState("done")).toBe(false);
+    expect(isActionableState("planned")).toBe(true);
+  });
+
+  it("cannot appear in pipelines", () => {
+    const errors = validatePipelineConfig("bad", {
+      stages: [
+        { state: "idea" },
+        { state: "blocked", phase: "research" },
+        { state: "done", phase: "complete" },
+      ],
+    });
+    expect(errors.some((e) => e.includes("parked state 'blocked'"))).toBe(true);
+  });
+
+  it("applyPark remembers the state to resume to", () => {
+    const blocked = applyPark(makeItem(), "blocked", {
+      reason: "waiting on legal",
+      unblockCondition: "contract signed",
+    });
+    expect(blocked.nextItem).toMatchObject({
+      state: "blocked",
+      parked_from: "planned",
+      parked_reason: "waiting on legal",
+      unblock_condition: "contract signed",
+    });
+
+    const paused = applyPark(blocked.nextItem!, "paused");
+    expect(paused.nextItem?.parked_from).toBe("planned");
+    expect(paused.nextItem?.unblock_condition).toBeNull();
+
+    const resumed = applyResume(paused.nextItem!);
+    expect(resumed.nextItem?.state).toBe("planned");
+    expect(resumed.nextItem?.parked_from).toBeNull();
+  });
+
+  it("applyPark rejects done items and non-parked targets", () => {
+    expect(applyPark(makeItem({ state: "done" }), "wontfix").error).toBeDefined();
+    expect(applyPark(makeItem(), "planned").error).toBeDefined();
+    expect(applyResume(makeItem()).error).toBeDefined();
+  });
+});
+
+describe("parkCommand / resumeCommand", () => {
+  let tempDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), "wreckit-park-test-"));
+    await fs.mkdir(path.join(tempDir, ".git"), { recursive: true });
+  });
+
+  afterEach(async () => {
+    await fs.rm(tempDir, { recursive: true, force: true });
+  });
+
+  it("blocks and resumes an item, recording history", async () => {
+    await writeTestItem(tempDir, makeItem());
+    const logger = createMockLogger();
+
+    await parkCommand(
+      "001-test",
+      "blocked",
+      { reason: "needs API key", until: "key issued", cwd: tempDir },
+      logger,
+    );
+    expect((await readTestItem(tempDir, "001-test")).state).toBe("blocked");
+
+    await resumeCommand("001-test", { cwd: tempDir }, logger);
+    expect((await readTestItem(tempDir, "001-test")).state).toBe("planned");
+
+    const history = await readHistory(tempDir, "001-test");
+    expect(history.map((e) => [e.from, e.to])).toEqual([
+      ["planned", "blocked"],
+      ["blocked", "planned"],
+    ]);
+  });
+
+  it("requires a reason for blocked and wontfix", async () => {
+    await writeTestItem(tempDir, makeItem());
+    const logger = createMockLogger();
+
+    await expect(
+      parkCommand("001-test", "wontfix", { cwd: tempDir }, logger),
+    ).rejects.toThrow(WreckitError);
+    await expect(
+      parkCommand("001-test", "paused", { cwd: tempDir }, logger),
+    ).resolves.toMatchObject({ state: "paused" });
+  });
+
+  it("getNextIncompleteItem skips parked items", async () => {
+    await writeTestItem(
+      tempDir,
+      makeItem({ id: "001-blocked", state: "blocked", parked_from: "idea" }),
+    );
+    await writeTestItem(tempDir, makeItem({ id: "002-ready", state: "idea" }));
+
+    expect(await getNextIncompleteItem(tempDir)).toBe("002-ready");
+  });
+});
@@ -0,0 +1,225 @@
+import { describe, expect, it, beforeEach, afterEach, vi } from "bun:test";
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import * as os from "node:os";
+import { reopenCommand } from "../../commands/reopen";
+import { applyRegression, validateRegression } from "../../domain";
+import { TransitionError, WreckitError } from "../../errors";
+import type { Logger } from "../../logging";
+import type { Item, Prd } from "../../schemas";
+
+function createMockLogger() {
+  return {
+    debug: vi.fn(),
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    json: vi.fn(),
+  } satisfies Logger;
+}
+
+function makeItem(overrides: Partial<Item> = {}): Item {
+  return {
+    schema_version: 1,
+    id: "001-test",
+    title: "test",
+    state: "critique",
+    overview: "Test overview",
+    branch: "wreckit/001-test",
+    pr_url: null,
+    pr_number: null,
+    last_error: null,
+    created_at: "2025-01-01T00:00:00Z",
+    updated_at: "2025-01-01T00:00:00Z",
+    ...overrides,
+  };
+}
+
+const PRD: Prd = {
+  schema_version: 1,
+  id: "001-test",
+  branch_name: "wreckit/001-test",
+  user_stories: [
+    {
+      id: "US-001",
+      title: "Story one",
+      acceptance_criteria: ["AC"],
+      priority: 1,
+      status: "done",
+      notes: "",
+    },
+    {
+      id: "US-002",
+      title: "Story two",
+      acceptance_criteria: ["AC"],
+      priority: 2,
+      status: "done",
+      notes: "",
+    },
+  ],
+};
+
+async function createItem(
+  root: string,
+  overrides: Partial<Item> = {},
+): Promise<string> {
+  const item = makeItem(overrides);
+  const itemDir = path.join(root, ".wreckit", "items", item.id);
+  await fs.mkdir(itemDir, { recursive: true });
+  await fs.writeFile(
+    path.join(itemDir, "item.json"),
+    JSON.stringify(item, null, 2),
+  );
+  await fs.writeFile(path.join(itemDir, "research.md"), "# Research");
+  await fs.writeFile(path.join(itemDir, "plan.md"), "# Plan");
+  await fs.writeFile(
+    path.join(itemDir, "prd.json"),
+    JSON.stringify(PRD, null, 2),
+  );
+  return itemDir;
+}
+
+async function readJson<T>(file: string): Promise<T> {
+  return JSON.parse(await fs.readFile(file, "utf-8")) as T;
+}
+
+describe("backward transitions", () => {
+  it("validateRegression only allows earlier states", () => {
+    expect(validateRegression("critique", "planned").valid).toBe(true);
+    expect(validateRegression("critique", "in_pr").valid).toBe(false);
+    expect(validateRegression("idea", "idea").valid).toBe(false);
+  });
+
+  it("applyRegression records the reason and clears completion metadata", () => {
+    const result = applyRegression(
+      makeItem({
+        state: "done",
+        completed_at: "2025-01-02T00:00:00Z",
+        merged_at: "2025-01-02T00:00:00Z",
+      }),
+      "researched",
+      { reason: "wrong approach", actor: "human", now: "2025-01-03T00:00:00Z" },
+    );
+
+    expect(result.nextItem?.state).toBe("researched");
+    expect(result.nextItem?.completed_at).toBeNull();
+    expect(result.nextItem?.merged_at).toBeNull();
+    expect(result.nextItem?.regressions).toEqual([
+      {
+        from: "done",
+        to: "researched",
+        reason: "wrong approach",
+        actor: "human",
+        at: "2025-01-03T00:00:00Z",
+        archive_dir: null,
+      },
+    ]);
+  });
+});
+
+describe("reopenCommand", () => {
+  let tempDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), "wreckit-reopen-test-"));
+    await fs.mkdir(path.join(tempDir, ".git"), { recursive: true });
+  });
+
+  afterEach(async () => {
+    await fs.rm(tempDir, { recursive: true, force: true });
+  });
+
+  it("archives the plan when reopening to researched", async () => {
+    const itemDir = await createItem(tempDir);
+
+    const result = await reopenCommand(
+      "001-test",
+      { to: "researched", reason: "plan missed the migration", cwd: tempDir },
+      createMockLogger(),
+    );
+
+    expect(result.item.state).toBe("researched");
+    expect(result.archivedFiles).toEqual(["plan.md", "prd.json"]);
+    expect(result.archiveDir).not.toBeNull();
+
+    const archived = path.join(itemDir, result.archiveDir!);
+    expect(await fs.readFile(path.join(archived, "plan.md"), "utf-8")).toBe(
+      "# Plan",
+    );
+    await expect(fs.access(path.join(itemDir, "plan.md"))).rejects.toThrow();
+    await expect(
+      fs.access(path.join(itemDir, "research.md")),
+    ).resolves.toBeUndefined();
+
+    const item = await readJson<Item>(path.join(itemDir, "item.json"));
+    expect(item.state).toBe("researched");
+    expect(item.regressions?.[0].reason).toBe("plan missed the migration");
+
+    const log = await fs.readFile(path.join(itemDir, "progress.log"), "utf-8");
+    expect(log).toContain("REOPENED (human): critique → researched");
+  });
+
+  it("resets stories when reopening to planned", async () => {
+    const itemDir = await createItem(tempDir);
+
+    const result = await reopenCommand(
+      "001-test",
+      { to: "planned", reason: "too complex", cwd: tempDir },
+      createMockLogger(),
+    );
+
+    expect(result.storiesReset).toBe(2);
+    const prd = await readJson<Prd>(path.join(itemDir, "prd.json"));
+    expect(prd.user_stories.every((s) => s.status === "pending")).toBe(true);
+    const archivedPrd = await readJson<Prd>(
+      path.join(itemDir, result.archiveDir!, "prd.json"),
+    );
+    expect(archivedPrd.user_stories.every((s) => s.status === "done")).toBe(
+      true,
+    );
+  });
+
+  it("rejects forward targets", async () => {
+    await createItem(tempDir, { state: "planned" });
+
+    await expect(
+      reopenCommand(
+        "001-test",
+        { to: "in_pr", reason: "skip ahead", cwd: tempDir },
+        createMockLogger(),
+      ),
+    ).rejects.toThrow(TransitionError);
+  });
+
+  it("rejects unknown states and empty reasons", async () => {
+    await createItem(tempDir);
+    const logger = createMockLogger();
+
+    await expect(
+      reopenCommand("001-test", { to: "bogus", reason: "x", cwd: tempDir }, logger),
+    ).rejects.toThrow(WreckitError);
+    await expect(
+      reopenCommand(
+        "001-test",
+        { to: "planned", reason: "  ", cwd: tempDir },
+        logger,
+      ),
+    ).rejects.toThrow("reason is required");
+  });
+
+  it("does not modify anything in dry-run mode", async () => {
+    const itemDir = await createItem(tempDir);
+
+    await reopenCommand(
+      "001-test",
+      { to: "researched", reason: "check", cwd: tempDir, dryRun: true },
+      createMockLogger(),
+    );
+
+    const item = await readJson<Item>(path.join(itemDir, "item.json"));
+    expect(item.state).toBe("critique");
+    await expect(
+      fs.access(path.join(itemDir, "plan.md")),
+    ).resolves.toBeUndefined();
+  });
+});
@@ -0,0 +1,108 @@
+import { describe, expect, it, beforeEach, afterEach, vi } from "bun:test";
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import * as os from "node:os";
+import {
+  routingCommand,
+  getPhaseRouting,
+  formatPhaseRoute,
+} from "../../commands/routing";
+import { mergeWithDefaults } from "../../config";
+import type { Logger } from "../../logging";
+
+function createMockLogger() {
+  return {
+    debug: vi.fn(),
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    json: vi.fn(),
+  } satisfies Logger;
+}
+
+const CHEAP = { kind: "claude_sdk", model: "claude-haiku", max_tokens: 4096 };
+const STRONG = { kind: "claude_sdk", model: "claude-opus", max_tokens: 8192 };
+
+describe("phase routing", () => {
+  let tempDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), "wreckit-routing-"));
+    await fs.mkdir(path.join(tempDir, ".git"));
+    await fs.mkdir(path.join(tempDir, ".wreckit"));
+    await fs.writeFile(
+      path.join(tempDir, ".wreckit", "config.json"),
+      JSON.stringify({
+        agent: CHEAP,
+        phase_agents: {
+          plan: STRONG,
+          implement: [STRONG, { kind: "codex_sdk", model: "codex-1" }],
+        },
+        phases: { pr: { agent: { kind: "amp_sdk" } } },
+      }),
+    );
+  });
+
+  afterEach(async () => {
+    await fs.rm(tempDir, { recursive: true, force: true });
+  });
+
+  it("routes each phase to its configured agent", () => {
+    const config = mergeWithDefaults({
+      agent: CHEAP as any,
+      phase_agents: { plan: STRONG as any },
+      phases: {
+        plan: { timeout_seconds: 60 },
+        pr: { agent: { kind: "amp_sdk" } },
+      },
+    });
+
+    const routing = getPhaseRouting(config);
+    const byPhase = Object.fromEntries(routing.map((r) => [r.phase, r]));
+
+    expect(routing.map((r) => r.phase)).not.toContain("complete");
+    expect(byPhase.research).toMatchObject({
+      agents: ["claude_sdk/claude-haiku"],
+      source: "agent",
+    });
+    expect(byPhase.plan).toMatchObject({
+      agents: ["claude_sdk/claude-opus"],
+      source: "phase_agents.plan",
+      timeout_seconds: 60,
+      max_iterations: 3,
+    });
+    expect(byPhase.pr).toMatchObject({
+      agents: ["amp_sdk"],
+      source: "phases.pr.agent",
+    });
+    expect(formatPhaseRoute(byPhase.plan)).toBe(
+      "  plan       claude_sdk/claude-opus  (phase_agents.plan, 60s timeout, max 3 iterations)",
+    );
+  });
+
+  it("routingCommand reads the config and prints chains", async () => {
+    const logger = createMockLogger();
+
+    await routingCommand({ cwd: tempDir }, logger);
+
+    const lines = logger.info.mock.calls.map((call) => call[0]);
+    expect(lines).toContain(
+      "  implement  claude_sdk/claude-opus → codex_sdk/codex-1  (phase_agents.implement, 3600s timeout, max 100 iterations)",
+    );
+  });
+
+  it("applies CLI agent overrides to every phase", async () => {
+    const logger = createMockLogger();
+
+    await routingCommand(
+      { cwd: tempDir, json: true, agentKind: "codex_sdk" },
+      logger,
+    );
+
+    const routing = logger.json.mock.calls[0][0] as any[];
+    for (const route of routing) {
+      expect(route.source).toBe("agent");
+      expect(route.agents).toEqual(["codex_sdk/claude-haiku"]);
+    }
+  });
+});
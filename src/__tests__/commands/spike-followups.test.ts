@@ -0,0 +1,119 @@
+import { describe, expect, it, beforeEach, afterEach, vi } from "bun:test";
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import * as os from "node:os";
+import { spikeFollowupsCommand } from "../../commands/spike-followups";
+import { FileNotFoundError } from "../../errors";
+import type { Logger } from "../../logging";
+import type { Item } from "../../schemas";
+
+function createMockLogger() {
+  return {
+    debug: vi.fn(),
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    json: vi.fn(),
+  } satisfies Logger;
+}
+
+function makeSpike(): Item {
+  return {
+    schema_version: 1,
+    id: "001-try-x",
+    title: "Should we migrate to X?",
+    kind: "spike",
+    state: "done",
+    overview: "",
+    branch: null,
+    pr_url: null,
+    pr_number: null,
+    last_error: null,
+    created_at: "2025-01-01T00:00:00Z",
+    updated_at: "2025-01-01T00:00:00Z",
+  };
+}
+
+const REPORT = `# Spike Report: Should we migrate to X?
+
+## Findings
+X is already a dependency.
+
+## Options
+Migrate or stay.
+
+## Recommendation
+Migrate the session store first.
+
+## Follow-up Ideas
+- **Migrate session store to X**: Start with sessions
+- **Drop legacy adapter**: Once sessions moved
+`;
+
+describe("spikeFollowupsCommand", () => {
+  let tempDir: string;
+  let itemDir: string;
+  let logger: ReturnType<typeof createMockLogger>;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), "wreckit-spike-test-"));
+    await fs.mkdir(path.join(tempDir, ".git"), { recursive: true });
+    itemDir = path.join(tempDir, ".wreckit", "items", "001-try-x");
+    await fs.mkdir(itemDir, { recursive: true });
+    await fs.writeFile(
+      path.join(itemDir, "item.json"),
+      JSON.stringify(makeSpike(), null, 2),
+    );
+    logger = createMockLogger();
+  });
+
+  afterEach(async () => {
+    await fs.rm(tempDir, { recursive: true, force: true });
+  });
+
+  it("creates an item for each recommended follow-up", async () => {
+    await fs.writeFile(path.join(itemDir, "report.md"), REPORT);
+
+    const created = await spikeFollowupsCommand(
+      "001-try-x",
+      { cwd: tempDir },
+      logger,
+    );
+
+    expect(created.map((item) => item.title)).toEqual([
+      "Migrate session store to X",
+      "Drop legacy adapter",
+    ]);
+    expect(created[0].state).toBe("idea");
+    expect(created[0].motivation).toBe(
+      "Recommended by spike 001-try-x: Should we migrate to X?",
+    );
+
+    const again = await spikeFollowupsCommand(
+      "001-try-x",
+      { cwd: tempDir },
+      logger,
+    );
+    expect(again).toEqual([]);
+  });
+
+  it("creates nothing in dry-run mode", async () => {
+    await fs.writeFile(path.join(itemDir, "report.md"), REPORT);
+
+    const created = await spikeFollowupsCommand(
+      "001-try-x",
+      { cwd: tempDir, dryRun: true },
+      logger,
+    );
+
+    expect(created).toEqual([]);
+    const entries = await fs.readdir(path.join(tempDir, ".wreckit", "items"));
+    expect(entries).toEqual(["001-try-x"]);
+  });
+
+  it("fails when the spike has no report", async () => {
+    await expect(
+      spikeFollowupsCommand("001-try-x", { cwd: tempDir }, logger),
+    ).rejects.toBeInstanceOf(FileNotFoundError);
+  });
+});
@@ -0,0 +1,232 @@
+import { describe, expect, it, beforeEach, afterEach, vi } from "bun:test";
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import * as os from "node:os";
+import {
+  transcriptCommand,
+  formatTranscript,
+  transcriptToMarkdown,
+} from "../../commands/transcript";
+import {
+  createTranscriptWriter,
+  listTranscripts,
+  readTranscript,
+  type TranscriptEntry,
+} from "../../fs/transcripts";
+import { runAgentUnion } from "../../agent";
+import type { Logger } from "../../logging";
+import type { Item } from "../../schemas";
+
+function createMockLogger() {
+  return {
+    debug: vi.fn(),
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    json: vi.fn(),
+  } satisfies Logger;
+}
+
+function makeItem(overrides: Partial<Item> = {}): Item {
+  return {
+    schema_version: 1,
+    id: "001-test",
+    title: "test",
+    state: "implementing",
+    overview: "",
+    branch: null,
+    pr_url: null,
+    pr_number: null,
+    last_error: null,
+    created_at: "2025-01-01T00:00:00Z",
+    updated_at: "2025-01-01T00:00:00Z",
+    ...overrides,
+  };
+}
+
+const ENTRIES: TranscriptEntry[] = [
+  {
+    ts: "2025-01-01T10:00:00.000Z",
+    event: { type: "agent_started", agent: "claude_sdk/claude-sonnet-4" },
+  },
+  {
+    ts: "2025-01-01T10:00:01.000Z",
+    event: { type: "assistant_text", text: "Running the tests" },
+  },
+  {
+    ts: "2025-01-01T10:00:02.000Z",
+    event: {
+      type: "tool_started",
+      toolUseId: "t1",
+      toolName: "Bash",
+      input: { command: "bun test" },
+    },
+  },
+  {
+    ts: "2025-01-01T10:00:14.500Z",
+    event: { type: "tool_result", toolUseId: "t1", result: "42 pass" },
+    duration_ms: 12500,
+  },
+  {
+    ts: "2025-01-01T10:00:15.000Z",
+    event: { type: "run_result", subtype: "success" },
+  },
+];
+
+describe("transcripts", () => {
+  let tempDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), "wreckit-transcript-"));
+    await fs.mkdir(path.join(tempDir, ".git"), { recursive: true });
+    const itemDir = path.join(tempDir, ".wreckit", "items", "001-test");
+    await fs.mkdir(itemDir, { recursive: true });
+    await fs.writeFile(
+      path.join(itemDir, "item.json"),
+      JSON.stringify(makeItem(), null, 2),
+    );
+  });
+
+  afterEach(async () => {
+    await fs.rm(tempDir, { recursive: true, force: true });
+  });
+
+  it("writes numbered transcripts with tool durations", async () => {
+    const onError = vi.fn();
+    for (let run = 0; run < 2; run++) {
+      const writer = await createTranscriptWriter(
+        tempDir,
+        "001-test",
+        "implement",
+        onError,
+      );
+      writer.write({
+        type: "tool_started",
+        toolUseId: "t1",
+        toolName: "Read",
+        input: { path: "a.ts" },
+      });
+      writer.write({ type: "tool_result", toolUseId: "t1", result: "..." });
+      await writer.close();
+    }
+    const research = await createTranscriptWriter(
+      tempDir,
+      "001-test",
+      "research",
+      onError,
+    );
+    research.write({ type: "assistant_text", text: "Reading" });
+    await research.close();
+
+    const files = await listTranscripts(tempDir, "001-test");
+    expect(files.map((f) => `${f.phase}-${f.run}`)).toEqual([
+      "research-1",
+      "implement-1",
+      "implement-2",
+    ]);
+    expect(
+      await listTranscripts(tempDir, "001-test", "research"),
+    ).toHaveLength(1);
+
+    const entries = await readTranscript(files[2].path);
+    expect(entries.map((e) => e.event.type)).toEqual([
+      "tool_started",
+      "tool_result",
+    ]);
+    expect(entries[0].duration_ms).toBeUndefined();
+    expect(entries[1].duration_ms).toBeGreaterThanOrEqual(0);
+    expect(onError).not.toHaveBeenCalled();
+  });
+
+  it("records a process agent's output", async () => {
+    const writer = await createTranscriptWriter(
+      tempDir,
+      "001-test",
+      "implement",
+      vi.fn(),
+    );
+    const result = await runAgentUnion({
+      config: {
+        kind: "process",
+        command: "sh",
+        args: [
+          "-c",
+          'echo "Editing files" && echo "<promise>COMPLETE</promise>"',
+        ],
+        completion_signal: "<promise>COMPLETE</promise>",
+      },
+      cwd: tempDir,
+      prompt: "test prompt",
+      logger: createMockLogger(),
+      onStdoutChunk: vi.fn(),
+      onAgentEvent: (event) => writer.write(event),
+    });
+    await writer.close();
+
+    expect(result.success).toBe(true);
+    const entries = await readTranscript(writer.path);
+    const text = entries
+      .map((e) => (e.event.type === "assistant_text" ? e.event.text : ""))
+      .join("");
+    expect(text).toContain("Editing files");
+  });
+
+  it("formats a transcript as timeline lines", () => {
+    expect(formatTranscript(ENTRIES)).toEqual([
+      "10:00:00  ▶ claude_sdk/claude-sonnet-4",
+      "10:00:01  Running the tests",
+      '10:00:02  → Bash {"command":"bun test"}',
+      "10:00:14  ← Bash (12.5s)",
+      "10:00:15  ✓ run finished (success)",
+    ]);
+  });
+
+  it("exports a transcript as Markdown", () => {
+    const markdown = transcriptToMarkdown(ENTRIES);
+
+    expect(markdown).toContain("Running the tests");
+    expect(markdown).toContain('"command": "bun test"');
+    expect(markdown).toContain("<summary>Bash result (12.5s)</summary>");
+  });
+
+  it("transcriptCommand filters by phase and writes Markdown", async () => {
+    const writer = await createTranscriptWriter(
+      tempDir,
+      "001-test",
+      "implement",
+      vi.fn(),
+    );
+    writer.write({ type: "assistant_text", text: "Implementing US-001" });
+    await writer.close();
+
+    const logger = createMockLogger();
+    await transcriptCommand(
+      "001-test",
+      { cwd: tempDir, phase: "implement" },
+      logger,
+    );
+    const lines = logger.info.mock.calls.map((call) => call[0]);
+    expect(lines).toContain("━━━ implement-1 ━━━");
+
+    await transcriptCommand(
+      "001-test",
+      { cwd: tempDir, output: "transcript.md" },
+      createMockLogger(),
+    );
+    const markdown = await fs.readFile(
+      path.join(tempDir, "transcript.md"),
+      "utf-8",
+    );
+    expect(markdown).toContain("## implement-1\n\nImplementing US-001");
+
+    const empty = createMockLogger();
+    await transcriptCommand("001-test", { cwd: tempDir, phase: "pr" }, empty);
+    expect(empty.info).toHaveBeenCalledWith(
+      "No transcripts recorded for 001-test in pr",
+    );
+
+    await expect(
+      transcriptCommand("001-test", { cwd: tempDir, phase: "nope" }, empty),
+    ).rejects.toThrow("Unknown phase 'nope'");
+  });
+});
@@ -1,4 +1,4 @@
-import { describe, it, expect, beforeEach, afterEach } from "bun:test";
+import { describe, it, expect, beforeEach, afterEach, vi } from "bun:test";
 import * as fs from "node:fs/promises";
 import * as path from "node:path";
 import * as os from "node:os";
@@ -7,6 +7,7 @@ import {
   mergeWithDefaults,
   applyOverrides,
   createDefaultConfig,
+  getPhaseSettings,
   DEFAULT_CONFIG,
   type ConfigResolved,
   type ConfigOverrides,
@@ -117,6 +118,29 @@ describe("loadConfig", () => {
     expect(result.agent).toEqual(DEFAULT_CONFIG.agent);
   });
 
+  it("rejects a bash_policy rule with an invalid pattern, naming it", async () => {
+    const warnSpy = vi.spyOn(console, "warn").mockImplementation(() => {});
+    await fs.writeFile(
+      path.join(tempDir, ".wreckit", "config.json"),
+      JSON.stringify({
+        base_branch: "develop",
+        bash_policy: {
+          rules: [{ pattern: "git push (--force", action: "deny" }],
+        },
+      }),
+    );
+
+    const result = await loadConfig(tempDir);
+
+    expect(result.base_branch).toBe(DEFAULT_CONFIG.base_branch);
+    expect(result.bash_policy).toBeUndefined();
+    expect(warnSpy).toHaveBeenCalledTimes(1);
+    expect(String(warnSpy.mock.calls[0][0])).toContain(
+      "Invalid regular expression: git push (--force",
+    );
+    warnSpy.mockRestore();
+  });
+
   it("falls back to defaults for malformed JSON", async () => {
     // The implementation was changed to be lenient: it catches JSON parse
     // errors and falls back to defaults instead of throwing
@@ -285,6 +309,88 @@ describe("applyOverrides", () => {
   });
 });
 
+describe("getPhaseSettings", () => {
+  const config: ConfigResolved = {
+    ...DEFAULT_CONFIG,
+    phases: {
+      research: { timeout_seconds: 300 },
+      implement: {
+        max_iterations: 20,
+        agent: { kind: "codex_sdk", model: "codex-1" },
+      },
+    },
+  };
+
+  it("falls back to global settings", () => {
+    expect(getPhaseSettings(config, "pr")).toEqual({
+      agent: DEFAULT_CONFIG.agent,
+      timeout_seconds: 3600,
+      max_iterations: 100,
+    });
+  });
+
+  it("applies per-phase overrides", () => {
+    expect(getPhaseSettings(config, "research").timeout_seconds).toBe(300);
+    expect(getPhaseSettings(config, "implement")).toMatchObject({
+      agent: { kind: "codex_sdk" },
+      timeout_seconds: 3600,
+      max_iterations: 20,
+    });
+  });
+
+  it("defaults research and plan to 3 validation attempts", () => {
+    expect(getPhaseSettings(config, "research").max_iterations).toBe(3);
+    expect(getPhaseSettings(config, "plan").max_iterations).toBe(3);
+  });
+
+  it("lets CLI overrides replace per-phase settings", () => {
+    const overridden = applyOverrides(config, {
+      timeoutSeconds: 60,
+      agentKind: "amp_sdk",
+    });
+    expect(getPhaseSettings(overridden, "research").timeout_seconds).toBe(60);
+    expect(getPhaseSettings(overridden, "implement").agent.kind).toBe(
+      "amp_sdk",
+    );
+    expect(getPhaseSettings(overridden, "implement").max_iterations).toBe(20);
+  });
+
+  it("splits an agent chain into the primary agent and fallbacks", () => {
+    const codex = { kind: "codex_sdk" as const, model: "codex-1" };
+    const amp = { kind: "amp_sdk" as const };
+    const chained = mergeWithDefaults({
+      agent: [DEFAULT_CONFIG.agent, codex],
+      phases: { implement: { agent: [amp, codex] } },
+    });
+
+    expect(chained.agent).toEqual(DEFAULT_CONFIG.agent);
+    expect(getPhaseSettings(chained, "pr").agent_fallbacks).toEqual([codex]);
+    expect(getPhaseSettings(chained, "implement")).toMatchObject({
+      agent: amp,
+      agent_fallbacks: [codex],
+    });
+
+    const overridden = applyOverrides(chained, { agentKind: "amp_sdk" });
+    expect(overridden.agent_fallbacks).toBeUndefined();
+    expect(getPhaseSettings(overridden, "pr").agent_fallbacks).toBeUndefined();
+  });
+
+  it("validates the phases section", () => {
+    expect(
+      ConfigSchema.safeParse({
+        agent: DEFAULT_CONFIG.agent,
+        phases: { implement: { timeout_seconds: 7200, max_iterations: 50 } },
+      }).success,
+    ).toBe(true);
+    expect(
+      ConfigSchema.safeParse({
+        agent: DEFAULT_CONFIG.agent,
+        phases: { research: { timeout: 10 } },
+      }).success,
+    ).toBe(false);
+  });
+});
+
 describe("createDefaultConfig", () => {
   let tempDir: string;
 
@@ -0,0 +1,45 @@
+import { describe, expect, it } from "bun:test";
+import { compareWithBaseline, requiresBehaviorCheck } from "../../domain";
+import type { TestOutcome } from "../../schemas";
+
+const baseline: TestOutcome[] = [
+  { name: "keeps totals", status: "passed" },
+  { name: "formats dates", status: "passed" },
+  { name: "flaky upload", status: "failed" },
+  { name: "later", status: "skipped" },
+  { name: "renamed", status: "passed" },
+];
+
+describe("behavior checks", () => {
+  it("apply to refactor items only", () => {
+    expect(requiresBehaviorCheck("refactor")).toBe(true);
+    expect(requiresBehaviorCheck("feature")).toBe(false);
+    expect(requiresBehaviorCheck(undefined)).toBe(false);
+  });
+
+  it("reports previously passing tests that now fail or are missing", () => {
+    const current: TestOutcome[] = [
+      { name: "keeps totals", status: "passed" },
+      { name: "formats dates", status: "failed" },
+      { name: "flaky upload", status: "failed" },
+      { name: "later", status: "failed" },
+    ];
+
+    expect(compareWithBaseline(baseline, current)).toEqual({
+      broken: ["formats dates"],
+      missing: ["renamed"],
+    });
+  });
+
+  it("counts a test reported more than once as failed if any run failed", () => {
+    const current: TestOutcome[] = [
+      ...baseline,
+      { name: "keeps totals", status: "failed" },
+      { name: "keeps totals", status: "passed" },
+    ];
+
+    expect(compareWithBaseline(baseline, current).broken).toEqual([
+      "keeps totals",
+    ]);
+  });
+});
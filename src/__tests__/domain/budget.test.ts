@@ -0,0 +1,58 @@
+import { describe, expect, it } from "bun:test";
+import { findBudgetBreach, isSessionBudgetReached } from "../../domain";
+import { BudgetExceededError, ErrorCodes } from "../../errors";
+
+describe("findBudgetBreach", () => {
+  const spend = { item: 4, phase: 1, session: 9 };
+
+  it("returns null without caps or below every cap", () => {
+    expect(findBudgetBreach(undefined, spend)).toBeNull();
+    expect(findBudgetBreach({}, spend)).toBeNull();
+    expect(
+      findBudgetBreach(
+        { per_item_usd: 5, per_phase_usd: 2, per_session_usd: 10 },
+        spend,
+      ),
+    ).toBeNull();
+  });
+
+  it("treats reaching a cap as a breach", () => {
+    expect(findBudgetBreach({ per_item_usd: 4 }, spend)).toEqual({
+      cap: "item",
+      limit_usd: 4,
+      spent_usd: 4,
+    });
+  });
+
+  it("reports the narrowest cap first", () => {
+    expect(
+      findBudgetBreach(
+        { per_item_usd: 3, per_phase_usd: 1, per_session_usd: 5 },
+        spend,
+      )?.cap,
+    ).toBe("phase");
+    expect(
+      findBudgetBreach({ per_item_usd: 3, per_session_usd: 5 }, spend)?.cap,
+    ).toBe("item");
+  });
+});
+
+describe("isSessionBudgetReached", () => {
+  it("only stops once the session cap is reached", () => {
+    expect(isSessionBudgetReached(undefined, 100)).toBe(false);
+    expect(isSessionBudgetReached({ per_item_usd: 1 }, 100)).toBe(false);
+    expect(isSessionBudgetReached({ per_session_usd: 10 }, 9.99)).toBe(false);
+    expect(isSessionBudgetReached({ per_session_usd: 10 }, 10)).toBe(true);
+  });
+});
+
+describe("BudgetExceededError", () => {
+  it("names the cap and carries BUDGET_EXCEEDED", () => {
+    const error = new BudgetExceededError("phase", 2, 2.5);
+
+    expect(error.code).toBe(ErrorCodes.BUDGET_EXCEEDED);
+    expect(error.message).toBe(
+      "Budget exceeded: phase spend of $2.50 reached budget.per_phase_usd ($2.00)",
+    );
+  });
+});
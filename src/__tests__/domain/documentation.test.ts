@@ -0,0 +1,173 @@
+import { describe, expect, it } from "bun:test";
+import type { Item, Prd } from "../../schemas";
+import {
+  DEFAULT_DOCUMENTATION_PATHS,
+  applyRegression,
+  buildPipeline,
+  findNonDocumentationPaths,
+  getNextPhaseInPipeline,
+  matchesAnyGlob,
+  validateTransition,
+  type ValidationContext,
+} from "../../domain";
+
+const DOCUMENTED_PIPELINE = buildPipeline("documented", {
+  stages: [
+    { state: "idea" },
+    { state: "researched", phase: "research" },
+    { state: "planned", phase: "plan" },
+    { state: "implementing", phase: "implement" },
+    { state: "critique", phase: "critique" },
+    { state: "documented", phase: "document" },
+    { state: "in_pr", phase: "pr" },
+    { state: "done", phase: "complete" },
+  ],
+});
+
+function makePrd(status: "pending" | "done"): Prd {
+  return {
+    schema_version: 1,
+    id: "prd",
+    branch_name: "wreckit/test",
+    user_stories: [
+      {
+        id: "US-001",
+        title: "Story",
+        acceptance_criteria: ["AC"],
+        priority: 1,
+        status,
+        notes: "",
+      },
+    ],
+  };
+}
+
+function ctx(
+  status: "pending" | "done",
+  documented: boolean,
+): ValidationContext {
+  return {
+    hasResearchMd: true,
+    hasPlanMd: true,
+    prd: makePrd(status),
+    hasPr: false,
+    prMerged: false,
+    documentation: documented
+      ? { changed_files: ["README.md"], documented_at: "2025-01-01T00:00:00Z" }
+      : null,
+  };
+}
+
+describe("matchesAnyGlob", () => {
+  it("matches '**' across directories and '*' within one", () => {
+    expect(matchesAnyGlob("docs/guide/loop.md", ["docs/**"])).toBe(true);
+    expect(matchesAnyGlob("src/docs/a.md", ["docs/**"])).toBe(false);
+    expect(matchesAnyGlob("docs/a.md", ["docs/*.md"])).toBe(true);
+    expect(matchesAnyGlob("docs/guide/a.md", ["docs/*.md"])).toBe(false);
+    expect(matchesAnyGlob("a.md", ["**/*.md"])).toBe(true);
+    expect(matchesAnyGlob("CHANGELOG.md", ["CHANGELOG.???"])).toBe(false);
+    expect(matchesAnyGlob("CHANGELOG.md", ["CHANGELOG.??"])).toBe(true);
+  });
+
+  it("matches patterns without a slash at any depth", () => {
+    expect(matchesAnyGlob("packages/cli/README.md", ["README*"])).toBe(true);
+    expect(matchesAnyGlob("./notes.md", ["*.md"])).toBe(true);
+    expect(matchesAnyGlob("src/index.ts", ["*.md"])).toBe(false);
+  });
+
+  it("treats regex characters in patterns literally", () => {
+    expect(matchesAnyGlob("docs/a+b.md", ["docs/a+b.md"])).toBe(true);
+    expect(matchesAnyGlob("docs/aab.md", ["docs/a+b.md"])).toBe(false);
+  });
+});
+
+describe("findNonDocumentationPaths", () => {
+  it("returns changed paths outside the documentation globs", () => {
+    expect(
+      findNonDocumentationPaths(
+        [
+          "README.md",
+          "CHANGELOG.md",
+          "docs/cli/phases.md",
+          "docs/images/flow.svg",
+          "src/index.ts",
+          "package.json",
+        ],
+        DEFAULT_DOCUMENTATION_PATHS,
+      ),
+    ).toEqual(["src/index.ts", "package.json"]);
+  });
+
+  it("always allows wreckit's own item files", () => {
+    expect(
+      findNonDocumentationPaths(
+        [".wreckit/items/001-test/item.json", "src/a.ts"],
+        ["docs/**"],
+      ),
+    ).toEqual(["src/a.ts"]);
+  });
+});
+
+describe("document phase", () => {
+  it("runs between critique and the PR when added to a pipeline", () => {
+    expect(getNextPhaseInPipeline(DOCUMENTED_PIPELINE, "critique")).toBe(
+      "document",
+    );
+    expect(getNextPhaseInPipeline(DOCUMENTED_PIPELINE, "documented")).toBe(
+      "pr",
+    );
+  });
+
+  it("requires finished stories and a documentation record", () => {
+    const enter = (context: ValidationContext) =>
+      validateTransition(
+        "critique",
+        "documented",
+        context,
+        DOCUMENTED_PIPELINE,
+      );
+
+    expect(enter(ctx("done", true))).toEqual({ valid: true });
+    expect(enter(ctx("pending", true))).toEqual({
+      valid: false,
+      reason: "not all stories are done",
+    });
+    expect(enter(ctx("done", false))).toEqual({
+      valid: false,
+      reason: "documentation update not recorded",
+    });
+  });
+
+  it("is redone when the item is sent back for rework", () => {
+    const item: Item = {
+      schema_version: 1,
+      id: "001-test",
+      title: "Test",
+      state: "in_pr",
+      overview: "",
+      branch: null,
+      pr_url: null,
+      pr_number: null,
+      last_error: null,
+      created_at: "2025-01-01T00:00:00Z",
+      updated_at: "2025-01-01T00:00:00Z",
+      documentation: {
+        changed_files: ["README.md"],
+        documented_at: "2025-01-01T00:00:00Z",
+      },
+    };
+    const options = {
+      reason: "redo",
+      actor: "human",
+      pipeline: DOCUMENTED_PIPELINE,
+    };
+
+    const kept = applyRegression(item, "documented", options);
+    if ("error" in kept) throw new Error(kept.error);
+    expect(kept.nextItem.documentation).toEqual(item.documentation);
+
+    const dropped = applyRegression(item, "planned", options);
+    if ("error" in dropped) throw new Error(dropped.error);
+    expect(dropped.nextItem.documentation).toBeNull();
+  });
+});
@@ -0,0 +1,180 @@
+import { describe, expect, it } from "bun:test";
+import type { Item, PipelineConfig } from "../../schemas";
+import { ConfigSchema } from "../../schemas";
+import {
+  DEFAULT_PIPELINE,
+  WORKFLOW_STATES,
+  getNextState,
+  validateTransition,
+  applyStateTransition,
+  validatePipelineConfig,
+  resolvePipeline,
+  getItemPipeline,
+  getPhaseEntryState,
+  getNextPhaseInPipeline,
+  type ValidationContext,
+} from "../../domain";
+import { getNextPhase } from "../../workflow";
+import { ConfigError } from "../../errors";
+
+const LEAN_PIPELINE: PipelineConfig = {
+  stages: [
+    { state: "idea" },
+    { state: "researched", phase: "research" },
+    { state: "planned", phase: "plan", prompt: "plan-lean" },
+    { state: "implementing", phase: "implement" },
+    { state: "in_pr", phase: "pr", allowed_tools: ["Read", "Bash"] },
+    { state: "done", phase: "complete" },
+  ],
+};
+
+function makeItem(overrides: Partial<Item> = {}): Item {
+  return {
+    schema_version: 1,
+    id: "001-test",
+    title: "Test",
+    state: "idea",
+    overview: "",
+    branch: null,
+    pr_url: null,
+    pr_number: null,
+    last_error: null,
+    created_at: "2025-01-01T00:00:00Z",
+    updated_at: "2025-01-01T00:00:00Z",
+    ...overrides,
+  };
+}
+
+const doneCtx: ValidationContext = {
+  hasResearchMd: true,
+  hasPlanMd: true,
+  prd: {
+    schema_version: 1,
+    id: "prd",
+    branch_name: "wreckit/test",
+    user_stories: [
+      {
+        id: "US-001",
+        title: "Story",
+        acceptance_criteria: ["AC"],
+        priority: 1,
+        status: "done",
+        notes: "",
+      },
+    ],
+  },
+  hasPr: true,
+  prMerged: false,
+};
+
+describe("workflow pipelines", () => {
+  it("derives WORKFLOW_STATES from the built-in pipeline", () => {
+    expect(WORKFLOW_STATES).toEqual(
+      DEFAULT_PIPELINE.stages.map((stage) => stage.state),
+    );
+  });
+
+  it("accepts pipelines in the config schema", () => {
+    const result = ConfigSchema.safeParse({
+      agent: { kind: "claude_sdk" },
+      pipelines: { lean: LEAN_PIPELINE },
+      default_pipeline: "lean",
+    });
+    expect(result.success).toBe(true);
+  });
+
+  describe("validatePipelineConfig", () => {
+    it("accepts a pipeline without critique", () => {
+      expect(validatePipelineConfig("lean", LEAN_PIPELINE)).toEqual([]);
+    });
+
+    it("rejects pipelines that do not start at idea or end at done", () => {
+      const errors = validatePipelineConfig("bad", {
+        stages: [
+          { state: "researched", phase: "research" },
+          { state: "planned", phase: "plan" },
+        ],
+      });
+      expect(errors.some((e) => e.includes("must start with state 'idea'"))).toBe(
+        true,
+      );
+      expect(errors.some((e) => e.includes("must end with state 'done'"))).toBe(
+        true,
+      );
+    });
+
+    it("rejects a phase mapped to the wrong state", () => {
+      const errors = validatePipelineConfig("bad", {
+        stages: [
+          { state: "idea" },
+          { state: "planned", phase: "research" },
+          { state: "done", phase: "complete" },
+        ],
+      });
+      expect(errors).toHaveLength(1);
+      expect(errors[0]).toContain("produces 'researched'");
+    });
+  });
+
+  describe("resolvePipeline", () => {
+    it("returns the built-in pipeline by default", () => {
+      expect(resolvePipeline({})).toBe(DEFAULT_PIPELINE);
+    });
+
+    it("uses default_pipeline when no name is given", () => {
+      const pipeline = resolvePipeline({
+        pipelines: { lean: LEAN_PIPELINE },
+        default_pipeline: "lean",
+      });
+      expect(pipeline.name).toBe("lean");
+      expect(pipeline.stages[2].prompt).toBe("plan-lean");
+      expect(pipeline.stages[4].allowedTools).toEqual(["Read", "Bash"]);
+    });
+
+    it("throws ConfigError for unknown pipelines", () => {
+      expect(() => resolvePipeline({}, "missing")).toThrow(ConfigError);
+    });
+
+    it("keeps items without a recorded pipeline on the built-in one", () => {
+      const pipeline = getItemPipeline(makeItem(), {
+        pipelines: { lean: LEAN_PIPELINE },
+        default_pipeline: "lean",
+      });
+      expect(pipeline.name).toBe("default");
+    });
+  });
+
+  describe("transitions follow the pipeline", () => {
+    const lean = resolvePipeline({ pipelines: { lean: LEAN_PIPELINE } }, "lean");
+
+    it("skips critique in a pipeline without it", () => {
+      expect(getNextState("implementing", lean)).toBe("in_pr");
+      expect(getNextState("implementing")).toBe("critique");
+    });
+
+    it("validates transitions against the pipeline order", () => {
+      expect(validateTransition("implementing", "in_pr", doneCtx, lean).valid).toBe(
+        true,
+      );
+      expect(validateTransition("implementing", "in_pr", doneCtx).valid).toBe(
+        false,
+      );
+    });
+
+    it("applies transitions using the pipeline", () => {
+      const result = applyStateTransition(
+        makeItem({ state: "implementing", pipeline: "lean" }),
+        doneCtx,
+        lean,
+      );
+      expect(result.nextItem?.state).toBe("in_pr");
+    });
+
+    it("resolves phase entry states and next phases", () => {
+      expect(getPhaseEntryState(lean, "pr")).toBe("implementing");
+      expect(getPhaseEntryState(lean, "critique")).toBeNull();
+      expect(getNextPhaseInPipeline(lean, "implementing")).toBe("pr");
+      expect(getNextPhase(makeItem({ state: "implementing" }), lean)).toBe("pr");
+    });
+  });
+});
@@ -0,0 +1,227 @@
+import { describe, expect, it } from "bun:test";
+import type { Item, Reproduction } from "../../schemas";
+import { ConfigSchema } from "../../schemas";
+import {
+  BUG_PIPELINE,
+  DEFAULT_PIPELINE,
+  applyRegression,
+  getKindPipelineName,
+  getNextPhaseInPipeline,
+  isFixVerified,
+  recordTestRun,
+  requiresReproduction,
+  resolvePipeline,
+  validateTransition,
+  type ValidationContext,
+} from "../../domain";
+
+function makeItem(overrides: Partial<Item> = {}): Item {
+  return {
+    schema_version: 1,
+    id: "001-test",
+    title: "Test",
+    state: "idea",
+    overview: "",
+    branch: null,
+    pr_url: null,
+    pr_number: null,
+    last_error: null,
+    created_at: "2025-01-01T00:00:00Z",
+    updated_at: "2025-01-01T00:00:00Z",
+    ...overrides,
+  };
+}
+
+const unrun: Reproduction = {
+  command: "bun test src/__tests__/bug.test.ts",
+  test_files: ["src/__tests__/bug.test.ts"],
+  failing_run: null,
+  passing_run: null,
+};
+
+const failed = recordTestRun(unrun, 1, "2025-01-01T00:00:00Z");
+const fixed = recordTestRun(failed, 0, "2025-01-02T00:00:00Z");
+
+function bugCtx(reproduction: Reproduction | null): ValidationContext {
+  return {
+    hasResearchMd: true,
+    hasPlanMd: true,
+    prd: {
+      schema_version: 1,
+      id: "prd",
+      branch_name: "wreckit/test",
+      user_stories: [
+        {
+          id: "US-001",
+          title: "Story",
+          acceptance_criteria: ["AC"],
+          priority: 1,
+          status: "done",
+          notes: "",
+        },
+      ],
+    },
+    hasPr: true,
+    prMerged: false,
+    kind: "bug",
+    reproduction,
+  };
+}
+
+describe("bug reproduction", () => {
+  describe("bug pipeline", () => {
+    it("is used for bug items only", () => {
+      expect(getKindPipelineName("bug")).toBe("bug");
+      expect(getKindPipelineName("feature")).toBeUndefined();
+      expect(getKindPipelineName(undefined)).toBeUndefined();
+    });
+
+    it("resolves by name and can be overridden in config", () => {
+      const config = ConfigSchema.parse({});
+      expect(resolvePipeline(config, "bug")).toBe(BUG_PIPELINE);
+
+      const custom = ConfigSchema.parse({
+        pipelines: {
+          bug: {
+            stages: [
+              { state: "idea" },
+              { state: "reproduced", phase: "reproduce" },
+              { state: "implementing", phase: "implement" },
+              { state: "in_pr", phase: "pr" },
+              { state: "done", phase: "complete" },
+            ],
+          },
+        },
+      });
+      expect(resolvePipeline(custom, "bug").stages).toHaveLength(5);
+    });
+
+    it("reproduces after research and before planning", () => {
+      expect(getNextPhaseInPipeline(BUG_PIPELINE, "researched")).toBe(
+        "reproduce",
+      );
+      expect(getNextPhaseInPipeline(BUG_PIPELINE, "reproduced")).toBe("plan");
+    });
+
+    it("requires reproduction for bugs with a reproduce stage", () => {
+      expect(requiresReproduction("bug", BUG_PIPELINE)).toBe(true);
+      expect(requiresReproduction("bug", DEFAULT_PIPELINE)).toBe(false);
+      expect(requiresReproduction("feature", BUG_PIPELINE)).toBe(false);
+    });
+  });
+
+  describe("recordTestRun", () => {
+    it("records failing and passing runs", () => {
+      expect(failed.failing_run).toEqual({
+        at: "2025-01-01T00:00:00Z",
+        exit_code: 1,
+        passed: false,
+      });
+      expect(failed.passing_run).toBeNull();
+      expect(fixed.passing_run?.passed).toBe(true);
+      expect(isFixVerified(fixed)).toBe(true);
+    });
+
+    it("drops an earlier pass when the test fails again", () => {
+      const broken = recordTestRun(fixed, 2, "2025-01-03T00:00:00Z");
+      expect(broken.passing_run).toBeNull();
+      expect(broken.failing_run?.exit_code).toBe(2);
+      expect(isFixVerified(broken)).toBe(false);
+    });
+
+    it("does not count a pass without a prior failure", () => {
+      expect(isFixVerified(recordTestRun(unrun, 0))).toBe(false);
+    });
+  });
+
+  describe("validateTransition", () => {
+    it("enters reproduced only after the test was seen failing", () => {
+      const denied = validateTransition(
+        "researched",
+        "reproduced",
+        bugCtx(unrun),
+        BUG_PIPELINE,
+      );
+      expect(denied.valid).toBe(false);
+      expect(denied.reason).toContain("has not been seen failing");
+
+      expect(
+        validateTransition(
+          "researched",
+          "reproduced",
+          bugCtx(null),
+          BUG_PIPELINE,
+        ).reason,
+      ).toBe("no reproduction test recorded");
+
+      expect(
+        validateTransition(
+          "researched",
+          "reproduced",
+          bugCtx(failed),
+          BUG_PIPELINE,
+        ).valid,
+      ).toBe(true);
+    });
+
+    it("refuses to leave implementation until the test passes", () => {
+      const denied = validateTransition(
+        "implementing",
+        "critique",
+        bugCtx(failed),
+        BUG_PIPELINE,
+      );
+      expect(denied.valid).toBe(false);
+      expect(denied.reason).toBe(
+        "reproduction test has not failed and then passed",
+      );
+
+      expect(
+        validateTransition("critique", "in_pr", bugCtx(failed), BUG_PIPELINE)
+          .valid,
+      ).toBe(false);
+      expect(
+        validateTransition(
+          "implementing",
+          "critique",
+          bugCtx(fixed),
+          BUG_PIPELINE,
+        ).valid,
+      ).toBe(true);
+    });
+
+    it("does not gate features", () => {
+      const ctx = { ...bugCtx(null), kind: "feature" as const };
+      expect(
+        validateTransition("implementing", "critique", ctx, DEFAULT_PIPELINE)
+          .valid,
+      ).toBe(true);
+    });
+  });
+
+  describe("applyRegression", () => {
+    const options = {
+      reason: "fix was wrong",
+      actor: "human",
+      pipeline: BUG_PIPELINE,
+      now: "2025-01-04T00:00:00Z",
+    };
+
+    it("keeps the failing run when implementation is redone", () => {
+      const item = makeItem({ state: "critique", reproduction: fixed });
+      const result = applyRegression(item, "planned", options);
+      if ("error" in result) throw new Error(result.error);
+      expect(result.nextItem.reproduction?.failing_run).toEqual(
+        fixed.failing_run,
+      );
+      expect(result.nextItem.reproduction?.passing_run).toBeNull();
+    });
+
+    it("drops the reproduction when regressing before it", () => {
+      const item = makeItem({ state: "planned", reproduction: failed });
+      const result = applyRegression(item, "researched", options);
+      if ("error" in result) throw new Error(result.error);
+      expect(result.nextItem.reproduction).toBeNull();
+    });
+  });
+});
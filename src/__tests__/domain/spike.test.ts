@@ -0,0 +1,119 @@
+import { describe, expect, it } from "bun:test";
+import {
+  DEFAULT_PIPELINE,
+  SPIKE_PIPELINE,
+  getKindPipelineName,
+  getNextPhaseInPipeline,
+  parseFollowUpIdeas,
+  requiresReport,
+  validateSpikeReport,
+  validateTransition,
+  type ValidationContext,
+} from "../../domain";
+
+const REPORT = `# Spike Report: Should we migrate to X?
+
+## Findings
+
+- X is used in \`src/db.ts:12\`
+
+## Options
+
+### Option 1: Migrate
+
+Costly.
+
+## Recommendation
+
+Migrate the session store first.
+
+## Follow-up Ideas
+
+- **Migrate session store to X**: Start with sessions
+- **Drop legacy adapter** — once sessions moved
+- Benchmark X: compare p99 latency
+* Update docs
+`;
+
+function spikeCtx(hasReportMd: boolean): ValidationContext {
+  return {
+    hasResearchMd: true,
+    hasPlanMd: false,
+    prd: null,
+    hasPr: false,
+    prMerged: false,
+    kind: "spike",
+    hasReportMd,
+  };
+}
+
+describe("spike items", () => {
+  it("use the spike pipeline, which completes after research", () => {
+    expect(getKindPipelineName("spike")).toBe("spike");
+    expect(getNextPhaseInPipeline(SPIKE_PIPELINE, "researched")).toBe(
+      "complete",
+    );
+  });
+
+  it("require a report only when the pipeline has no PR stage", () => {
+    expect(requiresReport("spike", SPIKE_PIPELINE)).toBe(true);
+    expect(requiresReport("spike", DEFAULT_PIPELINE)).toBe(false);
+    expect(requiresReport("feature", SPIKE_PIPELINE)).toBe(false);
+  });
+
+  describe("validateTransition", () => {
+    it("lets a spike reach done with report.md instead of a merged PR", () => {
+      expect(
+        validateTransition("researched", "done", spikeCtx(true), SPIKE_PIPELINE)
+          .valid,
+      ).toBe(true);
+
+      const denied = validateTransition(
+        "researched",
+        "done",
+        spikeCtx(false),
+        SPIKE_PIPELINE,
+      );
+      expect(denied).toEqual({
+        valid: false,
+        reason: "report.md does not exist",
+      });
+    });
+  });
+
+  describe("validateSpikeReport", () => {
+    it("accepts a report with findings, options and a recommendation", () => {
+      expect(validateSpikeReport(REPORT)).toEqual({ valid: true, errors: [] });
+    });
+
+    it("reports missing and empty sections", () => {
+      const result = validateSpikeReport(
+        "# Spike Report: X\n\n## Findings\n\n## Options\nA or B\n",
+      );
+      expect(result.valid).toBe(false);
+      expect(result.errors).toEqual([
+        "Missing required sections: Recommendation",
+        "Section Findings is empty",
+      ]);
+    });
+  });
+
+  describe("parseFollowUpIdeas", () => {
+    it("parses the follow-up list in its common formats", () => {
+      expect(parseFollowUpIdeas(REPORT)).toEqual([
+        {
+          title: "Migrate session store to X",
+          description: "Start with sessions",
+        },
+        { title: "Drop legacy adapter", description: "once sessions moved" },
+        { title: "Benchmark X", description: "compare p99 latency" },
+        { title: "Update docs", description: "" },
+      ]);
+    });
+
+    it("returns nothing without a follow-up section or for 'None'", () => {
+      expect(parseFollowUpIdeas("# Report\n\n## Findings\n- a\n")).toEqual([]);
+      expect(parseFollowUpIdeas("## Follow-up Ideas\n\n- None\n")).toEqual([]);
+    });
+  });
+});
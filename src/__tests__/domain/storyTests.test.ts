@@ -0,0 +1,177 @@
+import { describe, expect, it } from "bun:test";
+import type { Item, Prd, StoryTests } from "../../schemas";
+import {
+  TDD_PIPELINE,
+  applyRegression,
+  checkTestsFailBeforeImplementation,
+  formatStoryTests,
+  getNextPhaseInPipeline,
+  getStoriesWithoutTests,
+  validateTransition,
+  type ValidationContext,
+} from "../../domain";
+
+const prd: Prd = {
+  schema_version: 1,
+  id: "prd",
+  branch_name: "wreckit/test",
+  user_stories: ["US-001", "US-002"].map((id, i) => ({
+    id,
+    title: `Story ${i + 1}`,
+    acceptance_criteria: ["AC"],
+    priority: i + 1,
+    status: "pending" as const,
+    notes: "",
+  })),
+};
+
+const storyTests: StoryTests = {
+  command: "bun test src/__tests__/export.test.ts",
+  stories: [
+    {
+      story_id: "US-001",
+      test_files: ["src/__tests__/export.test.ts"],
+      tests: ["exports csv", "escapes commas"],
+    },
+    {
+      story_id: "US-002",
+      test_files: ["src/__tests__/export.test.ts"],
+      tests: ["exports json"],
+    },
+  ],
+  failing_run: { at: "2025-01-01T00:00:00Z", exit_code: 1, passed: false },
+};
+
+function ctx(tests: StoryTests | null): ValidationContext {
+  return {
+    hasResearchMd: true,
+    hasPlanMd: true,
+    prd,
+    hasPr: false,
+    prMerged: false,
+    storyTests: tests,
+  };
+}
+
+describe("story tests", () => {
+  it("run between plan and implement in the tdd pipeline", () => {
+    expect(getNextPhaseInPipeline(TDD_PIPELINE, "planned")).toBe("tests");
+    expect(getNextPhaseInPipeline(TDD_PIPELINE, "tests_written")).toBe(
+      "implement",
+    );
+  });
+
+  describe("validateTransition", () => {
+    it("enters tests_written once every story has failing tests", () => {
+      expect(
+        validateTransition(
+          "planned",
+          "tests_written",
+          ctx(storyTests),
+          TDD_PIPELINE,
+        ).valid,
+      ).toBe(true);
+    });
+
+    it("rejects missing tests, uncovered stories and unseen failures", () => {
+      expect(
+        validateTransition("planned", "tests_written", ctx(null), TDD_PIPELINE)
+          .reason,
+      ).toBe("no story tests recorded");
+
+      const partial = { ...storyTests, stories: [storyTests.stories[0]] };
+      expect(
+        validateTransition(
+          "planned",
+          "tests_written",
+          ctx(partial),
+          TDD_PIPELINE,
+        ).reason,
+      ).toBe("stories without tests: US-002");
+
+      const unrun = { ...storyTests, failing_run: null };
+      expect(
+        validateTransition("planned", "tests_written", ctx(unrun), TDD_PIPELINE)
+          .reason,
+      ).toContain("have not been seen failing");
+    });
+  });
+
+  it("getStoriesWithoutTests ignores entries with no test names", () => {
+    const empty = {
+      ...storyTests,
+      stories: [{ ...storyTests.stories[0], tests: [] }],
+    };
+    expect(getStoriesWithoutTests(prd, empty)).toEqual(["US-001", "US-002"]);
+  });
+
+  describe("checkTestsFailBeforeImplementation", () => {
+    it("accepts a run where tests ran and failed", () => {
+      const outcomes = [
+        { name: "exports csv", status: "failed" as const },
+        { name: "exports json", status: "passed" as const },
+      ];
+      expect(checkTestsFailBeforeImplementation(outcomes, false)).toBeNull();
+      expect(checkTestsFailBeforeImplementation([], false)).toBeNull();
+    });
+
+    it("rejects passing tests and runs where no test failed", () => {
+      expect(checkTestsFailBeforeImplementation([], true)).toContain(
+        "already pass",
+      );
+      expect(
+        checkTestsFailBeforeImplementation(
+          [{ name: "exports csv", status: "passed" }],
+          false,
+        ),
+      ).toContain("may not compile");
+    });
+  });
+
+  it("formatStoryTests lists each story's tests for the implement prompt", () => {
+    expect(formatStoryTests(storyTests)).toBe(
+      [
+        "- **US-001** (`src/__tests__/export.test.ts`)",
+        "  - exports csv",
+        "  - escapes commas",
+        "- **US-002** (`src/__tests__/export.test.ts`)",
+        "  - exports json",
+      ].join("\n"),
+    );
+  });
+
+  describe("applyRegression", () => {
+    const item: Item = {
+      schema_version: 1,
+      id: "001-test",
+      title: "Test",
+      state: "implementing",
+      overview: "",
+      branch: null,
+      pr_url: null,
+      pr_number: null,
+      last_error: null,
+      created_at: "2025-01-01T00:00:00Z",
+      updated_at: "2025-01-01T00:00:00Z",
+      story_tests: storyTests,
+    };
+    const options = {
+      reason: "redo",
+      actor: "human",
+      pipeline: TDD_PIPELINE,
+      now: "2025-01-02T00:00:00Z",
+    };
+
+    it("keeps story tests while the plan stands", () => {
+      const result = applyRegression(item, "planned", options);
+      if ("error" in result) throw new Error(result.error);
+      expect(result.nextItem.story_tests).toEqual(storyTests);
+    });
+
+    it("drops story tests when the item is replanned", () => {
+      const result = applyRegression(item, "researched", options);
+      if ("error" in result) throw new Error(result.error);
+      expect(result.nextItem.story_tests).toBeNull();
+    });
+  });
+});
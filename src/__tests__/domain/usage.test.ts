@@ -0,0 +1,130 @@
+import { describe, expect, it } from "bun:test";
+import type { AgentUsage, ModelPrice, UsageTotals } from "../../schemas";
+import {
+  addRunUsage,
+  computeCost,
+  emptyUsageTotals,
+  findModelPrice,
+  formatTokens,
+  formatUsage,
+  getItemUsage,
+  getUnpricedModels,
+} from "../../domain";
+
+const PRICES: Record<string, ModelPrice> = {
+  "claude-sonnet-4": { input: 3, output: 15, cache_read: 0.3 },
+  "claude-sonnet-4-5": { input: 4, output: 20 },
+};
+
+function usage(overrides: Partial<AgentUsage> = {}): AgentUsage {
+  return {
+    input_tokens: 1_000_000,
+    output_tokens: 100_000,
+    cache_read_tokens: 0,
+    cache_write_tokens: 0,
+    model: "claude-sonnet-4-20250514",
+    ...overrides,
+  };
+}
+
+describe("findModelPrice", () => {
+  it("prefers an exact entry, then the longest matching prefix", () => {
+    expect(findModelPrice("claude-sonnet-4", PRICES)).toBe(
+      PRICES["claude-sonnet-4"],
+    );
+    expect(findModelPrice("claude-sonnet-4-5-20250929", PRICES)).toBe(
+      PRICES["claude-sonnet-4-5"],
+    );
+    expect(findModelPrice("gpt-4o", PRICES)).toBeNull();
+    expect(findModelPrice(null, PRICES)).toBeNull();
+    expect(findModelPrice("claude-sonnet-4", undefined)).toBeNull();
+  });
+});
+
+describe("computeCost", () => {
+  it("prices tokens per million, with cache reads at their own rate", () => {
+    expect(computeCost(usage(), PRICES)).toBeCloseTo(3 + 1.5);
+    expect(
+      computeCost(
+        usage({ input_tokens: 0, output_tokens: 0, cache_read_tokens: 1e6 }),
+        PRICES,
+      ),
+    ).toBeCloseTo(0.3);
+  });
+
+  it("charges cache writes at the input price when none is set", () => {
+    expect(
+      computeCost(
+        usage({ input_tokens: 0, output_tokens: 0, cache_write_tokens: 1e6 }),
+        PRICES,
+      ),
+    ).toBeCloseTo(3);
+  });
+
+  it("returns null for unpriced models", () => {
+    expect(computeCost(usage({ model: "gpt-4o" }), PRICES)).toBeNull();
+  });
+});
+
+describe("addRunUsage", () => {
+  it("accumulates tokens, cost, runs and models", () => {
+    let totals = addRunUsage(undefined, usage(), PRICES);
+    totals = addRunUsage(totals, usage({ model: "gpt-4o" }), PRICES);
+
+    expect(totals.input_tokens).toBe(2_000_000);
+    expect(totals.output_tokens).toBe(200_000);
+    expect(totals.cost_usd).toBeCloseTo(4.5);
+    expect(totals.runs).toBe(2);
+    expect(totals.models).toEqual(["claude-sonnet-4-20250514", "gpt-4o"]);
+    expect(getUnpricedModels(totals, PRICES)).toEqual(["gpt-4o"]);
+  });
+});
+
+describe("getItemUsage", () => {
+  it("sums the item's phases", () => {
+    const research: UsageTotals = {
+      ...emptyUsageTotals(),
+      input_tokens: 10,
+      cost_usd: 0.5,
+      runs: 1,
+      models: ["a"],
+    };
+    const implement: UsageTotals = {
+      ...emptyUsageTotals(),
+      input_tokens: 5,
+      cost_usd: 0.25,
+      runs: 3,
+      models: ["a", "b"],
+    };
+
+    expect(getItemUsage({ usage: { research, implement } })).toEqual({
+      ...emptyUsageTotals(),
+      input_tokens: 15,
+      cost_usd: 0.75,
+      runs: 4,
+      models: ["a", "b"],
+    });
+    expect(getItemUsage({})).toBeNull();
+  });
+});
+
+describe("formatUsage", () => {
+  it("abbreviates token counts", () => {
+    expect(formatTokens(950)).toBe("950");
+    expect(formatTokens(12_345)).toBe("12.3k");
+    expect(formatTokens(1_250_000)).toBe("1.3M");
+  });
+
+  it("summarizes tokens and cost on one line", () => {
+    expect(
+      formatUsage({
+        input_tokens: 12_300,
+        output_tokens: 4_100,
+        cache_read_tokens: 70_000,
+        cache_write_tokens: 10_000,
+        cost_usd: 0.4212,
+      }),
+    ).toBe("12.3k in, 4.1k out, 80.0k cached, $0.42");
+    expect(formatUsage(emptyUsageTotals())).toBe("0 in, 0 out, $0.00");
+  });
+});
@@ -94,6 +94,113 @@ describe("git/quality", () => {
     });
   });
 
+  describe("runTestCommand", () => {
+    it("reports a failing command with its exit code", async () => {
+      const result = await gitModule.runTestCommand("false", {
+        cwd: tempDir,
+        logger: mockLogger,
+      });
+
+      expect(result.passed).toBe(false);
+      expect(result.exitCode).not.toBe(0);
+      expect(result.error).toBeUndefined();
+    });
+
+    it("reports a passing command with its output", async () => {
+      const result = await gitModule.runTestCommand("echo reproduced", {
+        cwd: tempDir,
+        logger: mockLogger,
+      });
+
+      expect(result.passed).toBe(true);
+      expect(result.exitCode).toBe(0);
+      expect(result.output).toContain("reproduced");
+    });
+
+    it("does not count a command that cannot start as a failure", async () => {
+      const result = await gitModule.runTestCommand(
+        "wreckit-no-such-test-runner --run",
+        { cwd: tempDir, logger: mockLogger },
+      );
+
+      expect(result.passed).toBe(false);
+      expect(result.error).toBeDefined();
+    });
+  });
+
+  describe("test reports", () => {
+    it("parses TAP output including subtests and directives", () => {
+      const tap = [
+        "TAP version 13",
+        "# Subtest: math",
+        "    ok 1 - adds # time=3ms",
+        "    not ok 2 - divides",
+        "ok 3 - issue \\#12",
+        "ok 4 - later # SKIP not yet",
+        "not ok 5 - wip # TODO",
+        "1..5",
+      ].join("\n");
+
+      expect(gitModule.parseTapOutput(tap)).toEqual([
+        { name: "adds", status: "passed" },
+        { name: "divides", status: "failed" },
+        { name: "issue #12", status: "passed" },
+        { name: "later", status: "skipped" },
+        { name: "wip", status: "skipped" },
+      ]);
+    });
+
+    it("ignores output without a TAP plan", () => {
+      expect(gitModule.parseTapOutput("ok looks fine")).toEqual([]);
+    });
+
+    it("parses JUnit XML test cases", () => {
+      const xml = `<?xml version="1.0"?>
+<testsuites>
+  <testsuite name="math">
+    <testcase classname="math" name="adds &amp; sums" time="0.1"/>
+    <testcase classname="math" name="divides"><failure>boom</failure></testcase>
+    <testcase name="later"><skipped/></testcase>
+    <testcase name="crashes"><error message="x"/></testcase>
+  </testsuite>
+</testsuites>`;
+
+      expect(gitModule.parseTestOutput(xml)).toEqual([
+        { name: "math > adds & sums", status: "passed" },
+        { name: "math > divides", status: "failed" },
+        { name: "later", status: "skipped" },
+        { name: "crashes", status: "failed" },
+      ]);
+    });
+  });
+
+  describe("runQualityChecks", () => {
+    it("reports per-test outcomes from TAP output", async () => {
+      await fs.writeFile(
+        path.join(tempDir, "tap.sh"),
+        'echo "ok 1 - works"\necho "not ok 2 - breaks"\necho "1..2"\n',
+      );
+
+      const result = await gitModule.runQualityChecks({
+        cwd: tempDir,
+        logger: mockLogger,
+        checks: {
+          commands: ["sh tap.sh", "true"],
+          secret_scan: false,
+          require_all_stories_done: false,
+          allow_unsafe_direct_merge: false,
+          allowed_remote_patterns: [],
+        },
+      });
+
+      expect(result.tests).toEqual([
+        { name: "works", status: "passed" },
+        { name: "breaks", status: "failed" },
+        { name: "true", status: "passed" },
+      ]);
+    });
+  });
+
   describe("scanForSecrets", () => {
     it("detects private keys", () => {
       const diff = `
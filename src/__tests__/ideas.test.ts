@@ -391,6 +391,38 @@ describe("persistItems", () => {
     expect(entries).toContain("002-fix-bug");
     expect(entries).toContain("003-update-ci");
   });
+
+  it("creates bug items under the bug pipeline", async () => {
+    const ideas: ParsedIdea[] = [{ title: "Fix login crash", description: "" }];
+
+    const result = await persistItems(tempDir, ideas, { kind: "bug" });
+
+    expect(result.created[0].kind).toBe("bug");
+    expect(result.created[0].pipeline).toBe("bug");
+  });
+
+  it("creates items under the default pipeline of the given config", async () => {
+    const ideas: ParsedIdea[] = [{ title: "Tidy docs", description: "" }];
+
+    const result = await persistItems(tempDir, ideas, {
+      config: {
+        pipelines: {
+          quick: {
+            stages: [
+              { state: "idea" },
+              { state: "planned", phase: "plan" },
+              { state: "implementing", phase: "implement" },
+              { state: "in_pr", phase: "pr" },
+              { state: "done", phase: "complete" },
+            ],
+          },
+        },
+        default_pipeline: "quick",
+      },
+    });
+
+    expect(result.created[0].pipeline).toBe("quick");
+  });
 });
 
 describe("ingestIdeas integration", () => {
@@ -0,0 +1,319 @@
+import {
+  describe,
+  expect,
+  it,
+  beforeEach,
+  afterEach,
+  mock,
+  vi,
+} from "bun:test";
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import * as os from "node:os";
+import {
+  getStoryBranchName,
+  implementStoriesInParallel,
+  type ParallelStoriesOptions,
+} from "../workflow/parallelStories";
+import { runWorkerPool } from "../workflow/workerPool";
+import { readPrd } from "../fs/json";
+import { pathExists } from "../fs/util";
+import { branchExists } from "../git";
+import { StoryMergeConflictError } from "../errors";
+import { DEFAULT_CONFIG, type ConfigResolved } from "../config";
+import type { Logger } from "../logging";
+import type { Item, Prd, Story } from "../schemas";
+
+const mockedRunAgentUnion = vi.fn();
+
+mock.module("../agent/runner", () => ({
+  runAgent: vi.fn(),
+  getAgentConfig: vi.fn(),
+  runAgentUnion: mockedRunAgentUnion,
+  getAgentConfigUnion: (config: ConfigResolved) => config.agent,
+}));
+
+const { runPhaseImplement } = await import("../workflow");
+
+function createMockLogger() {
+  return {
+    debug: vi.fn(),
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    json: vi.fn(),
+  } satisfies Logger;
+}
+
+function makeStory(id: string, overrides: Partial<Story> = {}): Story {
+  return {
+    id,
+    title: `Story ${id}`,
+    acceptance_criteria: ["works"],
+    priority: 1,
+    status: "pending",
+    notes: "",
+    ...overrides,
+  };
+}
+
+describe("runWorkerPool", () => {
+  it("never runs more items than the concurrency limit", async () => {
+    let running = 0;
+    let peak = 0;
+    const done: number[] = [];
+
+    const blocked = await runWorkerPool([1, 2, 3, 4, 5], {
+      concurrency: 2,
+      isReady: () => true,
+      run: async (n) => {
+        running++;
+        peak = Math.max(peak, running);
+        await new Promise((resolve) => setTimeout(resolve, 5));
+        running--;
+        done.push(n);
+      },
+    });
+
+    expect(blocked).toEqual([]);
+    expect(peak).toBe(2);
+    expect(done.sort()).toEqual([1, 2, 3, 4, 5]);
+  });
+
+  it("starts items once they become ready and returns the rest", async () => {
+    const done = new Set<string>();
+    const deps: Record<string, string[]> = {
+      a: [],
+      b: ["a"],
+      c: ["missing"],
+    };
+
+    const blocked = await runWorkerPool(["b", "c", "a"], {
+      concurrency: 3,
+      isReady: (id) => deps[id].every((dep) => done.has(dep)),
+      run: async (id) => {
+        done.add(id);
+      },
+    });
+
+    expect([...done]).toEqual(["a", "b"]);
+    expect(blocked).toEqual(["c"]);
+  });
+});
+
+describe("implementStoriesInParallel", () => {
+  const itemId = "001-test";
+  const itemBranch = "wreckit/001-test";
+  let root: string;
+  let itemDir: string;
+
+  async function writePrd(stories: Story[]) {
+    const prd: Prd = {
+      schema_version: 1,
+      id: itemId,
+      branch_name: itemBranch,
+      user_stories: stories,
+    };
+    await fs.writeFile(
+      path.join(itemDir, "prd.json"),
+      JSON.stringify(prd, null, 2),
+    );
+    await Bun.$`cd ${root} && git add -A && git commit -m prd`.quiet();
+  }
+
+  function makeOptions(
+    runStory: ParallelStoriesOptions["runStory"],
+  ): ParallelStoriesOptions {
+    return {
+      root,
+      itemId,
+      itemBranch,
+      maxWorkers: 2,
+      maxIterations: 10,
+      logger: createMockLogger(),
+      runStory,
+    };
+  }
+
+  beforeEach(async () => {
+    root = await fs.mkdtemp(path.join(os.tmpdir(), "wreckit-parallel-test-"));
+    itemDir = path.join(root, ".wreckit", "items", itemId);
+    await fs.mkdir(itemDir, { recursive: true });
+    await fs.writeFile(path.join(root, "shared.txt"), "base\n");
+    await Bun.$`cd ${root} && git init -b main`.quiet();
+    await Bun.$`cd ${root} && git config user.email "test@test.com" && git config user.name "Test"`.quiet();
+    await Bun.$`cd ${root} && git add -A && git commit -m init`.quiet();
+    await Bun.$`cd ${root} && git checkout -b ${itemBranch}`.quiet();
+  });
+
+  afterEach(async () => {
+    await fs.rm(root, { recursive: true, force: true });
+  });
+
+  it("merges independent stories and marks them done", async () => {
+    await writePrd([makeStory("US-001"), makeStory("US-002")]);
+
+    const result = await implementStoriesInParallel(
+      makeOptions(async (story, worktree) => {
+        await fs.writeFile(path.join(worktree.path, `${story.id}.txt`), "x");
+        return { success: true, status: "done" };
+      }),
+    );
+
+    expect(result).toEqual({ success: true, iterations: 2 });
+    expect(await pathExists(path.join(root, "US-001.txt"))).toBe(true);
+    expect(await pathExists(path.join(root, "US-002.txt"))).toBe(true);
+    const prd = await readPrd(itemDir);
+    expect(prd.user_stories.map((s) => s.status)).toEqual(["done", "done"]);
+    expect(
+      await branchExists(getStoryBranchName(itemBranch, "US-001"), {
+        cwd: root,
+        logger: createMockLogger(),
+      }),
+    ).toBe(false);
+  });
+
+  it("merges stories that both log progress", async () => {
+    const progressPath = path.join(itemDir, "progress.log");
+    await fs.writeFile(progressPath, "# Progress\n");
+    await writePrd([makeStory("US-001"), makeStory("US-002")]);
+
+    const result = await implementStoriesInParallel(
+      makeOptions(async (story, worktree) => {
+        await fs.writeFile(path.join(worktree.path, `${story.id}.txt`), "x");
+        await fs.appendFile(
+          path.join(worktree.itemDir, "progress.log"),
+          `Notes from ${story.id}\n`,
+        );
+        await Bun.$`cd ${worktree.path} && git add -A && git commit -m ${story.id}`.quiet();
+        return { success: true, status: "done" };
+      }),
+    );
+
+    expect(result).toEqual({ success: true, iterations: 2 });
+    const progress = await fs.readFile(progressPath, "utf-8");
+    expect(progress).toMatch(/^# Progress\n/);
+    for (const id of ["US-001", "US-002"]) {
+      expect(progress).toContain(`Notes from ${id}\n`);
+      expect(progress).toContain(`Merged story ${id}`);
+    }
+    const prd = await readPrd(itemDir);
+    expect(prd.user_stories.map((s) => s.status)).toEqual(["done", "done"]);
+    const status = await Bun.$`cd ${root} && git status --porcelain`.text();
+    expect(status).toBe("");
+  });
+
+  it("starts dependent stories from the merged item branch", async () => {
+    await writePrd([
+      makeStory("US-001"),
+      makeStory("US-002", { depends_on: ["US-001"] }),
+    ]);
+    const seen: Record<string, boolean> = {};
+
+    const result = await implementStoriesInParallel(
+      makeOptions(async (story, worktree) => {
+        seen[story.id] = await pathExists(
+          path.join(worktree.path, "US-001.txt"),
+        );
+        await fs.writeFile(path.join(worktree.path, `${story.id}.txt`), "x");
+        return { success: true, status: "done" };
+      }),
+    );
+
+    expect(result.success).toBe(true);
+    expect(seen).toEqual({ "US-001": false, "US-002": true });
+  });
+
+  it("reports a merge conflict and keeps the story branch", async () => {
+    await writePrd([makeStory("US-001"), makeStory("US-002")]);
+
+    const result = await implementStoriesInParallel(
+      makeOptions(async (story, worktree) => {
+        await fs.writeFile(path.join(worktree.path, "shared.txt"), story.id);
+        return { success: true, status: "done" };
+      }),
+    );
+
+    expect(result.success).toBe(false);
+    expect(result.conflict).toBeInstanceOf(StoryMergeConflictError);
+    expect(result.conflict?.conflictedFiles).toEqual(["shared.txt"]);
+
+    const conflicted = result.conflict!.storyId;
+    const prd = await readPrd(itemDir);
+    expect(prd.user_stories.find((s) => s.id === conflicted)?.status).toBe(
+      "pending",
+    );
+    expect(
+      await branchExists(getStoryBranchName(itemBranch, conflicted), {
+        cwd: root,
+        logger: createMockLogger(),
+      }),
+    ).toBe(true);
+  });
+
+  it("stops scheduling after a story fails", async () => {
+    await writePrd([
+      makeStory("US-001"),
+      makeStory("US-002", { depends_on: ["US-001"] }),
+    ]);
+    const runStory = vi.fn(async () => ({
+      success: false,
+      error: "Agent timed out",
+      status: null,
+    }));
+
+    const result = await implementStoriesInParallel(makeOptions(runStory));
+
+    expect(result).toEqual({
+      success: false,
+      iterations: 1,
+      error: "Story US-001: Agent timed out",
+    });
+    expect(runStory).toHaveBeenCalledTimes(1);
+  });
+
+  it("points each story's agent at its own worktree", async () => {
+    const item: Item = {
+      schema_version: 1,
+      id: itemId,
+      title: "Test",
+      state: "planned",
+      overview: "A test item",
+      branch: itemBranch,
+      pr_url: null,
+      pr_number: null,
+      last_error: null,
+      created_at: "2025-01-12T00:00:00Z",
+      updated_at: "2025-01-12T00:00:00Z",
+      rollback_sha: null,
+    };
+    await fs.writeFile(
+      path.join(itemDir, "item.json"),
+      JSON.stringify(item, null, 2),
+    );
+    await writePrd([makeStory("US-001")]);
+    mockedRunAgentUnion.mockResolvedValue({
+      success: false,
+      output: "Tests failed",
+      timedOut: false,
+      exitCode: 1,
+      completionDetected: false,
+    });
+
+    const result = await runPhaseImplement(itemId, {
+      root,
+      config: {
+        ...DEFAULT_CONFIG,
+        parallel_stories: { enabled: true, max_workers: 1 },
+      },
+      logger: createMockLogger(),
+    });
+
+    expect(result.success).toBe(false);
+    expect(mockedRunAgentUnion).toHaveBeenCalledTimes(1);
+    const { cwd, prompt } = mockedRunAgentUnion.mock.calls[0][0];
+    expect(cwd).not.toBe(itemDir);
+    expect(prompt).toContain(cwd);
+    expect(prompt).not.toContain(itemDir);
+  });
+});
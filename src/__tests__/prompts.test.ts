@@ -40,6 +40,29 @@ describe("loadPromptTemplate", () => {
     expect(result).toBe(defaultTemplate);
   });
 
+  it("prefers bundled kind variants over the bundled base", async () => {
+    const result = await loadPromptTemplate(tempDir, "implement", "bug");
+
+    expect(result).toBe(await getDefaultTemplate("implement-bug"));
+  });
+
+  it("prefers a project base template over bundled kind variants", async () => {
+    const promptsDir = path.join(tempDir, ".wreckit", "prompts");
+    await fs.mkdir(promptsDir, { recursive: true });
+    const customContent = "# Custom Implement Template\n{{id}}";
+    await fs.writeFile(path.join(promptsDir, "implement.md"), customContent);
+
+    expect(await loadPromptTemplate(tempDir, "implement", "bug")).toBe(
+      customContent,
+    );
+    expect(await loadPromptTemplate(tempDir, "implement", "refactor")).toBe(
+      customContent,
+    );
+
+    await fs.writeFile(path.join(promptsDir, "implement-bug.md"), "bug");
+    expect(await loadPromptTemplate(tempDir, "implement", "bug")).toBe("bug");
+  });
+
   it("works for all template names", async () => {
     const names: PromptName[] = [
       "research",
@@ -1,6 +1,10 @@
 import { describe, it, expect } from "bun:test";
+import type { Prd, Story } from "../schemas";
 import {
   validateStoryQuality,
+  validateStoryDependencies,
+  findStoryDependencyCycles,
+  getNextStory,
   verifyStoryCompletion,
   DEFAULT_STORY_QUALITY_OPTIONS,
   type StoryQualityOptions,
@@ -895,3 +899,75 @@ describe("Story Quality Validation (Gap 3)", () => {
     });
   });
 });
+
+describe("Story dependencies", () => {
+  function story(id: string, overrides: Partial<Story> = {}): Story {
+    return {
+      id,
+      title: `Story ${id}`,
+      acceptance_criteria: ["Criterion 1", "Criterion 2"],
+      priority: 1,
+      status: "pending",
+      notes: "",
+      ...overrides,
+    };
+  }
+
+  function prdOf(stories: Story[]): Prd {
+    return {
+      schema_version: 1,
+      id: "001-test",
+      branch_name: "wreckit/001-test",
+      user_stories: stories,
+    };
+  }
+
+  it("detects cycles, including self-references", () => {
+    const cycles = findStoryDependencyCycles([
+      story("US-001", { depends_on: ["US-002"] }),
+      story("US-002", { depends_on: ["US-001"] }),
+      story("US-003", { depends_on: ["US-003"] }),
+    ]);
+    expect(cycles).toEqual([
+      ["US-001", "US-002", "US-001"],
+      ["US-003", "US-003"],
+    ]);
+  });
+
+  it("reports unknown references", () => {
+    const errors = validateStoryDependencies([
+      story("US-001", { depends_on: ["US-009"] }),
+    ]);
+    expect(errors).toEqual(['Story "US-001" depends on unknown story "US-009"']);
+  });
+
+  it("fails story quality validation on a cyclic PRD", () => {
+    const result = validateStoryQuality({
+      user_stories: [
+        story("US-001", { depends_on: ["US-002"] }),
+        story("US-002", { depends_on: ["US-001"] }),
+      ],
+    });
+    expect(result.valid).toBe(false);
+    expect(result.errors.some((e) => e.includes("Circular story"))).toBe(true);
+  });
+
+  it("getNextStory picks the best story whose dependencies are done", () => {
+    const prd = prdOf([
+      story("US-001", { priority: 2 }),
+      story("US-003", { priority: 1, depends_on: ["US-001"] }),
+    ]);
+    expect(getNextStory(prd)?.id).toBe("US-001");
+
+    prd.user_stories[0].status = "done";
+    expect(getNextStory(prd)?.id).toBe("US-003");
+  });
+
+  it("getNextStory returns null when every pending story is waiting", () => {
+    const prd = prdOf([
+      story("US-001", { depends_on: ["US-002"] }),
+      story("US-002", { depends_on: ["US-001"] }),
+    ]);
+    expect(getNextStory(prd)).toBeNull();
+  });
+});
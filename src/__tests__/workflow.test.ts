@@ -15,8 +15,10 @@ import type { Item, Prd } from "../schemas";
 import type { ConfigResolved } from "../config";
 import type { Logger } from "../logging";
 import type { AgentResult } from "../agent/runner";
+import type { WreckitError } from "../errors";
 // Import real git module for passthrough in mock
 import * as gitModule from "../git";
+import { SPIKE_PIPELINE, TDD_PIPELINE } from "../domain/pipeline";
 
 const mockedRunAgentUnion = vi.fn();
 const mockedGetAgentConfigUnion = vi.fn(
@@ -146,13 +148,26 @@ mock.module("../git", () => ({
   isDetachedHead: gitModule.isDetachedHead,
   hasRemote: gitModule.hasRemote,
   getBranchSyncStatus: gitModule.getBranchSyncStatus,
+  getBranchDiff: gitModule.getBranchDiff,
+  // Worktree helpers used by parallel story implementation
+  addWorktree: gitModule.addWorktree,
+  removeWorktree: gitModule.removeWorktree,
+  mergeBranch: gitModule.mergeBranch,
+  validateStoryScope: gitModule.validateStoryScope,
+  // Runs bug reproduction tests
+  runTestCommand: gitModule.runTestCommand,
+  parseJUnitXml: gitModule.parseJUnitXml,
+  parseTapOutput: gitModule.parseTapOutput,
+  parseTestOutput: gitModule.parseTestOutput,
 }));
 
 const {
   buildValidationContext,
   runPhaseResearch,
+  runPhaseReproduce,
   runPhasePlan,
   runPhaseImplement,
+  runPhaseCritique,
   runPhasePr,
   runPhaseComplete,
   getNextPhase,
@@ -604,6 +619,53 @@ None.
     });
   });
 
+  describe("runPhaseResearch for spikes", () => {
+    it("reruns research when report.md is missing", async () => {
+      const item = createTestItem({
+        state: "idea",
+        kind: "spike",
+        pipeline: "spike",
+      });
+      const itemDir = await setupItem(item);
+      await fs.writeFile(path.join(itemDir, "research.md"), "# Research");
+      mockedRunAgentUnion.mockImplementation(
+        createMockAgentResult({ success: false }, itemDir),
+      );
+
+      const result = await runPhaseResearch(item.id, {
+        root: tempDir,
+        config,
+        logger: mockLogger,
+        noHealing: true,
+      });
+
+      expect(mockedRunAgentUnion).toHaveBeenCalled();
+      expect(result.success).toBe(false);
+      expect((await readItemState(item.id)).state).toBe("idea");
+    });
+
+    it("skips research once research.md and report.md exist", async () => {
+      const item = createTestItem({
+        state: "idea",
+        kind: "spike",
+        pipeline: "spike",
+      });
+      const itemDir = await setupItem(item);
+      await fs.writeFile(path.join(itemDir, "research.md"), "# Research");
+      await fs.writeFile(path.join(itemDir, "report.md"), "# Report");
+
+      const result = await runPhaseResearch(item.id, {
+        root: tempDir,
+        config,
+        logger: mockLogger,
+      });
+
+      expect(mockedRunAgentUnion).not.toHaveBeenCalled();
+      expect(result.success).toBe(true);
+      expect(result.item.state).toBe("researched");
+    });
+  });
+
   describe("runPhasePlan", () => {
     it("transitions from researched to planned on success", async () => {
       const item = createTestItem({ state: "researched" });
@@ -972,6 +1034,35 @@ None.
       expect(result.item.state).toBe("critique");
     });
 
+    it("holds the implementation at its gate after handing off to critique", async () => {
+      const item = createTestItem({ state: "planned" });
+      const itemDir = await setupItem(item);
+      const prd = createTestPrd();
+      prd.user_stories[0].status = "done";
+      await fs.writeFile(
+        path.join(itemDir, "prd.json"),
+        JSON.stringify(prd, null, 2),
+        "utf-8",
+      );
+      mockedRunAgentUnion.mockResolvedValue({
+        success: true,
+        output: "test output",
+        timedOut: false,
+        exitCode: 0,
+        completionDetected: true,
+      });
+
+      const result = await runPhaseImplement(item.id, {
+        root: tempDir,
+        config: { ...config, gates: { implement: "human" } },
+        logger: mockLogger,
+      });
+
+      expect(result.success).toBe(true);
+      expect(result.item.state).toBe("critique");
+      expect(result.item.pending_approval).toBe("implement");
+    });
+
     it("fails when not in planned or implementing state", async () => {
       const item = createTestItem({ state: "idea" });
       await setupItem(item);
@@ -1041,6 +1132,118 @@ None.
       expect(updatedPrd.user_stories[0].status).toBe("done");
     });
 
+    it("runs the mock agent on the next story with --mock-agent", async () => {
+      const item = createTestItem({ state: "planned" });
+      const itemDir = await setupItem(item);
+      await fs.writeFile(
+        path.join(itemDir, "prd.json"),
+        JSON.stringify(createTestPrd(), null, 2),
+        "utf-8",
+      );
+      mockedRunAgentUnion.mockImplementation(
+        createMockAgentResult({}, itemDir),
+      );
+
+      const result = await runPhaseImplement(item.id, {
+        root: tempDir,
+        config,
+        logger: mockLogger,
+        mockAgent: true,
+      });
+
+      expect(result.success).toBe(true);
+      expect(result.item.state).toBe("implementing");
+      expect(mockedRunAgentUnion).toHaveBeenCalledTimes(1);
+      expect(mockedRunAgentUnion.mock.calls[0][0].prompt).toContain(
+        "US-001: First story",
+      );
+    });
+
+    describe("refactor items", () => {
+      const TEST_SCRIPT = [
+        'echo "TAP version 13"',
+        'echo "ok 1 - keeps totals"',
+        'if [ -f broken ]; then echo "not ok 2 - formats dates"; else echo "ok 2 - formats dates"; fi',
+        'echo "1..2"',
+      ].join("\n");
+
+      async function setupRefactor(breaksTests: boolean): Promise<string> {
+        const item = createTestItem({ state: "planned", kind: "refactor" });
+        const itemDir = await setupItem(item);
+        await fs.writeFile(
+          path.join(itemDir, "prd.json"),
+          JSON.stringify(createTestPrd(), null, 2),
+          "utf-8",
+        );
+        await fs.writeFile(path.join(tempDir, "tests.sh"), TEST_SCRIPT);
+        config = {
+          ...config,
+          pr_checks: { ...config.pr_checks, commands: ["sh tests.sh"] },
+        };
+
+        mockedRunAgentUnion.mockImplementation(async () => {
+          const prdPath = path.join(itemDir, "prd.json");
+          const currentPrd = JSON.parse(
+            await fs.readFile(prdPath, "utf-8"),
+          ) as Prd;
+          currentPrd.user_stories[0].status = "done";
+          await fs.writeFile(prdPath, JSON.stringify(currentPrd, null, 2));
+          if (breaksTests) {
+            await fs.writeFile(path.join(tempDir, "broken"), "");
+          }
+          return {
+            success: true,
+            output: "test output",
+            timedOut: false,
+            exitCode: 0,
+            completionDetected: true,
+          };
+        });
+        return itemDir;
+      }
+
+      it("records a per-test baseline and passes when behavior is kept", async () => {
+        const itemDir = await setupRefactor(false);
+
+        const result = await runPhaseImplement("001-test-feature", {
+          root: tempDir,
+          config,
+          logger: mockLogger,
+        });
+
+        expect(result.success).toBe(true);
+        const baseline = JSON.parse(
+          await fs.readFile(path.join(itemDir, "test-baseline.json"), "utf-8"),
+        );
+        expect(baseline.tests).toEqual([
+          { name: "keeps totals", status: "passed" },
+          { name: "formats dates", status: "passed" },
+        ]);
+      });
+
+      it("blocks a story that breaks a previously passing test", async () => {
+        const itemDir = await setupRefactor(true);
+
+        const result = await runPhaseImplement("001-test-feature", {
+          root: tempDir,
+          config,
+          logger: mockLogger,
+        });
+
+        expect(result.success).toBe(false);
+        expect((result.error as WreckitError).code).toBe(
+          "BEHAVIOR_REGRESSION",
+        );
+        expect(result.item.last_error).toContain("formats dates");
+
+        const prd = JSON.parse(
+          await fs.readFile(path.join(itemDir, "prd.json"), "utf-8"),
+        ) as Prd;
+        expect(prd.user_stories[0].status).toBe("pending");
+        expect(prd.user_stories[0].notes).toContain("formats dates");
+      });
+    });
+
     it("appends to progress.log", async () => {
       const prd = createTestPrd();
       const item = createTestItem({ state: "planned" });
@@ -1476,6 +1679,139 @@ None.
     });
   });
 
+  describe("runPhaseCritique", () => {
+    async function setupImplemented(): Promise<string> {
+      const item = createTestItem({ state: "implementing" });
+      const itemDir = await setupItem(item);
+      const prd = createTestPrd();
+      prd.user_stories[0].status = "done";
+      await fs.writeFile(
+        path.join(itemDir, "prd.json"),
+        JSON.stringify(prd, null, 2),
+        "utf-8",
+      );
+      return itemDir;
+    }
+
+    it("retries a failed critic once, then fails without regressing", async () => {
+      const itemDir = await setupImplemented();
+      mockedRunAgentUnion.mockResolvedValue({
+        success: true,
+        output: "Looks fine to me",
+        timedOut: false,
+        exitCode: 0,
+        completionDetected: true,
+      });
+
+      const result = await runPhaseCritique("001-test-feature", {
+        root: tempDir,
+        config,
+        logger: mockLogger,
+      });
+
+      expect(result.success).toBe(false);
+      expect(result.error).toBe("Critic failed to output valid JSON decision");
+      expect(mockedRunAgentUnion).toHaveBeenCalledTimes(2);
+      expect((await readItemState("001-test-feature")).state).toBe(
+        "implementing",
+      );
+      const prd = JSON.parse(
+        await fs.readFile(path.join(itemDir, "prd.json"), "utf-8"),
+      ) as Prd;
+      expect(prd.user_stories[0].status).toBe("done");
+    });
+
+    it("uses the retried critic's decision", async () => {
+      await setupImplemented();
+      mockedRunAgentUnion
+        .mockResolvedValueOnce({
+          success: false,
+          output: "",
+          timedOut: true,
+          exitCode: null,
+          completionDetected: false,
+        })
+        .mockResolvedValueOnce({
+          success: true,
+          output: '```json\n{"status": "approved", "reason": "ok", "critique": "fine"}\n```',
+          timedOut: false,
+          exitCode: 0,
+          completionDetected: true,
+        });
+
+      const result = await runPhaseCritique("001-test-feature", {
+        root: tempDir,
+        config,
+        logger: mockLogger,
+      });
+
+      expect(result.success).toBe(true);
+      expect(result.item.state).toBe("critique");
+    });
+
+    it("holds an approved critique at its gate", async () => {
+      await setupImplemented();
+      mockedRunAgentUnion.mockResolvedValue({
+        success: true,
+        output: '```json\n{"status": "approved", "reason": "ok", "critique": "fine"}\n```',
+        timedOut: false,
+        exitCode: 0,
+        completionDetected: true,
+      });
+
+      const result = await runPhaseCritique("001-test-feature", {
+        root: tempDir,
+        config: { ...config, gates: { critique: "human" } },
+        logger: mockLogger,
+      });
+
+      expect(result.success).toBe(true);
+      expect(result.item.pending_approval).toBe("critique");
+    });
+  });
+
+  describe("bug items with --mock-agent", () => {
+    it("runs reproduce, plan and implement", async () => {
+      const item = createTestItem({
+        state: "researched",
+        kind: "bug",
+        pipeline: "bug",
+      });
+      const itemDir = await setupItem(item);
+      await fs.writeFile(
+        path.join(itemDir, "research.md"),
+        "# Research",
+        "utf-8",
+      );
+      mockedRunAgentUnion.mockResolvedValue({
+        success: true,
+        output: "mock output",
+        timedOut: false,
+        exitCode: 0,
+        completionDetected: true,
+      });
+      const options = {
+        root: tempDir,
+        config,
+        logger: mockLogger,
+        mockAgent: true,
+      };
+
+      const reproduced = await runPhaseReproduce(item.id, options);
+      expect(reproduced.success).toBe(true);
+      expect(reproduced.item.state).toBe("reproduced");
+      expect(reproduced.item.reproduction?.command).toBe("mock-reproduction");
+
+      const planned = await runPhasePlan(item.id, options);
+      expect(planned.success).toBe(true);
+      expect(planned.item.state).toBe("planned");
+
+      const implemented = await runPhaseImplement(item.id, options);
+      expect(implemented.success).toBe(true);
+      expect(implemented.item.last_error).toBeNull();
+    });
+  });
+
   describe("runPhasePr", () => {
     it("fails when not all stories done", async () => {
       const prd = createTestPrd();
@@ -1843,6 +2179,50 @@ None.
         expect.stringContaining("CI checks did not pass"),
       );
     });
+
+    it("completes a spike from researched with its report and no PR", async () => {
+      const item = createTestItem({
+        state: "researched",
+        kind: "spike",
+        pipeline: "spike",
+      });
+      const itemDir = await setupItem(item);
+      await fs.writeFile(
+        path.join(itemDir, "report.md"),
+        "# Spike Report: Test\n\n## Findings\nx\n## Options\ny\n## Recommendation\nz\n",
+      );
+
+      const result = await runPhaseComplete(item.id, {
+        root: tempDir,
+        config,
+        logger: mockLogger,
+      });
+
+      expect(result.success).toBe(true);
+      expect(result.item.state).toBe("done");
+      expect(result.item.completed_at).toBeTruthy();
+      expect(result.item.pr_number).toBeNull();
+      expect(mockedGetPrDetails).not.toHaveBeenCalled();
+    });
+
+    it("does not complete a spike without report.md", async () => {
+      const item = createTestItem({
+        state: "researched",
+        kind: "spike",
+        pipeline: "spike",
+      });
+      await setupItem(item);
+
+      const result = await runPhaseComplete(item.id, {
+        root: tempDir,
+        config,
+        logger: mockLogger,
+      });
+
+      expect(result.success).toBe(false);
+      expect(result.error).toBe("report.md does not exist");
+      expect((await readItemState(item.id)).state).toBe("researched");
+    });
   });
 
   describe("getNextPhase", () => {
@@ -1880,6 +2260,18 @@ None.
       const item = createTestItem({ state: "done" });
       expect(getNextPhase(item)).toBeNull();
     });
+
+    it("spike: researched -> 'complete'", () => {
+      const item = createTestItem({ state: "researched", kind: "spike" });
+      expect(getNextPhase(item, SPIKE_PIPELINE)).toBe("complete");
+    });
+
+    it("tdd: planned -> 'tests' -> 'implement'", () => {
+      const planned = createTestItem({ state: "planned" });
+      expect(getNextPhase(planned, TDD_PIPELINE)).toBe("tests");
+      const written = createTestItem({ state: "tests_written" });
+      expect(getNextPhase(written, TDD_PIPELINE)).toBe("implement");
+    });
   });
 
   describe("runPhasePr - direct mode safeguards (Gap 4)", () => {
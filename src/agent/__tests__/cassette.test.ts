@@ -0,0 +1,208 @@
+/**
+ * Unit tests for recording agent runs and replaying them
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from "bun:test";
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import * as os from "node:os";
+import type { Logger } from "../../logging";
+import type { AgentEvent } from "../../tui/agentEvents";
+import {
+  beginCassette,
+  listCassettes,
+  readCassette,
+  startRecording,
+  stopRecording,
+} from "../cassette";
+import { resetReplayState, runReplayAgent } from "../replay-runner";
+
+function createMockLogger(): Logger {
+  return {
+    debug: vi.fn(),
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    json: vi.fn(),
+  };
+}
+
+const RESULT = {
+  success: true,
+  output: "done\n<promise>COMPLETE</promise>",
+  timedOut: false,
+  exitCode: 0,
+  completionDetected: true,
+};
+
+describe("agent cassettes", () => {
+  let tempDir: string;
+  let cassetteDir: string;
+  let logger: Logger;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), "wreckit-cassette-"));
+    cassetteDir = path.join(tempDir, "cassettes");
+    logger = createMockLogger();
+    await fs.mkdir(path.join(tempDir, ".wreckit"));
+    await fs.writeFile(path.join(tempDir, "README.md"), "hello\n");
+    await fs.writeFile(path.join(tempDir, "old.txt"), "old\n");
+    await Bun.$`cd ${tempDir} && git init`.quiet();
+    await Bun.$`cd ${tempDir} && git config user.email "test@test.com" && git config user.name "Test"`.quiet();
+    await Bun.$`cd ${tempDir} && git add README.md old.txt && git commit -m "init"`.quiet();
+    resetReplayState();
+  });
+
+  afterEach(async () => {
+    stopRecording();
+    await fs.rm(tempDir, { recursive: true, force: true });
+  });
+
+  async function recordRun(prompt: string, saved: string): Promise<void> {
+    const recording = await beginCassette({
+      config: {
+        kind: "claude_sdk",
+        model: "claude-sonnet-4",
+        max_tokens: 8192,
+      },
+      cwd: tempDir,
+      prompt,
+      logger,
+    });
+    const { options } = recording!;
+    options.onStdoutChunk!("working\n");
+    options.onAgentEvent!({
+      type: "tool_started",
+      toolUseId: "t1",
+      toolName: "mcp__wreckit__save_prd",
+      input: { saved },
+    });
+    options.onAgentEvent!({
+      type: "tool_result",
+      toolUseId: "t1",
+      result: "ok",
+    });
+    await fs.writeFile(path.join(tempDir, "README.md"), `${saved}\n`);
+    await fs.writeFile(path.join(tempDir, "image.bin"), Buffer.from([0, 1]));
+    await fs.rm(path.join(tempDir, "old.txt"));
+    await recording!.finish(RESULT);
+  }
+
+  async function resetTree(): Promise<void> {
+    await Bun.$`cd ${tempDir} && git checkout -- . && git clean -fdq -e cassettes`.quiet();
+  }
+
+  it("does nothing unless recording", async () => {
+    const recording = await beginCassette({
+      config: { kind: "claude_sdk", model: "m", max_tokens: 1 },
+      cwd: tempDir,
+      prompt: "p",
+      logger,
+    });
+    expect(recording).toBeNull();
+  });
+
+  it("records the timeline and the files the run changed", async () => {
+    await startRecording(cassetteDir);
+    await recordRun("research item 001", "first");
+
+    const files = await listCassettes(cassetteDir);
+    expect(files.map((file) => path.basename(file))).toEqual([
+      "0001-claude_sdk.json",
+    ]);
+
+    const cassette = await readCassette(files[0]);
+    expect(cassette.prompt).toBe("research item 001");
+    expect(cassette.cwd).toBe("");
+    expect(cassette.timeline.map((entry) => entry.type)).toEqual([
+      "stdout",
+      "event",
+      "event",
+    ]);
+    expect(cassette.file_changes).toEqual([
+      { path: "README.md", content: "first\n", encoding: "utf8" },
+      { path: "image.bin", content: "AAE=", encoding: "base64" },
+      { path: "old.txt", content: null, encoding: "utf8" },
+    ]);
+    expect(cassette.result).toEqual(RESULT);
+  });
+
+  it("replays output, MCP tool calls and file changes", async () => {
+    await startRecording(cassetteDir);
+    await recordRun("plan item 001", "plan");
+    stopRecording();
+    await resetTree();
+
+    const saved: unknown[] = [];
+    const events: AgentEvent[] = [];
+    const chunks: string[] = [];
+    const result = await runReplayAgent({
+      config: { kind: "replay", cassette_dir: "cassettes", strict: true },
+      cwd: tempDir,
+      prompt: "plan item 001",
+      logger,
+      onStdoutChunk: (chunk) => chunks.push(chunk),
+      onAgentEvent: (event) => events.push(event),
+      mcpServers: {
+        wreckit: {
+          tools: [
+            {
+              name: "save_prd",
+              handler: async (args: unknown) => {
+                saved.push(args);
+                return { content: [] };
+              },
+            },
+          ],
+        },
+      },
+    });
+
+    expect(result).toEqual(RESULT);
+    expect(chunks).toEqual(["working\n"]);
+    expect(events.map((event) => event.type)).toEqual([
+      "tool_started",
+      "tool_result",
+    ]);
+    expect(saved).toEqual([{ saved: "plan" }]);
+    expect(await fs.readFile(path.join(tempDir, "README.md"), "utf-8")).toBe(
+      "plan\n",
+    );
+    expect(await fs.readFile(path.join(tempDir, "image.bin"))).toEqual(
+      Buffer.from([0, 1]),
+    );
+    await expect(fs.access(path.join(tempDir, "old.txt"))).rejects.toThrow();
+  });
+
+  it("matches cassettes by prompt and plays each one once", async () => {
+    await startRecording(cassetteDir);
+    await recordRun("first prompt", "one");
+    await resetTree();
+    await recordRun("second prompt", "two");
+    stopRecording();
+
+    const config = {
+      kind: "replay" as const,
+      cassette_dir: "cassettes",
+      strict: true,
+    };
+    const replay = (prompt: string) =>
+      runReplayAgent({ config, cwd: tempDir, prompt, logger });
+
+    expect((await replay("second prompt")).success).toBe(true);
+    expect(await fs.readFile(path.join(tempDir, "README.md"), "utf-8")).toBe(
+      "two\n",
+    );
+    expect((await replay("second prompt")).success).toBe(false);
+    expect((await replay("unknown prompt")).success).toBe(false);
+
+    const lenient = await runReplayAgent({
+      config: { ...config, strict: false },
+      cwd: tempDir,
+      prompt: "unknown prompt",
+      logger,
+    });
+    expect(lenient.success).toBe(true);
+    expect(logger.warn).toHaveBeenCalled();
+  });
+});
@@ -118,6 +118,26 @@ describe("Error Detection Engine", () => {
       expect(diagnosis?.errorType).toBe("json_corruption");
     });
 
+    it("should detect a stalled agent", () => {
+      const result: AgentResult = {
+        success: false,
+        output: "Agent stopped as stuck: no files changed in 900s\n",
+        timedOut: false,
+        exitCode: null,
+        completionDetected: false,
+        stall: {
+          reason: "no_file_changes",
+          message: "no files changed in 900s",
+        },
+      };
+
+      const diagnosis = detectRecoverableError(result);
+      expect(diagnosis?.recoverable).toBe(true);
+      expect(diagnosis?.errorType).toBe("agent_stall");
+      expect(diagnosis?.suggestedRepair).toEqual(["restart_agent"]);
+      expect(diagnosis?.detectedPattern).toBe("no_file_changes");
+    });
+
     it("should return null for non-recoverable errors", () => {
       const result: AgentResult = {
         success: false,
@@ -0,0 +1,63 @@
+import { describe, it, expect } from "bun:test";
+import type { AgentResult } from "../result";
+import { agentLabel, classifyTransientFailure } from "../fallback";
+
+function failed(output: string, overrides: Partial<AgentResult> = {}) {
+  return {
+    success: false,
+    output,
+    timedOut: false,
+    exitCode: 1,
+    completionDetected: false,
+    ...overrides,
+  };
+}
+
+describe("classifyTransientFailure", () => {
+  it("classifies backend failures", () => {
+    expect(
+      classifyTransientFailure(failed("Error: 429 Too Many Requests")),
+    ).toBe("rate_limit");
+    expect(
+      classifyTransientFailure(failed('{"type":"overloaded_error"}')),
+    ).toBe("overloaded");
+    expect(
+      classifyTransientFailure(failed("authentication_error: token expired")),
+    ).toBe("auth_expired");
+    expect(classifyTransientFailure(failed("spawn amp ENOENT"))).toBe(
+      "missing_binary",
+    );
+  });
+
+  it("ignores task failures, timeouts and successes", () => {
+    expect(classifyTransientFailure(failed("Tests failed"))).toBeNull();
+    expect(
+      classifyTransientFailure(failed("rate limit", { timedOut: true })),
+    ).toBeNull();
+    expect(
+      classifyTransientFailure(failed("rate limit", { success: true })),
+    ).toBeNull();
+  });
+
+  it("only looks at the end of the output", () => {
+    const output = `rate limit docs\n${"x".repeat(3000)}\nTests failed`;
+    expect(classifyTransientFailure(failed(output))).toBeNull();
+  });
+});
+
+describe("agentLabel", () => {
+  it("names agents by kind, model or command", () => {
+    expect(agentLabel({ kind: "amp_sdk" })).toBe("amp_sdk");
+    expect(agentLabel({ kind: "codex_sdk", model: "codex-1" })).toBe(
+      "codex_sdk/codex-1",
+    );
+    expect(
+      agentLabel({
+        kind: "process",
+        command: "claude",
+        args: [],
+        completion_signal: "DONE",
+      }),
+    ).toBe("process/claude");
+  });
+});
@@ -0,0 +1,49 @@
+/**
+ * Unit tests for agent abort scopes
+ */
+
+import { describe, it, expect } from "bun:test";
+import {
+  abortAgentScope,
+  createAgentScope,
+  registerSdkController,
+  runInAgentScope,
+  unregisterSdkController,
+} from "../lifecycle";
+
+describe("agent scopes", () => {
+  it("aborts only the agents registered inside the scope", async () => {
+    const scope = createAgentScope();
+    const inside = new AbortController();
+    const outside = new AbortController();
+
+    registerSdkController(outside);
+    await runInAgentScope(scope, async () => {
+      await Promise.resolve();
+      registerSdkController(inside);
+    });
+
+    expect(scope.controllers.has(inside)).toBe(true);
+    expect(scope.controllers.has(outside)).toBe(false);
+
+    abortAgentScope(scope);
+
+    expect(inside.signal.aborted).toBe(true);
+    expect(outside.signal.aborted).toBe(false);
+    expect(scope.controllers.size).toBe(0);
+    unregisterSdkController(outside);
+  });
+
+  it("forgets agents that finish before the scope is aborted", async () => {
+    const scope = createAgentScope();
+    const finished = new AbortController();
+
+    await runInAgentScope(scope, async () => {
+      registerSdkController(finished);
+      unregisterSdkController(finished);
+    });
+    abortAgentScope(scope);
+
+    expect(finished.signal.aborted).toBe(false);
+  });
+});
@@ -0,0 +1,213 @@
+/**
+ * Unit tests for the OpenAI-compatible agent runner, against a local fake
+ * chat completions server
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from "bun:test";
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import * as os from "node:os";
+import { z } from "zod";
+import type { Logger } from "../../logging";
+import type { OpenAiCompatAgentConfig } from "../../schemas";
+import type { AgentEvent } from "../../tui/agentEvents";
+import {
+  chatCompletionsUrl,
+  runOpenAiCompatAgent,
+} from "../openai-compat-runner";
+
+function createMockLogger(): Logger {
+  return {
+    debug: vi.fn(),
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    json: vi.fn(),
+  };
+}
+
+function toolCall(id: string, name: string, args: unknown) {
+  return {
+    id,
+    type: "function",
+    function: { name, arguments: JSON.stringify(args) },
+  };
+}
+
+describe("runOpenAiCompatAgent", () => {
+  let tempDir: string;
+  let server: ReturnType<typeof Bun.serve>;
+  let requests: any[];
+  let replies: any[];
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), "wreckit-openai-"));
+    requests = [];
+    replies = [];
+    server = Bun.serve({
+      port: 0,
+      async fetch(req) {
+        requests.push({
+          url: new URL(req.url).pathname,
+          auth: req.headers.get("authorization"),
+          body: await req.json(),
+        });
+        const message = replies.shift();
+        return Response.json({
+          model: "qwen2.5-coder",
+          choices: [{ message }],
+          usage: { prompt_tokens: 100, completion_tokens: 10 },
+        });
+      },
+    });
+  });
+
+  afterEach(async () => {
+    server.stop(true);
+    await fs.rm(tempDir, { recursive: true, force: true });
+  });
+
+  function config(
+    overrides: Partial<OpenAiCompatAgentConfig> = {},
+  ): OpenAiCompatAgentConfig {
+    return {
+      kind: "openai_compat",
+      base_url: `http://localhost:${server.port}/v1/`,
+      model: "qwen2.5-coder",
+      max_tokens: 1024,
+      max_iterations: 5,
+      ...overrides,
+    };
+  }
+
+  it("builds the endpoint URL from the base URL", () => {
+    expect(chatCompletionsUrl("http://localhost:8080/v1")).toBe(
+      "http://localhost:8080/v1/chat/completions",
+    );
+    expect(chatCompletionsUrl("http://localhost:8080/v1//")).toBe(
+      "http://localhost:8080/v1/chat/completions",
+    );
+  });
+
+  it("runs built-in and MCP tools until the model stops calling them", async () => {
+    replies.push(
+      {
+        role: "assistant",
+        content: "Writing the file",
+        tool_calls: [
+          toolCall("c1", "Write", { path: "notes.md", content: "hi\n" }),
+        ],
+      },
+      {
+        role: "assistant",
+        content: null,
+        tool_calls: [toolCall("c2", "mcp__wreckit__save_prd", { id: 1 })],
+      },
+      { role: "assistant", content: "Done" },
+    );
+    const saved: unknown[] = [];
+    const events: AgentEvent[] = [];
+
+    const result = await runOpenAiCompatAgent({
+      config: config(),
+      cwd: tempDir,
+      prompt: "write notes",
+      logger: createMockLogger(),
+      onAgentEvent: (event) => events.push(event),
+      mcpServers: {
+        wreckit: {
+          tools: [
+            {
+              name: "save_prd",
+              description: "Save the PRD",
+              inputSchema: z.object({ id: z.number() }),
+              handler: async (args: unknown) => {
+                saved.push(args);
+                return { content: [{ type: "text", text: "saved" }] };
+              },
+            },
+          ],
+        },
+      },
+    });
+
+    expect(result.success).toBe(true);
+    expect(result.completionDetected).toBe(true);
+    expect(result.output).toBe("Writing the file\nDone\n");
+    expect(result.usage).toEqual({
+      input_tokens: 300,
+      output_tokens: 30,
+      cache_read_tokens: 0,
+      cache_write_tokens: 0,
+      model: "qwen2.5-coder",
+    });
+    expect(await fs.readFile(path.join(tempDir, "notes.md"), "utf-8")).toBe(
+      "hi\n",
+    );
+    expect(saved).toEqual([{ id: 1 }]);
+
+    expect(requests[0].url).toBe("/v1/chat/completions");
+    expect(requests[0].auth).toBeNull();
+    expect(
+      requests[0].body.tools.map((tool: any) => tool.function.name),
+    ).toContain("mcp__wreckit__save_prd");
+    expect(requests[2].body.messages.at(-1)).toEqual({
+      role: "tool",
+      tool_call_id: "c2",
+      content: "saved",
+    });
+    expect(
+      events
+        .filter((event) => event.type === "tool_started")
+        .map((event) => (event as any).toolName),
+    ).toEqual(["Write", "mcp__wreckit__save_prd"]);
+  });
+
+  it("sends the API key from the configured environment variable", async () => {
+    process.env.WRECKIT_TEST_OPENAI_KEY = "secret";
+    replies.push({ role: "assistant", content: "ok" });
+    try {
+      await runOpenAiCompatAgent({
+        config: config({ api_key_env: "WRECKIT_TEST_OPENAI_KEY" }),
+        cwd: tempDir,
+        prompt: "hi",
+        logger: createMockLogger(),
+      });
+    } finally {
+      delete process.env.WRECKIT_TEST_OPENAI_KEY;
+    }
+
+    expect(requests[0].auth).toBe("Bearer secret");
+
+    const missing = await runOpenAiCompatAgent({
+      config: config({ api_key_env: "WRECKIT_TEST_OPENAI_KEY" }),
+      cwd: tempDir,
+      prompt: "hi",
+      logger: createMockLogger(),
+    });
+    expect(missing.success).toBe(false);
+    expect(missing.output).toContain("WRECKIT_TEST_OPENAI_KEY");
+  });
+
+  it("fails once max_iterations requests are made", async () => {
+    for (let i = 0; i < 2; i++) {
+      replies.push({
+        role: "assistant",
+        content: null,
+        tool_calls: [toolCall(`c${i}`, "Read", { path: "missing.md" })],
+      });
+    }
+
+    const result = await runOpenAiCompatAgent({
+      config: config({ max_iterations: 2 }),
+      cwd: tempDir,
+      prompt: "loop",
+      logger: createMockLogger(),
+    });
+
+    expect(result.success).toBe(false);
+    expect(result.completionDetected).toBe(false);
+    expect(result.output).toContain("max_iterations (2)");
+    expect(requests).toHaveLength(2);
+  });
+});
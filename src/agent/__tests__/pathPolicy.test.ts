@@ -0,0 +1,113 @@
+import { describe, it, expect, vi, beforeEach, afterEach } from "bun:test";
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import * as os from "node:os";
+import { DEFAULT_CONFIG } from "../../config";
+import {
+  checkPathPolicy,
+  createPathGuard,
+  getPhasePathPolicy,
+} from "../pathPolicy";
+import { buildToolRegistry } from "../rlm-tools";
+
+describe("getPhasePathPolicy", () => {
+  it("fills in the item id of the default policies", () => {
+    expect(getPhasePathPolicy(DEFAULT_CONFIG, "plan", "001-auth")).toEqual({
+      allow: [".wreckit/items/001-auth/**"],
+      deny: [],
+    });
+    expect(
+      getPhasePathPolicy(DEFAULT_CONFIG, "pr", "001-auth"),
+    ).toBeUndefined();
+  });
+
+  it("lets path_policies replace the default for a phase", () => {
+    const config = {
+      ...DEFAULT_CONFIG,
+      path_policies: { implement: { deny: ["migrations/**"] } },
+    };
+
+    expect(getPhasePathPolicy(config, "implement", "001-auth")).toEqual({
+      deny: ["migrations/**"],
+    });
+  });
+});
+
+describe("checkPathPolicy", () => {
+  const policy = {
+    allow: [".wreckit/items/001-auth/**", "docs/**"],
+    deny: ["docs/private/**"],
+  };
+
+  it("allows paths matching the allow list", () => {
+    expect(checkPathPolicy(policy, ".wreckit/items/001-auth/plan.md")).toBe(
+      null,
+    );
+    expect(checkPathPolicy(policy, "./docs/guide.md")).toBe(null);
+  });
+
+  it("refuses paths outside the allow list or matching a deny glob", () => {
+    expect(checkPathPolicy(policy, "src/index.ts")).toContain(
+      "outside the paths this phase may write",
+    );
+    expect(checkPathPolicy(policy, "docs/private/keys.md")).toBe(
+      "docs/private/keys.md matches denied path docs/private/**",
+    );
+    expect(checkPathPolicy(policy, "docs/../src/index.ts")).not.toBe(null);
+  });
+
+  it("only refuses denied paths without an allow list", () => {
+    const implement = { deny: [".github/**", ".wreckit/config.json"] };
+
+    expect(checkPathPolicy(implement, "src/index.ts")).toBe(null);
+    expect(checkPathPolicy(implement, ".github/workflows/ci.yml")).not.toBe(
+      null,
+    );
+  });
+});
+
+describe("local tools with a path guard", () => {
+  let tempDir: string;
+
+  beforeEach(async () => {
+    tempDir = await fs.mkdtemp(path.join(os.tmpdir(), "wreckit-paths-"));
+  });
+
+  afterEach(async () => {
+    await fs.rm(tempDir, { recursive: true, force: true });
+  });
+
+  it("refuses writes outside the policy and reports them", async () => {
+    const onDenied = vi.fn();
+    const guard = createPathGuard(
+      { allow: ["notes/**"], deny: [] },
+      tempDir,
+      onDenied,
+    );
+    const tools = buildToolRegistry(
+      ["Write"],
+      undefined,
+      undefined,
+      tempDir,
+      guard,
+    );
+    const write = tools[0].func as (args: {
+      path: string;
+      content: string;
+    }) => Promise<string>;
+
+    expect(await write({ path: "notes/a.md", content: "ok" })).toContain(
+      "Successfully wrote",
+    );
+    expect(await write({ path: "src/a.ts", content: "no" })).toContain(
+      "Error writing file src/a.ts: src/a.ts is outside",
+    );
+
+    expect(onDenied).toHaveBeenCalledTimes(1);
+    expect(onDenied.mock.calls[0][0]).toMatchObject({
+      tool: "Write",
+      path: "src/a.ts",
+    });
+    await expect(fs.access(path.join(tempDir, "src/a.ts"))).rejects.toThrow();
+  });
+});
@@ -0,0 +1,106 @@
+import { describe, it, expect, vi } from "bun:test";
+import { StoryScopeConfigSchema } from "../../schemas";
+import type { DiffStats } from "../../git/scope";
+import { parseNumstat } from "../../git/scope";
+import { createLiveScopeWatcher } from "../scope";
+
+const CONFIG = StoryScopeConfigSchema.parse({
+  max_diff_files: 2,
+  live_check_interval_seconds: 60,
+});
+
+function stats(paths: string[]): DiffStats {
+  const fileDiffs = paths.map((path) => ({ path, lines: 10, bytes: 400 }));
+  return {
+    totalFiles: fileDiffs.length,
+    totalLines: fileDiffs.length * 10,
+    totalBytes: fileDiffs.length * 400,
+    fileDiffs,
+  };
+}
+
+const flush = () => new Promise((r) => setTimeout(r, 0));
+
+describe("createLiveScopeWatcher", () => {
+  it("checks scope after each file-writing tool result", async () => {
+    let changed = ["a.ts"];
+    const getStats = vi.fn(async () => stats(changed));
+    const onViolation = vi.fn();
+    const watcher = createLiveScopeWatcher({
+      config: CONFIG,
+      storyId: "US-001",
+      getStats,
+      onViolation,
+    });
+
+    watcher.observe({
+      type: "tool_started",
+      toolUseId: "t1",
+      toolName: "Read",
+      input: { file_path: "a.ts" },
+    });
+    watcher.observe({ type: "tool_result", toolUseId: "t1", result: "" });
+    await flush();
+    expect(getStats).not.toHaveBeenCalled();
+
+    watcher.observe({
+      type: "tool_started",
+      toolUseId: "t2",
+      toolName: "Write",
+      input: { file_path: "a.ts" },
+    });
+    watcher.observe({ type: "tool_result", toolUseId: "t2", result: "" });
+    await flush();
+    expect(getStats).toHaveBeenCalledTimes(1);
+    expect(onViolation).not.toHaveBeenCalled();
+
+    changed = ["a.ts", "b.ts", "c.ts"];
+    watcher.observe({
+      type: "tool_started",
+      toolUseId: "t3",
+      toolName: "Edit",
+      input: { file_path: "c.ts" },
+    });
+    watcher.observe({ type: "tool_result", toolUseId: "t3", result: "" });
+    await flush();
+
+    expect(onViolation).toHaveBeenCalledTimes(1);
+    const [result, message] = onViolation.mock.calls[0];
+    expect(result.violations.map((v: { type: string }) => v.type)).toEqual([
+      "too_many_files",
+    ]);
+    expect(message).toContain("Story US-001 exceeded scope limits:");
+    watcher.stop();
+  });
+
+  it("leaves out excluded patterns", async () => {
+    const onViolation = vi.fn();
+    const watcher = createLiveScopeWatcher({
+      config: CONFIG,
+      storyId: "US-001",
+      getStats: async () => stats(["a.ts", "bun.lock", "yarn.lock"]),
+      onViolation,
+    });
+
+    watcher.observe({
+      type: "tool_started",
+      toolUseId: "t1",
+      toolName: "Write",
+      input: {},
+    });
+    watcher.observe({ type: "tool_result", toolUseId: "t1", result: "" });
+    await flush();
+    watcher.stop();
+
+    expect(onViolation).not.toHaveBeenCalled();
+  });
+});
+
+describe("parseNumstat", () => {
+  it("counts added and deleted lines, and binary files as one line", () => {
+    expect(parseNumstat("12\t3\tsrc/a.ts\n-\t-\tlogo.png")).toEqual([
+      { path: "src/a.ts", lines: 15, bytes: 600 },
+      { path: "logo.png", lines: 1, bytes: 40 },
+    ]);
+  });
+});
@@ -0,0 +1,157 @@
+import { describe, it, expect, vi } from "bun:test";
+import type { AgentEvent } from "../../tui/agentEvents";
+import {
+  createStallWatcher,
+  formatNudge,
+  type StallLimits,
+  type StallWatcherOptions,
+} from "../stallDetector";
+
+const LIMITS: StallLimits = {
+  max_repeated_tool_calls: 3,
+  max_repeated_errors: 3,
+};
+
+function bash(id: string, command = "bun test"): AgentEvent {
+  return {
+    type: "tool_started",
+    toolUseId: id,
+    toolName: "Bash",
+    input: { command },
+  };
+}
+
+function watch(overrides: Partial<StallWatcherOptions> = {}) {
+  const onNudge = vi.fn();
+  const onStall = vi.fn();
+  const watcher = createStallWatcher({
+    limits: LIMITS,
+    canNudge: true,
+    fingerprint: async () => null,
+    onNudge,
+    onStall,
+    ...overrides,
+  });
+  return { watcher, onNudge, onStall };
+}
+
+const sleep = (ms: number) => new Promise((r) => setTimeout(r, ms));
+
+describe("createStallWatcher", () => {
+  it("nudges on repeated tool calls, then aborts", () => {
+    const { watcher, onNudge, onStall } = watch();
+
+    for (let i = 0; i < 3; i++) watcher.observe(bash(`t${i}`));
+    expect(onNudge).toHaveBeenCalledTimes(1);
+    const [stall, message] = onNudge.mock.calls[0];
+    expect(stall.reason).toBe("repeated_tool_call");
+    expect(message).toBe(formatNudge(stall));
+    expect(onStall).not.toHaveBeenCalled();
+
+    for (let i = 3; i < 6; i++) watcher.observe(bash(`t${i}`));
+    expect(onStall).toHaveBeenCalledTimes(1);
+    expect(onStall.mock.calls[0][0]).toMatchObject({
+      reason: "repeated_tool_call",
+      message:
+        "Bash was called 3 times with the same input and no file changes",
+    });
+  });
+
+  it("aborts straight away when the backend cannot be nudged", () => {
+    const { watcher, onNudge, onStall } = watch({ canNudge: false });
+
+    for (let i = 0; i < 3; i++) watcher.observe(bash(`t${i}`));
+
+    expect(onNudge).not.toHaveBeenCalled();
+    expect(onStall).toHaveBeenCalledTimes(1);
+  });
+
+  it("does not count different tool inputs together", () => {
+    const { watcher, onNudge } = watch();
+
+    for (let i = 0; i < 6; i++) watcher.observe(bash(`t${i}`, `cat ${i}.ts`));
+
+    expect(onNudge).not.toHaveBeenCalled();
+  });
+
+  it("spots the same error coming back with different numbers", () => {
+    const { watcher, onNudge } = watch();
+
+    for (let i = 0; i < 3; i++) {
+      watcher.observe({
+        type: "tool_result",
+        toolUseId: `t${i}`,
+        result: `Error: expected 1 to be ${i + 2} at line ${40 + i}`,
+      });
+    }
+
+    expect(onNudge).toHaveBeenCalledTimes(1);
+    expect(onNudge.mock.calls[0][0].reason).toBe("error_loop");
+  });
+
+  it("resets repeated calls when files change", async () => {
+    let tree = "a.ts:1";
+    const { watcher, onNudge } = watch({
+      limits: { ...LIMITS, max_idle_seconds: 0.3 },
+      fingerprint: async () => tree,
+    });
+
+    await sleep(10);
+    watcher.observe(bash("t0"));
+    watcher.observe(bash("t1"));
+    tree = "a.ts:2";
+    await sleep(150);
+    watcher.observe(bash("t2"));
+    watcher.stop();
+
+    expect(onNudge).not.toHaveBeenCalled();
+  });
+
+  it("resets repeated errors when files change", async () => {
+    let tree = "a.ts:1";
+    const { watcher, onNudge } = watch({
+      limits: { ...LIMITS, max_idle_seconds: 0.3 },
+      fingerprint: async () => tree,
+    });
+    const failure: AgentEvent = {
+      type: "tool_error",
+      toolUseId: "t",
+      error: "TypeError: x is undefined",
+    };
+
+    await sleep(10);
+    watcher.observe(failure);
+    watcher.observe(failure);
+    tree = "a.ts:2";
+    await sleep(150);
+    watcher.observe(failure);
+    watcher.stop();
+
+    expect(onNudge).not.toHaveBeenCalled();
+  });
+
+  it("reports no file changes for max_idle_seconds", async () => {
+    const { watcher, onNudge } = watch({
+      limits: { ...LIMITS, max_idle_seconds: 0.06 },
+      fingerprint: async () => "a.ts:1",
+    });
+
+    await sleep(150);
+    watcher.stop();
+
+    expect(onNudge).toHaveBeenCalledTimes(1);
+    expect(onNudge.mock.calls[0][0]).toMatchObject({
+      reason: "no_file_changes",
+      message: "no files changed in 0.06s",
+    });
+  });
+
+  it("stops watching once stopped", () => {
+    const { watcher, onNudge } = watch();
+
+    watcher.stop();
+    for (let i = 0; i < 3; i++) watcher.observe(bash(`t${i}`));
+
+    expect(onNudge).not.toHaveBeenCalled();
+  });
+});
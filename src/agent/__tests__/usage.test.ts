@@ -0,0 +1,131 @@
+/**
+ * Unit tests for agent usage normalization
+ */
+
+import { describe, it, expect } from "bun:test";
+import {
+  addAgentUsage,
+  normalizeUsage,
+  parseUsageFromOutput,
+} from "../usage";
+
+describe("normalizeUsage", () => {
+  it("reads Anthropic usage with cache tokens", () => {
+    expect(
+      normalizeUsage(
+        {
+          input_tokens: 100,
+          output_tokens: 50,
+          cache_read_input_tokens: 2000,
+          cache_creation_input_tokens: 300,
+        },
+        "claude-sonnet-4",
+      ),
+    ).toEqual({
+      input_tokens: 100,
+      output_tokens: 50,
+      cache_read_tokens: 2000,
+      cache_write_tokens: 300,
+      model: "claude-sonnet-4",
+    });
+  });
+
+  it("moves cached prompt tokens out of the OpenAI and Codex input", () => {
+    expect(
+      normalizeUsage(
+        {
+          prompt_tokens: 1000,
+          completion_tokens: 20,
+          prompt_tokens_details: { cached_tokens: 800 },
+        },
+        "gpt-4o",
+      ),
+    ).toEqual({
+      input_tokens: 200,
+      output_tokens: 20,
+      cache_read_tokens: 800,
+      cache_write_tokens: 0,
+      model: "gpt-4o",
+    });
+    expect(
+      normalizeUsage(
+        { input_tokens: 500, cached_input_tokens: 400, output_tokens: 10 },
+        null,
+      ),
+    ).toMatchObject({ input_tokens: 100, cache_read_tokens: 400 });
+  });
+
+  it("reads OpenCode and Ax usage", () => {
+    expect(
+      normalizeUsage(
+        { input: 10, output: 5, cache: { read: 7, write: 3 } },
+        null,
+      ),
+    ).toMatchObject({
+      input_tokens: 10,
+      output_tokens: 5,
+      cache_read_tokens: 7,
+      cache_write_tokens: 3,
+    });
+    expect(
+      normalizeUsage({ promptTokens: 12, completionTokens: 4 }, null),
+    ).toMatchObject({ input_tokens: 12, output_tokens: 4 });
+  });
+
+  it("returns null without token counts", () => {
+    expect(normalizeUsage(undefined, null)).toBeNull();
+    expect(normalizeUsage({ total: 5 }, null)).toBeNull();
+  });
+});
+
+describe("addAgentUsage", () => {
+  it("sums the requests of a run and keeps the first model", () => {
+    const first = normalizeUsage({ input_tokens: 1, output_tokens: 2 }, "a");
+    const second = normalizeUsage({ input_tokens: 3, output_tokens: 4 }, "b");
+
+    expect(addAgentUsage(first, second)).toMatchObject({
+      input_tokens: 4,
+      output_tokens: 6,
+      model: "a",
+    });
+    expect(addAgentUsage(null, second)).toEqual(second);
+  });
+});
+
+describe("parseUsageFromOutput", () => {
+  it("reads the result message of claude stream-json output", () => {
+    const output = [
+      JSON.stringify({ type: "system", model: "claude-opus-4" }),
+      "plain text",
+      JSON.stringify({
+        type: "result",
+        usage: { input_tokens: 10, output_tokens: 20 },
+      }),
+    ].join("\n");
+
+    expect(parseUsageFromOutput(output)).toMatchObject({
+      input_tokens: 10,
+      output_tokens: 20,
+      model: "claude-opus-4",
+    });
+  });
+
+  it("sums codex turn.completed events", () => {
+    const turn = JSON.stringify({
+      type: "turn.completed",
+      usage: { input_tokens: 100, cached_input_tokens: 60, output_tokens: 5 },
+    });
+
+    expect(parseUsageFromOutput(`${turn}\n${turn}\n`)).toEqual({
+      input_tokens: 80,
+      output_tokens: 10,
+      cache_read_tokens: 120,
+      cache_write_tokens: 0,
+      model: null,
+    });
+  });
+
+  it("returns null for output without usage", () => {
+    expect(parseUsageFromOutput("done\n<promise>COMPLETE</promise>")).toBeNull();
+  });
+});
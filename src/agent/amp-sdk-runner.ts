@@ -6,6 +6,7 @@ import type { AmpSdkAgentConfig } from "../schemas";
 import type { AgentEvent } from "../tui/agentEvents";
 import { getAllowedToolsForPhase } from "./toolAllowlist";
 import { buildSdkEnv } from "./env.js";
+import { normalizeUsage } from "./usage";
 
 export interface AmpRunAgentOptions {
   config: AmpSdkAgentConfig;
@@ -71,6 +72,10 @@ export async function runAmpSdkAgent(
 
     output = typeof result === "string" ? result : JSON.stringify(result);
     if (onStdoutChunk) onStdoutChunk(output);
+    const usage = normalizeUsage(
+      (result as any)?.usage,
+      options.config.model ?? null,
+    );
 
     return {
       success: true,
@@ -78,6 +83,7 @@ export async function runAmpSdkAgent(
       timedOut: false,
       exitCode: 0,
       completionDetected: true,
+      ...(usage && { usage }),
     };
   } catch (error) {
     const errorMessage = error instanceof Error ? error.message : String(error);
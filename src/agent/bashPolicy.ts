@@ -0,0 +1,329 @@
+import * as path from "node:path";
+import type { ConfigResolved } from "../config";
+import {
+  BashPolicyConfigSchema,
+  type BashAction,
+  type BashPolicyConfig,
+  type BashRule,
+} from "../schemas";
+
+/**
+ * Rules applied before `bash_policy.rules` unless `default_rules` is false.
+ *
+ * Philosophy: only refuse commands that do damage outside the working tree
+ * or that no review of the agent's diff would catch.
+ */
+export const DEFAULT_BASH_RULES: BashRule[] = [
+  {
+    command: "git push --force",
+    action: "deny",
+    reason: "force pushes rewrite the remote branch",
+  },
+  {
+    command: "git push -f",
+    action: "deny",
+    reason: "force pushes rewrite the remote branch",
+  },
+  {
+    command: "rm -rf /",
+    action: "deny",
+    reason: "deletes the whole filesystem",
+  },
+  {
+    command: "rm -rf /*",
+    action: "deny",
+    reason: "deletes the whole filesystem",
+  },
+  {
+    command: "rm -rf ~",
+    action: "deny",
+    reason: "deletes the home directory",
+  },
+  {
+    pattern: "\\b(curl|wget)\\b[^|;&]*\\|\\s*(sudo\\s+)?(ba|z|da)?sh\\b",
+    action: "deny",
+    reason: "pipes a download straight into a shell",
+  },
+];
+
+/** Prefixes that run the command after them */
+const WRAPPERS = new Set(["sudo", "env", "nohup", "time", "command", "exec"]);
+const SHELLS = new Set(["sh", "bash", "zsh", "dash"]);
+const SEPARATORS = ";&|\n()`";
+
+const SEVERITY: Record<BashAction, number> = { allow: 0, approve: 1, deny: 2 };
+
+export interface BashDecision {
+  action: BashAction;
+  reason: string;
+  /** The deciding rule, unset when `default_action` applied */
+  rule?: BashRule;
+}
+
+/**
+ * A command the policy did not let run.
+ */
+export interface BashRefusal {
+  command: string;
+  action: Exclude<BashAction, "allow">;
+  reason: string;
+}
+
+export interface BashGuard {
+  /**
+   * The error to return to the agent instead of running `command`, or null
+   * if it may run. Refusals are reported to the guard's `onRefused`.
+   */
+  check(command: string): string | null;
+}
+
+/**
+ * Split a command line into the argv of each simple command in it, so
+ * `cd x && rm -rf /` yields `["cd", "x"]` and `["rm", "-rf", "/"]`.
+ * Quotes and backslashes are honoured; `$(...)` and backticks are split
+ * out as commands of their own, as are the scripts of `bash -c`.
+ */
+export function splitCommand(command: string, depth = 0): string[][] {
+  const segments: string[][] = [];
+  let argv: string[] = [];
+  let token = "";
+  let inToken = false;
+  let quote = null as '"' | "'" | null;
+
+  const endToken = () => {
+    if (inToken) {
+      argv.push(token);
+    }
+    token = "";
+    inToken = false;
+  };
+  const endSegment = () => {
+    endToken();
+    if (argv.length > 0) {
+      segments.push(argv);
+    }
+    argv = [];
+  };
+
+  for (let i = 0; i < command.length; i++) {
+    const ch = command[i];
+    if (quote) {
+      if (ch === quote) {
+        quote = null;
+      } else if (ch === "\\" && quote === '"' && i + 1 < command.length) {
+        token += command[++i];
+      } else {
+        token += ch;
+      }
+    } else if (ch === "'" || ch === '"') {
+      quote = ch;
+      inToken = true;
+    } else if (ch === "\\" && i + 1 < command.length) {
+      token += command[++i];
+      inToken = true;
+    } else if (SEPARATORS.includes(ch)) {
+      endSegment();
+    } else if (/\s/.test(ch)) {
+      endToken();
+    } else {
+      token += ch;
+      inToken = true;
+    }
+  }
+  endSegment();
+
+  if (depth > 2) {
+    return segments;
+  }
+  const nested = segments.flatMap((segment) => {
+    const words = commandWords(segment);
+    const script = words[words.indexOf("-c") + 1];
+    return SHELLS.has(words[0]) && words.includes("-c") && script
+      ? splitCommand(script, depth + 1)
+      : [];
+  });
+  return [...segments, ...nested];
+}
+
+/**
+ * The words of a simple command from the command name on, skipping
+ * variable assignments and wrappers like `sudo`.
+ */
+function commandWords(argv: string[]): string[] {
+  let start = 0;
+  while (
+    start < argv.length &&
+    (/^[A-Za-z_][A-Za-z0-9_]*=/.test(argv[start]) ||
+      WRAPPERS.has(argv[start]))
+  ) {
+    start++;
+  }
+  const words = argv.slice(start);
+  if (words.length > 0) {
+    words[0] = path.basename(words[0]);
+  }
+  return words;
+}
+
+/** Expand short flag clusters so `-rf` and `-fr` both match `-r -f`. */
+function expandFlags(words: string[]): Set<string> {
+  return new Set(
+    words.flatMap((word) =>
+      /^-[A-Za-z]{2,}$/.test(word)
+        ? [...word.slice(1)].map((flag) => `-${flag}`)
+        : [word],
+    ),
+  );
+}
+
+/**
+ * A rule ready to match: its regex or its command's argv, parsed once
+ * per policy rather than per command.
+ */
+interface CompiledRule {
+  rule: BashRule;
+  regex?: RegExp;
+  argv: string[];
+}
+
+/**
+ * The rules a policy applies, built-in ones first. Patterns were
+ * validated when the config was parsed.
+ */
+function compileRules(policy: BashPolicyConfig): CompiledRule[] {
+  return [
+    ...(policy.default_rules ? DEFAULT_BASH_RULES : []),
+    ...policy.rules,
+  ].map((rule) =>
+    "pattern" in rule
+      ? { rule, regex: new RegExp(rule.pattern), argv: [] }
+      : { rule, argv: splitCommand(rule.command)[0] ?? [] },
+  );
+}
+
+function ruleMatches(
+  compiled: CompiledRule,
+  command: string,
+  segment: string[],
+): boolean {
+  if (compiled.regex) {
+    return compiled.regex.test(command);
+  }
+  const [name, ...args] = compiled.argv;
+  const words = commandWords(segment);
+  if (!name || words[0] !== name) {
+    return false;
+  }
+  const present = expandFlags(words.slice(1));
+  return [...expandFlags(args)].every((arg) => present.has(arg));
+}
+
+function decideSegment(
+  policy: BashPolicyConfig,
+  rules: CompiledRule[],
+  command: string,
+  segment: string[],
+): BashDecision {
+  let decided: BashRule | undefined;
+  for (const compiled of rules) {
+    const { rule } = compiled;
+    if (
+      ruleMatches(compiled, command, segment) &&
+      (!decided || SEVERITY[rule.action] > SEVERITY[decided.action])
+    ) {
+      decided = rule;
+    }
+  }
+  if (!decided) {
+    return {
+      action: policy.default_action,
+      reason: "no bash_policy rule matches it",
+    };
+  }
+  return {
+    action: decided.action,
+    reason:
+      decided.reason ??
+      `matches ${"command" in decided ? decided.command : decided.pattern}`,
+    rule: decided,
+  };
+}
+
+/**
+ * Decide what happens to a command. Each simple command in it is decided
+ * on its own, so an allowed `npm test` does not let `npm test && rm x`
+ * through: the most restrictive matching rule wins, commands no rule
+ * matches get `default_action`, and the most restrictive decision is the
+ * command's.
+ */
+export function evaluateBashCommand(
+  policy: BashPolicyConfig,
+  command: string,
+): BashDecision {
+  return decideCommand(policy, compileRules(policy), command);
+}
+
+function decideCommand(
+  policy: BashPolicyConfig,
+  rules: CompiledRule[],
+  command: string,
+): BashDecision {
+  if (!policy.enabled) {
+    return { action: "allow", reason: "bash_policy is disabled" };
+  }
+  const [first = [], ...rest] = splitCommand(command);
+
+  let decision = decideSegment(policy, rules, command, first);
+  for (const segment of rest) {
+    const next = decideSegment(policy, rules, command, segment);
+    if (SEVERITY[next.action] > SEVERITY[decision.action]) {
+      decision = next;
+    }
+  }
+  return decision;
+}
+
+/**
+ * The Bash policy from config, or the default one when unset. Undefined
+ * when the policy is disabled.
+ */
+export function getBashPolicy(
+  config: ConfigResolved,
+): BashPolicyConfig | undefined {
+  const policy = config.bash_policy ?? BashPolicyConfigSchema.parse({});
+  return policy.enabled ? policy : undefined;
+}
+
+/**
+ * The tool error an agent gets for a refused command.
+ */
+export function formatBashRefusal(refusal: BashRefusal): string {
+  return refusal.action === "deny"
+    ? `Command denied by bash_policy: ${refusal.reason}`
+    : `Command needs approval (${refusal.reason}); it was not run. Leave it for a human to run, or ask them to allow it in bash_policy.`;
+}
+
+/**
+ * Guard the Bash tool of one agent run.
+ */
+export function createBashGuard(
+  policy: BashPolicyConfig,
+  onRefused: (refusal: BashRefusal) => void,
+): BashGuard {
+  const rules = compileRules(policy);
+  return {
+    check(command) {
+      const decision = decideCommand(policy, rules, command);
+      if (decision.action === "allow") {
+        return null;
+      }
+      const refusal: BashRefusal = {
+        command,
+        action: decision.action,
+        reason: decision.reason,
+      };
+      onRefused(refusal);
+      return formatBashRefusal(refusal);
+    },
+  };
+}
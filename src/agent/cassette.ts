@@ -0,0 +1,218 @@
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import type { Logger } from "../logging";
+import type {
+  AgentConfigUnion,
+  Cassette,
+  CassetteEntry,
+  CassetteFileChange,
+} from "../schemas";
+import { CassetteSchema } from "../schemas";
+import type { AgentEvent } from "../tui/agentEvents";
+import { findRepoRoot } from "../fs/paths";
+import { getGitStatus } from "../git";
+import type { AgentResult } from "./result";
+
+// ============================================================
+// Agent Session Cassettes
+// ============================================================
+// While recording (`wreckit --record <dir>`), every agent run started by
+// runAgentUnion is saved as a numbered cassette: the prompt, the stdout,
+// stderr and agent events in order (including tool inputs and results), the
+// files the run changed and its result. The `replay` agent kind plays the
+// cassettes back (see replay-runner.ts).
+
+/** Run options a cassette captures; a subset of UnionRunAgentOptions */
+export interface CassetteRunOptions {
+  config: AgentConfigUnion;
+  cwd: string;
+  prompt: string;
+  logger: Logger;
+  onStdoutChunk?: (chunk: string) => void;
+  onStderrChunk?: (chunk: string) => void;
+  onAgentEvent?: (event: AgentEvent) => void;
+}
+
+export interface CassetteRecording<T extends CassetteRunOptions> {
+  /** The run options with callbacks that also write to the cassette */
+  options: T;
+  /** Save the cassette once the run is over; returns its path */
+  finish(result: AgentResult): Promise<string>;
+}
+
+let recordingDir: string | null = null;
+let nextCassetteNumber = 1;
+
+/**
+ * Record every following agent run into `dir`. Numbering continues after
+ * the cassettes already in the directory.
+ */
+export async function startRecording(dir: string): Promise<void> {
+  await fs.mkdir(dir, { recursive: true });
+  recordingDir = dir;
+  nextCassetteNumber = (await listCassettes(dir)).length + 1;
+}
+
+export function stopRecording(): void {
+  recordingDir = null;
+}
+
+/**
+ * Cassette files in `dir`, in recording order.
+ */
+export async function listCassettes(dir: string): Promise<string[]> {
+  try {
+    const entries = await fs.readdir(dir);
+    return entries
+      .filter((name) => /^\d+-.+\.json$/.test(name))
+      .sort()
+      .map((name) => path.join(dir, name));
+  } catch (err) {
+    if ((err as NodeJS.ErrnoException).code === "ENOENT") {
+      return [];
+    }
+    throw err;
+  }
+}
+
+export async function readCassette(filePath: string): Promise<Cassette> {
+  const content = await fs.readFile(filePath, "utf-8");
+  return CassetteSchema.parse(JSON.parse(content));
+}
+
+type FileSnapshot = Map<string, Buffer | null>;
+
+async function readFileOrNull(filePath: string): Promise<Buffer | null> {
+  try {
+    return await fs.readFile(filePath);
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * Contents of the files git reports as changed, keyed by repo path.
+ */
+async function snapshotChangedFiles(
+  root: string,
+  logger: Logger,
+): Promise<FileSnapshot> {
+  const changes = await getGitStatus({
+    cwd: root,
+    logger,
+    untrackedFiles: "all",
+  });
+  const snapshot: FileSnapshot = new Map();
+  for (const change of changes) {
+    // Renames are reported as "old -> new"
+    for (const changedPath of change.path.split(" -> ")) {
+      if (changedPath.endsWith("/")) continue;
+      snapshot.set(
+        changedPath,
+        await readFileOrNull(path.join(root, changedPath)),
+      );
+    }
+  }
+  return snapshot;
+}
+
+/**
+ * Files whose content differs between two snapshots.
+ */
+export function diffSnapshots(
+  before: FileSnapshot,
+  after: FileSnapshot,
+): CassetteFileChange[] {
+  const paths = [...new Set([...before.keys(), ...after.keys()])].sort();
+  const changes: CassetteFileChange[] = [];
+  for (const changedPath of paths) {
+    const old = before.get(changedPath) ?? null;
+    const now = after.has(changedPath) ? after.get(changedPath)! : old;
+    if (old && now && old.equals(now)) continue;
+    if (!old && !now && before.has(changedPath)) continue;
+    changes.push(encodeFileChange(changedPath, now));
+  }
+  return changes;
+}
+
+function encodeFileChange(
+  filePath: string,
+  content: Buffer | null,
+): CassetteFileChange {
+  if (!content) {
+    return { path: filePath, content: null, encoding: "utf8" };
+  }
+  // Binary files would not survive a round trip through a JSON string
+  const binary = content.includes(0);
+  return {
+    path: filePath,
+    content: content.toString(binary ? "base64" : "utf8"),
+    encoding: binary ? "base64" : "utf8",
+  };
+}
+
+/**
+ * Start recording one agent run, or return null when recording is off.
+ * Replay runs are never recorded.
+ */
+export async function beginCassette<T extends CassetteRunOptions>(
+  options: T,
+): Promise<CassetteRecording<T> | null> {
+  const dir = recordingDir;
+  if (!dir || options.config.kind === "replay") {
+    return null;
+  }
+
+  const { logger } = options;
+  const number = nextCassetteNumber++;
+  const root = findRepoRoot(options.cwd);
+  const before = await snapshotChangedFiles(root, logger);
+  const timeline: CassetteEntry[] = [];
+
+  const recorded: T = {
+    ...options,
+    onStdoutChunk: (chunk: string) => {
+      timeline.push({ type: "stdout", chunk });
+      options.onStdoutChunk?.(chunk);
+    },
+    onStderrChunk: (chunk: string) => {
+      timeline.push({ type: "stderr", chunk });
+      options.onStderrChunk?.(chunk);
+    },
+    onAgentEvent: (event: AgentEvent) => {
+      timeline.push({ type: "event", event });
+      options.onAgentEvent?.(event);
+    },
+  };
+
+  return {
+    options: recorded,
+    finish: async (result) => {
+      const after = await snapshotChangedFiles(root, logger);
+      const cassette: Cassette = {
+        schema_version: 1,
+        recorded_at: new Date().toISOString(),
+        agent_kind: options.config.kind,
+        cwd: path.relative(root, path.resolve(options.cwd)),
+        prompt: options.prompt,
+        timeline,
+        file_changes: diffSnapshots(before, after),
+        result: {
+          success: result.success,
+          output: result.output,
+          timedOut: result.timedOut,
+          exitCode: result.exitCode,
+          completionDetected: result.completionDetected,
+          ...(result.usage && { usage: result.usage }),
+        },
+      };
+      const filePath = path.join(
+        dir,
+        `${String(number).padStart(4, "0")}-${options.config.kind}.json`,
+      );
+      await fs.writeFile(filePath, JSON.stringify(cassette, null, 2) + "\n");
+      logger.debug(`Recorded agent run to ${filePath}`);
+      return filePath;
+    },
+  };
+}
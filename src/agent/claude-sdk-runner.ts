@@ -3,8 +3,11 @@ import type { Logger } from "../logging";
 import type { AgentResult } from "./runner";
 import { registerSdkController, unregisterSdkController } from "./lifecycle.js";
 import type { AgentEvent } from "../tui/agentEvents";
-import type { ClaudeSdkAgentConfig } from "../schemas";
+import type { AgentUsage, ClaudeSdkAgentConfig } from "../schemas";
 import { buildSdkEnv } from "./env.js";
+import { addAgentUsage, normalizeUsage } from "./usage";
+import { toRepoPath, type PathGuard } from "./pathPolicy";
+import type { BashGuard } from "./bashPolicy";
 
 export interface ClaudeRunAgentOptions {
   config: ClaudeSdkAgentConfig;
@@ -19,6 +22,78 @@ export interface ClaudeRunAgentOptions {
   mcpServers?: Record<string, unknown>;
   allowedTools?: string[];
   timeoutSeconds?: number;
+  /** Refuses file writes outside the phase's path policy */
+  pathGuard?: PathGuard;
+  /** Refuses Bash commands the bash_policy does not allow */
+  bashGuard?: BashGuard;
+}
+
+// Input field naming the file each writing tool changes
+const WRITE_TOOL_PATH_KEYS: Record<string, string> = {
+  Write: "file_path",
+  Edit: "file_path",
+  MultiEdit: "file_path",
+  NotebookEdit: "notebook_path",
+};
+
+// PreToolUse hook output for a refused tool call
+function denyToolUse(reason: string | null) {
+  if (!reason) {
+    return { continue: true };
+  }
+  return {
+    hookSpecificOutput: {
+      hookEventName: "PreToolUse" as const,
+      permissionDecision: "deny" as const,
+      permissionDecisionReason: reason,
+    },
+  };
+}
+
+/**
+ * SDK hooks that deny writes outside the phase's path policy and commands
+ * the bash_policy refuses. PreToolUse hooks decide permissions even with
+ * `bypassPermissions`.
+ */
+function buildPolicyHooks(
+  guards: { pathGuard?: PathGuard; bashGuard?: BashGuard },
+  cwd: string,
+): Options["hooks"] {
+  const { pathGuard, bashGuard } = guards;
+  const matchers: NonNullable<Options["hooks"]>["PreToolUse"] = [];
+  if (pathGuard) {
+    matchers.push({
+      matcher: Object.keys(WRITE_TOOL_PATH_KEYS).join("|"),
+      hooks: [
+        async (input: any) => {
+          const key = WRITE_TOOL_PATH_KEYS[input.tool_name];
+          const filePath = key ? input.tool_input?.[key] : undefined;
+          return denyToolUse(
+            typeof filePath === "string"
+              ? pathGuard.check(
+                  input.tool_name,
+                  toRepoPath(pathGuard.root, cwd, filePath),
+                )
+              : null,
+          );
+        },
+      ],
+    });
+  }
+  if (bashGuard) {
+    matchers.push({
+      matcher: "Bash",
+      hooks: [
+        async (input: any) => {
+          const command = input.tool_input?.command;
+          return denyToolUse(
+            typeof command === "string" ? bashGuard.check(command) : null,
+          );
+        },
+      ],
+    });
+  }
+  return { PreToolUse: matchers };
 }
 
 export async function runClaudeSdkAgent(
@@ -35,6 +110,12 @@ export async function runClaudeSdkAgent(
   } = options;
   const timeoutSeconds = options.timeoutSeconds ?? 3600;
   let output = "";
+  let usage: AgentUsage | undefined;
+  // Usage per API response; the SDK can repeat a response's usage across
+  // several assistant messages, so they are keyed by message id
+  const responseUsage = new Map<string, AgentUsage>();
+  let progress: AgentUsage | null = null;
+  let model: string | null = config.model ?? null;
   let timedOut = false;
   let timeoutId: ReturnType<typeof setTimeout> | undefined;
   const abortController = new AbortController();
@@ -66,6 +147,10 @@ export async function runClaudeSdkAgent(
       ...(options.mcpServers && { mcpServers: options.mcpServers as any }),
       // Restrict tools if allowedTools is specified (guardrail to prevent unwanted actions)
       ...(options.allowedTools && { tools: options.allowedTools }),
+      // Deny writes outside the phase's path policy and refused commands
+      ...((options.pathGuard || options.bashGuard) && {
+        hooks: buildPolicyHooks(options, cwd),
+      }),
     };
 
     // Add model config if specified
@@ -80,6 +165,31 @@ export async function runClaudeSdkAgent(
     for await (const message of query({ prompt, options: sdkOptions })) {
       if (timedOut) break;
 
+      // The init message names the model actually used; the result carries
+      // the usage of the whole run
+      const sdkMessage = message as any;
+      if (sdkMessage.type === "system" && sdkMessage.model) {
+        model = sdkMessage.model;
+      }
+      if (sdkMessage.type === "result") {
+        usage = normalizeUsage(sdkMessage.usage, model) ?? undefined;
+      }
+      const response = sdkMessage.message;
+      const responseTokens =
+        sdkMessage.type === "assistant" && response?.id
+          ? normalizeUsage(response.usage, response.model ?? model)
+          : null;
+      if (responseTokens) {
+        responseUsage.set(response.id, responseTokens);
+        progress = [...responseUsage.values()].reduce<AgentUsage | null>(
+          addAgentUsage,
+          null,
+        );
+        if (progress) {
+          onAgentEvent?.({ type: "usage_progress", usage: progress });
+        }
+      }
+
       // Convert SDK message to output string
       const messageText = formatSdkMessage(message);
       output += messageText;
@@ -120,6 +230,7 @@ export async function runClaudeSdkAgent(
         timedOut: true,
         exitCode: null,
         completionDetected: false,
+        usage: usage ?? progress ?? undefined,
       };
     }
 
@@ -130,6 +241,7 @@ export async function runClaudeSdkAgent(
       timedOut: false,
       exitCode: 0,
       completionDetected: true,
+      usage,
     };
   } catch (error) {
     if (timeoutId) clearTimeout(timeoutId);
@@ -142,6 +254,8 @@ export async function runClaudeSdkAgent(
       timedOut: false,
       exitCode: errorResult.exitCode,
       completionDetected: false,
+      // An aborted run has no result message, only per-response usage
+      usage: progress ?? undefined,
     };
   } finally {
     unregisterSdkController(abortController);
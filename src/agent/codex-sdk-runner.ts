@@ -6,6 +6,7 @@ import type { CodexSdkAgentConfig } from "../schemas";
 import type { AgentEvent } from "../tui/agentEvents";
 import { getAllowedToolsForPhase } from "./toolAllowlist";
 import { buildSdkEnv } from "./env.js";
+import { normalizeUsage } from "./usage";
 
 export interface CodexRunAgentOptions {
   config: CodexSdkAgentConfig;
@@ -78,6 +79,7 @@ export async function runCodexSdkAgent(
 
     output = (result as any).text || (result as any).content || "";
     if (onStdoutChunk) onStdoutChunk(output);
+    const usage = normalizeUsage((result as any).usage, options.config.model);
 
     return {
       success: true,
@@ -85,6 +87,7 @@ export async function runCodexSdkAgent(
       timedOut: false,
       exitCode: 0,
       completionDetected: true,
+      ...(usage && { usage }),
     };
   } catch (error) {
     const errorMessage = error instanceof Error ? error.message : String(error);
@@ -8,6 +8,8 @@ import type {
   OpenCodeSdkAgentConfig,
   RlmSdkAgentConfig,
   SpriteAgentConfig,
+  OpenAiCompatAgentConfig,
+  ReplayAgentConfig,
 } from "../schemas";
 import type { AgentEvent } from "../tui/agentEvents";
 import { runProcessAgent } from "./process-runner.js";
@@ -189,6 +191,40 @@ export async function dispatchAgent(
       });
     }
 
+    case "openai_compat": {
+      const { runOpenAiCompatAgent } = await import(
+        "./openai-compat-runner.js"
+      );
+      return runOpenAiCompatAgent({
+        config: config as OpenAiCompatAgentConfig,
+        cwd: options.cwd,
+        prompt: options.prompt,
+        logger: options.logger,
+        dryRun: options.dryRun,
+        onStdoutChunk: options.onStdoutChunk,
+        onStderrChunk: options.onStderrChunk,
+        onAgentEvent: options.onAgentEvent,
+        mcpServers: options.mcpServers,
+        allowedTools: options.allowedTools,
+        timeoutSeconds: options.timeoutSeconds,
+      });
+    }
+
+    case "replay": {
+      const { runReplayAgent } = await import("./replay-runner.js");
+      return runReplayAgent({
+        config: config as ReplayAgentConfig,
+        cwd: options.cwd,
+        prompt: options.prompt,
+        logger: options.logger,
+        dryRun: options.dryRun,
+        onStdoutChunk: options.onStdoutChunk,
+        onStderrChunk: options.onStderrChunk,
+        onAgentEvent: options.onAgentEvent,
+        mcpServers: options.mcpServers,
+      });
+    }
+
     default:
       return exhaustiveCheck(config);
   }
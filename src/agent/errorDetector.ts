@@ -14,6 +14,7 @@ export type ErrorType =
   | "git_lock"
   | "npm_failure"
   | "json_corruption"
+  | "agent_stall"
   | "unknown";
 
 /**
@@ -39,6 +40,17 @@ export function detectRecoverableError(
     return null;
   }
 
+  // Stopped by the stall detector; a fresh run may take another approach
+  if (result.stall) {
+    return {
+      recoverable: true,
+      errorType: "agent_stall",
+      confidence: 0.9,
+      suggestedRepair: ["restart_agent"],
+      detectedPattern: result.stall.reason,
+    };
+  }
+
   const output = result.output.toLowerCase();
   const stderr = extractStderr(result.output).toLowerCase();
 
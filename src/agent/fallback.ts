@@ -0,0 +1,68 @@
+import type { AgentConfigUnion } from "../schemas";
+import type { AgentResult } from "./result";
+
+/**
+ * Why a failed run is worth retrying on the next agent of a fallback chain.
+ */
+export type FallbackReason =
+  | "rate_limit"
+  | "overloaded"
+  | "auth_expired"
+  | "missing_binary";
+
+const FALLBACK_PATTERNS: Array<[FallbackReason, RegExp]> = [
+  ["rate_limit", /rate[ _-]?limit|too many requests|\b429\b/i],
+  ["overloaded", /overloaded|\b529\b|\b503\b|service unavailable/i],
+  [
+    "auth_expired",
+    /\b401\b|unauthorized|authentication[ _]error|invalid api key|token (?:has )?expired/i,
+  ],
+  [
+    "missing_binary",
+    /spawn \S+ ENOENT|command not found|executable not found/i,
+  ],
+];
+
+// Errors are reported at the end of the output; matching the whole output
+// would mistake code or logs the agent printed for a backend failure
+const ERROR_TAIL_CHARS = 2000;
+
+/**
+ * Classify a failed run as a transient backend failure: rate limiting,
+ * overload, expired credentials or a missing CLI binary.
+ *
+ * @returns null for successful runs and for failures of the task itself,
+ *   including timeouts, stuck agents, exceeded story scope and denied
+ *   commands
+ */
+export function classifyTransientFailure(
+  result: AgentResult,
+): FallbackReason | null {
+  if (
+    result.success ||
+    result.timedOut ||
+    result.stall ||
+    result.scopeViolation ||
+    result.policyViolation
+  ) {
+    return null;
+  }
+  const tail = result.output.slice(-ERROR_TAIL_CHARS);
+  for (const [reason, pattern] of FALLBACK_PATTERNS) {
+    if (pattern.test(tail)) {
+      return reason;
+    }
+  }
+  return null;
+}
+
+/**
+ * Short name for an agent config, e.g. `claude_sdk/claude-sonnet-4`.
+ */
+export function agentLabel(config: AgentConfigUnion): string {
+  if (config.kind === "process") {
+    return `process/${config.command}`;
+  }
+  const model = "model" in config ? config.model : undefined;
+  return model ? `${config.kind}/${model}` : config.kind;
+}
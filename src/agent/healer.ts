@@ -34,7 +34,7 @@ export interface HealingResult {
  */
 export interface HealingConfig {
   enabled: boolean;
-  autoRepair: boolean | "safe-only"; // true = all repairs, false = none, "safe-only" = git lock + npm only
+  autoRepair: boolean | "safe-only"; // true = all repairs, false = none, "safe-only" = git lock, npm and agent restarts only
   maxRetries: number;
   timeoutMs: number;
 }
@@ -63,7 +63,9 @@ export async function applyHealing(
 
   // Check if this repair is allowed by auto_repair setting
   const isSafeRepair =
-    diagnosis.errorType === "git_lock" || diagnosis.errorType === "npm_failure";
+    diagnosis.errorType === "git_lock" ||
+    diagnosis.errorType === "npm_failure" ||
+    diagnosis.errorType === "agent_stall";
   if (config.autoRepair === false) {
     return {
       success: false,
@@ -95,6 +97,16 @@ export async function applyHealing(
       case "json_corruption":
         return await validateAndRepairJson(cwd, logger);
 
+      case "agent_stall":
+        // Nothing to repair; the retry is told how the last run got stuck
+        return {
+          success: true,
+          errorType: "agent_stall",
+          repairAttempted: "restart_agent",
+          message: "Restarting the agent with a note about the stall",
+          durationMs: Date.now() - startTime,
+        };
+
       default:
         return {
           success: false,
@@ -16,6 +16,7 @@ import {
 } from "./runner";
 import { detectRecoverableError, type ErrorDiagnosis } from "./errorDetector";
 import { applyHealing, type HealingConfig, type HealingResult } from "./healer";
+import { formatStallNote } from "./stallDetector";
 export type { HealingConfig };
 
 /**
@@ -72,13 +73,14 @@ export async function runAgentWithHealing(
   let attempt = 0;
   const healingAttempts: HealingAttempt[] = [];
   let initialDiagnosis: ErrorDiagnosis | null = null;
+  let runOptions = options;
 
   while (attempt < maxRetries) {
     attempt++;
     logger.debug(`Agent execution attempt ${attempt}/${maxRetries}`);
 
     // Run the agent
-    const result = await runAgentUnion(options);
+    const result = await runAgentUnion(runOptions);
 
     // Success - return immediately
     if (result.success) {
@@ -196,6 +198,14 @@ export async function runAgentWithHealing(
       durationMs: healingResult.durationMs,
     });
 
+    // A stuck agent is retried with a note on what it got stuck on
+    if (healingResult.success && result.stall) {
+      runOptions = {
+        ...options,
+        prompt: `${options.prompt}\n\n${formatStallNote(result.stall)}`,
+      };
+    }
+
     if (healingResult.success) {
       logger.info(`  ✓ Repair successful: ${healingResult.message}`);
     } else {
@@ -209,7 +219,7 @@ export async function runAgentWithHealing(
   }
 
   // Should never reach here, but TypeScript needs it
-  return runAgentUnion(options);
+  return runAgentUnion(runOptions);
 }
 
 /**
@@ -1,3 +1,4 @@
+import { AsyncLocalStorage } from "node:async_hooks";
 import { spawn, type ChildProcess } from "node:child_process";
 import type { Logger } from "../logging";
 
@@ -12,6 +13,17 @@ import type { Logger } from "../logging";
 const activeSdkControllers = new Set<AbortController>();
 const activeProcessAgents = new Set<ChildProcess>();
 
+/**
+ * Agents registered while running inside runInAgentScope, so one run can be
+ * aborted without touching agents started by other items.
+ */
+export interface AgentScope {
+  controllers: Set<AbortController>;
+  processes: Set<ChildProcess>;
+}
+
+const agentScopeStorage = new AsyncLocalStorage<AgentScope>();
+
 /**
  * Register an SDK agent's AbortController for cleanup on process exit.
  * Called by each SDK runner (claude, amp, codex, opencode, rlm) when an agent starts.
@@ -20,6 +32,7 @@ const activeProcessAgents = new Set<ChildProcess>();
  */
 export function registerSdkController(controller: AbortController): void {
   activeSdkControllers.add(controller);
+  agentScopeStorage.getStore()?.controllers.add(controller);
 }
 
 /**
@@ -30,6 +43,7 @@ export function registerSdkController(controller: AbortController): void {
  */
 export function unregisterSdkController(controller: AbortController): void {
   activeSdkControllers.delete(controller);
+  agentScopeStorage.getStore()?.controllers.delete(controller);
 }
 
 /**
@@ -40,6 +54,7 @@ export function unregisterSdkController(controller: AbortController): void {
  */
 export function registerProcessAgent(child: ChildProcess): void {
   activeProcessAgents.add(child);
+  agentScopeStorage.getStore()?.processes.add(child);
 }
 
 /**
@@ -50,20 +65,54 @@ export function registerProcessAgent(child: ChildProcess): void {
  */
 export function unregisterProcessAgent(child: ChildProcess): void {
   activeProcessAgents.delete(child);
+  agentScopeStorage.getStore()?.processes.delete(child);
 }
 
 /**
- * Terminate all active agents (both SDK and process-based).
- * Called on process exit or when user interrupts execution (Ctrl+C).
+ * Create an empty scope for runInAgentScope.
+ */
+export function createAgentScope(): AgentScope {
+  return { controllers: new Set(), processes: new Set() };
+}
+
+/**
+ * Run `fn` so that every agent it registers is also tracked in `scope`.
  *
- * **SDK agents**: Aborts their AbortController, which signals cancellation to the SDK.
- * **Process agents**: Sends SIGTERM, then SIGKILL after 5 seconds if still running.
+ * @param scope - Scope that collects the agents
+ * @param fn - Starts the agent(s)
+ */
+export function runInAgentScope<T>(
+  scope: AgentScope,
+  fn: () => Promise<T>,
+): Promise<T> {
+  return agentScopeStorage.run(scope, fn);
+}
+
+/**
+ * Abort only the agents registered in `scope`, the same way
+ * terminateAllAgents aborts every agent.
  *
+ * @param scope - Scope from runInAgentScope
  * @param logger - Optional logger for debug output
  */
-export function terminateAllAgents(logger?: Logger): void {
-  // Abort all SDK agents
-  for (const controller of [...activeSdkControllers]) {
+export function abortAgentScope(scope: AgentScope, logger?: Logger): void {
+  abortControllers(scope.controllers, logger);
+  killProcesses(scope.processes, logger);
+  for (const controller of scope.controllers) {
+    activeSdkControllers.delete(controller);
+  }
+  for (const child of scope.processes) {
+    activeProcessAgents.delete(child);
+  }
+  scope.controllers.clear();
+  scope.processes.clear();
+}
+
+function abortControllers(
+  controllers: Iterable<AbortController>,
+  logger?: Logger,
+): void {
+  for (const controller of [...controllers]) {
     logger?.debug?.("Aborting SDK agent");
     try {
       controller.abort();
@@ -71,10 +120,13 @@ export function terminateAllAgents(logger?: Logger): void {
       // ignore
     }
   }
-  activeSdkControllers.clear();
+}
 
-  // Kill all process-based agents (fallback mode)
-  for (const child of [...activeProcessAgents]) {
+function killProcesses(
+  processes: Iterable<ChildProcess>,
+  logger?: Logger,
+): void {
+  for (const child of [...processes]) {
     if (!child || child.killed) continue;
     logger?.debug?.(`Terminating agent process pid=${child.pid}`);
 
@@ -95,5 +147,23 @@ export function terminateAllAgents(logger?: Logger): void {
       }
     }, 5000);
   }
+}
+
+/**
+ * Terminate all active agents (both SDK and process-based).
+ * Called on process exit or when user interrupts execution (Ctrl+C).
+ *
+ * **SDK agents**: Aborts their AbortController, which signals cancellation to the SDK.
+ * **Process agents**: Sends SIGTERM, then SIGKILL after 5 seconds if still running.
+ *
+ * @param logger - Optional logger for debug output
+ */
+export function terminateAllAgents(logger?: Logger): void {
+  // Abort all SDK agents
+  abortControllers(activeSdkControllers, logger);
+  activeSdkControllers.clear();
+
+  // Kill all process-based agents (fallback mode)
+  killProcesses(activeProcessAgents, logger);
   activeProcessAgents.clear();
 }
@@ -4,6 +4,7 @@ import type { ParsedIdea } from "../../domain/ideas";
 import type { Prd, Story, StoryStatus } from "../../schemas";
 import {
   verifyStoryCompletion,
+  validateStoryDependencies,
   type StoryCompletionVerification,
 } from "../../domain/validation";
 
@@ -47,6 +48,10 @@ export const StorySchema = z.object({
   priority: z.number().describe("Priority (1 = highest)"),
   status: z.enum(["pending", "done"]).describe("Story status"),
   notes: z.string().describe("Implementation notes (can be empty string)"),
+  depends_on: z
+    .array(z.string())
+    .optional()
+    .describe("IDs of stories that must be done before this one"),
 });
 
 export const PrdDataSchema = z.object({
@@ -56,6 +61,42 @@ export const PrdDataSchema = z.object({
   user_stories: z.array(StorySchema).describe("Array of user stories"),
 });
 
+export const ReproductionDataSchema = z.object({
+  command: z
+    .string()
+    .describe("Command that runs only the reproduction test(s)"),
+  test_files: z
+    .array(z.string())
+    .describe("Repository-relative paths of the test files written"),
+  description: z
+    .string()
+    .optional()
+    .describe("What the test asserts and how it shows the bug"),
+});
+
+export type ReproductionData = z.infer<typeof ReproductionDataSchema>;
+
+export const StoryTestsDataSchema = z.object({
+  command: z
+    .string()
+    .describe("Command that runs all the new tests from the repository root"),
+  stories: z
+    .array(
+      z.object({
+        story_id: z.string().describe("Story ID (e.g., US-001)"),
+        test_files: z
+          .array(z.string())
+          .describe("Repository-relative paths of the story's test files"),
+        tests: z
+          .array(z.string())
+          .describe("Names of the tests covering the acceptance criteria"),
+      }),
+    )
+    .describe("Tests written for each story"),
+});
+
+export type StoryTestsData = z.infer<typeof StoryTestsDataSchema>;
+
 export interface WreckitMcpHandlers {
   onInterviewIdeas?: (ideas: ParsedIdea[]) => void;
   onParsedIdeas?: (ideas: ParsedIdea[]) => void;
@@ -66,6 +107,8 @@ export interface WreckitMcpHandlers {
     verification: StoryCompletionVerification | null,
   ) => void;
   getPrd?: () => Prd | null;
+  onSaveReproduction?: (reproduction: ReproductionData) => void;
+  onSaveStoryTests?: (storyTests: StoryTestsData) => void;
 }
 
 export function createWreckitMcpServer(handlers: WreckitMcpHandlers = {}) {
@@ -123,6 +166,18 @@ export function createWreckitMcpServer(handlers: WreckitMcpHandlers = {}) {
         },
         async (args) => {
           const prd = args.prd as Prd;
+          const dependencyErrors = validateStoryDependencies(prd.user_stories);
+          if (dependencyErrors.length > 0) {
+            return {
+              content: [
+                {
+                  type: "text" as const,
+                  text: `PRD not saved. Fix the story dependencies and call save_prd again:\n${dependencyErrors.map((e) => `- ${e}`).join("\n")}`,
+                },
+              ],
+              isError: true,
+            };
+          }
           handlers.onSavePrd?.(prd);
           return {
             content: [
@@ -175,6 +230,38 @@ export function createWreckitMcpServer(handlers: WreckitMcpHandlers = {}) {
           };
         },
       ),
+      tool(
+        "save_reproduction",
+        "Record the failing test that reproduces the bug. Call this tool during the reproduce phase after writing the test; wreckit runs the command and expects it to fail.",
+        ReproductionDataSchema.shape,
+        async (args) => {
+          handlers.onSaveReproduction?.(args as ReproductionData);
+          return {
+            content: [
+              {
+                type: "text" as const,
+                text: `Saved reproduction command: ${args.command}`,
+              },
+            ],
+          };
+        },
+      ),
+      tool(
+        "save_story_tests",
+        "Record the failing tests written for each user story. Call this tool during the tests phase after writing the tests; wreckit runs the command and expects the tests to compile but fail.",
+        StoryTestsDataSchema.shape,
+        async (args) => {
+          handlers.onSaveStoryTests?.(args as StoryTestsData);
+          return {
+            content: [
+              {
+                type: "text" as const,
+                text: `Saved tests for ${args.stories.length} stories: ${args.command}`,
+              },
+            ],
+          };
+        },
+      ),
     ],
   });
 }
@@ -0,0 +1,304 @@
+import type { AxFunction } from "@ax-llm/ax";
+import type { Logger } from "../logging";
+import type { AgentUsage, OpenAiCompatAgentConfig } from "../schemas";
+import type { AgentEvent } from "../tui/agentEvents";
+import type { AgentResult } from "./runner";
+import { registerSdkController, unregisterSdkController } from "./lifecycle";
+import { buildToolRegistry, createLocalExecutor } from "./rlm-tools";
+import type { PathGuard } from "./pathPolicy";
+import type { BashGuard } from "./bashPolicy";
+import { adaptMcpServersToAxTools } from "./mcp/mcporterAdapter";
+import { addAgentUsage, normalizeUsage } from "./usage";
+
+export interface OpenAiCompatRunAgentOptions {
+  config: OpenAiCompatAgentConfig;
+  cwd: string;
+  prompt: string;
+  logger: Logger;
+  dryRun?: boolean;
+  onStdoutChunk?: (chunk: string) => void;
+  onStderrChunk?: (chunk: string) => void;
+  onAgentEvent?: (event: AgentEvent) => void;
+  mcpServers?: Record<string, unknown>;
+  allowedTools?: string[];
+  timeoutSeconds?: number;
+  /** Polled before each request for a message to add to the conversation */
+  takeNudge?: () => string | null;
+  /** Refuses Write and Edit calls outside the phase's path policy */
+  pathGuard?: PathGuard;
+  /** Refuses Bash commands the bash_policy does not allow */
+  bashGuard?: BashGuard;
+}
+
+interface ChatToolCall {
+  id: string;
+  type: "function";
+  function: { name: string; arguments: string };
+}
+
+interface ChatMessage {
+  role: "system" | "user" | "assistant" | "tool";
+  content: string | null;
+  tool_calls?: ChatToolCall[];
+  tool_call_id?: string;
+}
+
+const MAX_TOOL_RESULT_CHARS = 20000;
+
+function truncate(text: string): string {
+  if (text.length <= MAX_TOOL_RESULT_CHARS) return text;
+  return `${text.slice(0, MAX_TOOL_RESULT_CHARS)}\n...[truncated ${text.length - MAX_TOOL_RESULT_CHARS} chars]...`;
+}
+
+/**
+ * URL of the chat completions endpoint under `base_url`.
+ */
+export function chatCompletionsUrl(baseUrl: string): string {
+  return `${baseUrl.replace(/\/+$/, "")}/chat/completions`;
+}
+
+/**
+ * The tools the model may call, in chat completions format.
+ */
+function toChatTools(tools: AxFunction[]) {
+  return tools.map((tool) => ({
+    type: "function" as const,
+    function: {
+      name: tool.name,
+      description: tool.description,
+      parameters: tool.parameters,
+    },
+  }));
+}
+
+async function callTool(
+  tools: AxFunction[],
+  call: ChatToolCall,
+  logger: Logger,
+): Promise<string> {
+  const tool = tools.find((t) => t.name === call.function.name);
+  if (!tool) {
+    return `Error: Tool ${call.function.name} not found`;
+  }
+
+  let args: unknown;
+  try {
+    args = JSON.parse(call.function.arguments || "{}");
+  } catch (err) {
+    return `Error: Invalid JSON arguments: ${(err as Error).message}`;
+  }
+
+  try {
+    const result = await tool.func(args);
+    return typeof result === "string" ? result : JSON.stringify(result);
+  } catch (err) {
+    logger.debug(`Tool execution error: ${(err as Error).message}`);
+    return `Error: ${(err as Error).message}`;
+  }
+}
+
+function buildSystemPrompt(cwd: string): string {
+  return `You are an expert software engineer working in the repository at ${cwd}.
+Use the tools to read, search and change files and to run commands. Paths are relative to ${cwd}.
+Keep calling tools until the task is done, then reply with a short summary and no tool calls.`;
+}
+
+/**
+ * Run an agent against any OpenAI-compatible chat completions endpoint,
+ * such as llama.cpp, vLLM or Ollama on localhost.
+ *
+ * The model gets the same built-in tools as the `rlm` agent (Read, Write,
+ * Edit, Glob, Grep, Bash) plus the in-process MCP tools, and runs until it
+ * answers without calling a tool or `max_iterations` requests are made.
+ */
+export async function runOpenAiCompatAgent(
+  options: OpenAiCompatRunAgentOptions,
+): Promise<AgentResult> {
+  const { config, cwd, prompt, logger, onStdoutChunk, onAgentEvent } =
+    options;
+
+  if (options.dryRun) {
+    logger.info(
+      `[dry-run] Would run OpenAI-compatible agent (${config.model} at ${config.base_url})`,
+    );
+    return {
+      success: true,
+      output: "[dry-run] OpenAI-compatible agent not executed",
+      timedOut: false,
+      exitCode: 0,
+      completionDetected: true,
+    };
+  }
+
+  const apiKey = config.api_key_env
+    ? process.env[config.api_key_env]
+    : undefined;
+  if (config.api_key_env && !apiKey) {
+    const message = `Environment variable ${config.api_key_env} is not set`;
+    logger.error(message);
+    return {
+      success: false,
+      output: message,
+      timedOut: false,
+      exitCode: 1,
+      completionDetected: false,
+    };
+  }
+
+  const tools = [
+    ...buildToolRegistry(
+      options.allowedTools,
+      undefined,
+      createLocalExecutor(cwd),
+      cwd,
+      options.pathGuard,
+      options.bashGuard,
+    ),
+    ...(options.mcpServers
+      ? adaptMcpServersToAxTools(options.mcpServers, options.allowedTools)
+      : []),
+  ];
+
+  const abortController = new AbortController();
+  registerSdkController(abortController);
+
+  let timedOut = false;
+  let timeoutId: ReturnType<typeof setTimeout> | undefined;
+  if (options.timeoutSeconds && options.timeoutSeconds > 0) {
+    timeoutId = setTimeout(() => {
+      timedOut = true;
+      logger.warn(`Agent timed out after ${options.timeoutSeconds}s`);
+      abortController.abort();
+    }, options.timeoutSeconds * 1000);
+  }
+
+  const messages: ChatMessage[] = [
+    { role: "system", content: buildSystemPrompt(cwd) },
+    { role: "user", content: prompt },
+  ];
+  let output = "";
+  let usage = null as AgentUsage | null;
+
+  logger.info(
+    `Starting OpenAI-compatible agent (model: ${config.model}, ${config.base_url})`,
+  );
+
+  try {
+    for (let i = 0; i < config.max_iterations; i++) {
+      const nudge = options.takeNudge?.();
+      if (nudge) {
+        messages.push({ role: "user", content: nudge });
+      }
+      const response = await fetch(chatCompletionsUrl(config.base_url), {
+        method: "POST",
+        headers: {
+          "Content-Type": "application/json",
+          ...(apiKey ? { Authorization: `Bearer ${apiKey}` } : {}),
+        },
+        body: JSON.stringify({
+          model: config.model,
+          max_tokens: config.max_tokens,
+          messages,
+          ...(tools.length > 0 && { tools: toChatTools(tools) }),
+        }),
+        signal: abortController.signal,
+      });
+      if (!response.ok) {
+        throw new Error(
+          `Chat completions request failed with ${response.status}: ${await response.text()}`,
+        );
+      }
+
+      const body = (await response.json()) as any;
+      usage = addAgentUsage(
+        usage,
+        normalizeUsage(body.usage, body.model ?? config.model),
+      );
+      if (usage) {
+        onAgentEvent?.({ type: "usage_progress", usage });
+      }
+
+      const message = body.choices?.[0]?.message as ChatMessage | undefined;
+      if (!message) {
+        throw new Error("Chat completions response has no message");
+      }
+      const calls = message.tool_calls ?? [];
+      messages.push({
+        role: "assistant",
+        content: message.content ?? null,
+        ...(calls.length > 0 ? { tool_calls: calls } : {}),
+      });
+
+      if (message.content) {
+        const text = `${message.content}\n`;
+        output += text;
+        onStdoutChunk?.(text);
+        onAgentEvent?.({ type: "assistant_text", text: message.content });
+      }
+
+      if (calls.length === 0) {
+        onAgentEvent?.({ type: "run_result", subtype: "success" });
+        return {
+          success: true,
+          output,
+          timedOut: false,
+          exitCode: 0,
+          completionDetected: true,
+          ...(usage && { usage }),
+        };
+      }
+
+      for (const call of calls) {
+        let input: Record<string, unknown> = {};
+        try {
+          input = JSON.parse(call.function.arguments || "{}");
+        } catch {
+          // Reported to the model by callTool
+        }
+        onAgentEvent?.({
+          type: "tool_started",
+          toolUseId: call.id,
+          toolName: call.function.name,
+          input,
+        });
+        const result = truncate(await callTool(tools, call, logger));
+        onAgentEvent?.({ type: "tool_result", toolUseId: call.id, result });
+        messages.push({
+          role: "tool",
+          tool_call_id: call.id,
+          content: result,
+        });
+      }
+    }
+
+    const message = `Reached max_iterations (${config.max_iterations}) without finishing`;
+    logger.warn(message);
+    return {
+      success: false,
+      output: `${output}\n${message}`,
+      timedOut: false,
+      exitCode: 1,
+      completionDetected: false,
+      ...(usage && { usage }),
+    };
+  } catch (err) {
+    const message = timedOut
+      ? `Agent timed out after ${options.timeoutSeconds}s`
+      : abortController.signal.aborted
+        ? "Agent aborted"
+        : (err as Error).message;
+    logger.error(`OpenAI-compatible agent failed: ${message}`);
+    onAgentEvent?.({ type: "error", message });
+    return {
+      success: false,
+      output: `${output}\n${message}`,
+      timedOut,
+      exitCode: 1,
+      completionDetected: false,
+      ...(usage && { usage }),
+    };
+  } finally {
+    if (timeoutId) clearTimeout(timeoutId);
+    unregisterSdkController(abortController);
+  }
+}
@@ -6,6 +6,7 @@ import type { OpenCodeSdkAgentConfig } from "../schemas";
 import type { AgentEvent } from "../tui/agentEvents";
 import { getAllowedToolsForPhase } from "./toolAllowlist";
 import { buildSdkEnv } from "./env.js";
+import { normalizeUsage } from "./usage";
 
 export interface OpenCodeRunAgentOptions {
   config: OpenCodeSdkAgentConfig;
@@ -96,6 +97,9 @@ export async function runOpenCodeSdkAgent(
         : (response as any).content || JSON.stringify(response);
     output = text;
     if (onStdoutChunk) onStdoutChunk(text);
+    // Assistant messages report their tokens as info.tokens
+    const info = (response as any)?.info ?? response;
+    const usage = normalizeUsage(info?.tokens, info?.modelID ?? null);
 
     return {
       success: true,
@@ -103,6 +107,7 @@ export async function runOpenCodeSdkAgent(
       timedOut: false,
       exitCode: 0,
       completionDetected: true,
+      ...(usage && { usage }),
     };
   } catch (error) {
     const errorMessage = error instanceof Error ? error.message : String(error);
@@ -0,0 +1,120 @@
+import * as path from "node:path";
+import type { ConfigResolved } from "../config";
+import type { PathPolicy, PhaseName } from "../schemas";
+import { matchesAnyGlob } from "../domain/globs";
+
+/**
+ * Write path policies for each workflow phase, used unless
+ * `path_policies.<phase>` replaces them.
+ *
+ * Philosophy:
+ * - research, plan: only the item's own directory (research.md, plan.md)
+ * - implement: anywhere except CI workflows and wreckit's config
+ * - other phases: no restriction beyond their tool allowlist
+ */
+export const DEFAULT_PATH_POLICIES: Partial<Record<PhaseName, PathPolicy>> = {
+  research: { allow: [".wreckit/items/<id>/**"], deny: [] },
+  plan: { allow: [".wreckit/items/<id>/**"], deny: [] },
+  implement: {
+    deny: [
+      ".github/**",
+      ".wreckit/config.json",
+      ".wreckit/config.local.json",
+    ],
+  },
+};
+
+/**
+ * A tool call refused by a path policy.
+ */
+export interface PathDenial {
+  tool: string;
+  /** Path relative to the repository root */
+  path: string;
+  reason: string;
+}
+
+export interface PathGuard {
+  /** Repository root that policy globs and checked paths are relative to */
+  root: string;
+  /**
+   * Why `tool` may not write `repoPath`, or null if it may. Denials are
+   * reported to the guard's `onDenied`.
+   */
+  check(tool: string, repoPath: string): string | null;
+}
+
+/**
+ * The write path policy for a phase, with `<id>` filled in.
+ */
+export function getPhasePathPolicy(
+  config: ConfigResolved,
+  phase: PhaseName,
+  itemId: string,
+): PathPolicy | undefined {
+  const policy = config.path_policies?.[phase] ?? DEFAULT_PATH_POLICIES[phase];
+  if (!policy) {
+    return undefined;
+  }
+  const fill = (globs: string[]) =>
+    globs.map((glob) => glob.replaceAll("<id>", itemId));
+  return {
+    ...(policy.allow && { allow: fill(policy.allow) }),
+    deny: fill(policy.deny),
+  };
+}
+
+/**
+ * Check a repo-relative path against a policy. Deny globs win over allow
+ * globs; paths outside the repository only pass when there is no allow
+ * list.
+ *
+ * @returns why the path may not be written, or null
+ */
+export function checkPathPolicy(
+  policy: PathPolicy,
+  repoPath: string,
+): string | null {
+  const normalized = path.posix.normalize(repoPath.replace(/\\/g, "/"));
+  const denied = policy.deny.find((glob) =>
+    matchesAnyGlob(normalized, [glob]),
+  );
+  if (denied) {
+    return `${normalized} matches denied path ${denied}`;
+  }
+  if (policy.allow && !matchesAnyGlob(normalized, policy.allow)) {
+    return `${normalized} is outside the paths this phase may write (${policy.allow.join(", ")})`;
+  }
+  return null;
+}
+
+/**
+ * Guard the file-writing tools of one agent run.
+ */
+export function createPathGuard(
+  policy: PathPolicy,
+  root: string,
+  onDenied: (denial: PathDenial) => void,
+): PathGuard {
+  return {
+    root,
+    check(tool, repoPath) {
+      const reason = checkPathPolicy(policy, repoPath);
+      if (reason) {
+        onDenied({ tool, path: repoPath, reason });
+      }
+      return reason;
+    },
+  };
+}
+
+/**
+ * Repo-relative form of a path an agent passed, resolved against `cwd`.
+ */
+export function toRepoPath(
+  root: string,
+  cwd: string,
+  filePath: string,
+): string {
+  return path.relative(root, path.resolve(cwd, filePath));
+}
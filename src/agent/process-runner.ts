@@ -4,6 +4,7 @@ import type { AgentEvent } from "../tui/agentEvents";
 import type { ProcessAgentConfig } from "../schemas";
 import type { AgentResult } from "./runner";
 import { registerProcessAgent, unregisterProcessAgent } from "./lifecycle.js";
+import { parseUsageFromOutput } from "./usage";
 
 // ============================================================
 // Process-Based Agent Execution
@@ -129,6 +130,8 @@ export async function runProcessAgent(
       } else {
         process.stdout.write(chunk);
       }
+      // Process agents have no structured events; their stdout is their text
+      options.onAgentEvent?.({ type: "assistant_text", text: chunk });
       if (output.includes(config.completion_signal)) {
         completionDetected = true;
       }
@@ -167,12 +170,15 @@ export async function runProcessAgent(
       logger.debug(
         `Agent exited with code ${code}, completion detected: ${completionDetected}`,
       );
+      // Agents that print JSON (e.g. --output-format stream-json) report usage
+      const usage = parseUsageFromOutput(output);
       resolve({
         success,
         output,
         timedOut,
         exitCode: code,
         completionDetected,
+        ...(usage && { usage }),
       });
     });
 
@@ -1,8 +1,30 @@
+import * as path from "node:path";
 import { type AxFunction, type AxFunctionJSONSchema } from "@ax-llm/ax";
 import type { Logger } from "../logging";
 import type { SpriteAgentConfig } from "../schemas";
 import { execSprite } from "./sprite-runner";
 import { SpriteExecError } from "../errors";
+import type { PathGuard } from "./pathPolicy";
+import type { BashGuard } from "./bashPolicy";
+
+// Where the project is synced to inside the VM
+const REMOTE_PROJECT_DIR = "/home/user/project";
+
+/**
+ * Why the phase's path policy refuses a write to a path inside the VM.
+ */
+function deniedRemoteWrite(
+  pathGuard: PathGuard | undefined,
+  tool: string,
+  filePath: string,
+): string | null {
+  if (!pathGuard) return null;
+  const repoPath = path.posix.relative(
+    REMOTE_PROJECT_DIR,
+    path.posix.resolve(REMOTE_PROJECT_DIR, filePath),
+  );
+  return pathGuard.check(tool, repoPath);
+}
 
 /**
  * Build a registry of remote tools that execute inside a Sprite VM.
@@ -12,12 +34,14 @@ export function buildRemoteToolRegistry(
   config: SpriteAgentConfig,
   logger: Logger,
   allowedTools?: string[],
+  pathGuard?: PathGuard,
+  bashGuard?: BashGuard,
 ): AxFunction[] {
   const remoteTools = [
     createRemoteReadTool(vmName, config, logger),
-    createRemoteWriteTool(vmName, config, logger),
-    createRemoteEditTool(vmName, config, logger),
-    createRemoteBashTool(vmName, config, logger),
+    createRemoteWriteTool(vmName, config, logger, pathGuard),
+    createRemoteEditTool(vmName, config, logger, pathGuard),
+    createRemoteBashTool(vmName, config, logger, bashGuard),
     createRemoteGlobTool(vmName, config, logger),
     createRemoteGrepTool(vmName, config, logger),
   ];
@@ -34,7 +58,6 @@ function createRemoteReadTool(
   config: SpriteAgentConfig,
   logger: Logger,
 ): AxFunction {
-  const remoteCwd = "/home/user/project";
   return {
     name: "Read",
     description: "Read a file from the filesystem inside the Sprite VM.",
@@ -53,7 +76,7 @@ function createRemoteReadTool(
         // Use cat | base64 to safely read binary content
         const result = await execSprite(
           vmName,
-          ["sh", "-c", `cd ${remoteCwd} && cat \"${filePath}\" | base64`],
+          ["sh", "-c", `cd ${REMOTE_PROJECT_DIR} && cat \"${filePath}\" | base64`],
           config,
           logger,
         );
@@ -80,8 +103,8 @@ function createRemoteWriteTool(
   vmName: string,
   config: SpriteAgentConfig,
   logger: Logger,
+  pathGuard?: PathGuard,
 ): AxFunction {
-  const remoteCwd = "/home/user/project";
   return {
     name: "Write",
     description: "Write content to a file inside the Sprite VM.",
@@ -106,6 +129,10 @@ function createRemoteWriteTool(
       path: string;
       content: string;
     }) => {
+      const denied = deniedRemoteWrite(pathGuard, "Write", filePath);
+      if (denied) {
+        return `Error writing file ${filePath}: ${denied}`;
+      }
       try {
         // Use base64 | decode > file to safely write content
         const base64Content = Buffer.from(content).toString("base64");
@@ -114,7 +141,7 @@ function createRemoteWriteTool(
           [
             "sh",
             "-c",
-            `cd ${remoteCwd} && echo \"${base64Content}\" | base64 -d > \"${filePath}\" `,
+            `cd ${REMOTE_PROJECT_DIR} && echo \"${base64Content}\" | base64 -d > \"${filePath}\" `,
           ],
           config,
           logger,
@@ -136,8 +163,8 @@ function createRemoteEditTool(
   vmName: string,
   config: SpriteAgentConfig,
   logger: Logger,
+  pathGuard?: PathGuard,
 ): AxFunction {
-  const remoteCwd = "/home/user/project";
   return {
     name: "Edit",
     description: "Edit a file inside the Sprite VM by replacing a string with a new string.",
@@ -168,11 +195,15 @@ function createRemoteEditTool(
       old_string: string;
       new_string: string;
     }) => {
+      const denied = deniedRemoteWrite(pathGuard, "Edit", filePath);
+      if (denied) {
+        return `Error editing file ${filePath}: ${denied}`;
+      }
       try {
         // 1. Read file
         const readResult = await execSprite(
           vmName,
-          ["sh", "-c", `cd ${remoteCwd} && cat \"${filePath}\" | base64`],
+          ["sh", "-c", `cd ${REMOTE_PROJECT_DIR} && cat \"${filePath}\" | base64`],
           config,
           logger,
         );
@@ -196,7 +227,7 @@ function createRemoteEditTool(
           [
             "sh",
             "-c",
-            `cd ${remoteCwd} && echo \"${base64Content}\" | base64 -d > \"${filePath}\" `,
+            `cd ${REMOTE_PROJECT_DIR} && echo \"${base64Content}\" | base64 -d > \"${filePath}\" `,
           ],
           config,
           logger,
@@ -218,8 +249,8 @@ function createRemoteBashTool(
   vmName: string,
   config: SpriteAgentConfig,
   logger: Logger,
+  bashGuard?: BashGuard,
 ): AxFunction {
-  const remoteCwd = "/home/user/project";
   return {
     name: "Bash",
     description: "Execute a bash command inside the Sprite VM.",
@@ -234,10 +265,14 @@ function createRemoteBashTool(
       required: ["command"],
     } as AxFunctionJSONSchema,
     func: async ({ command }: { command: string }) => {
+      const refused = bashGuard?.check(command);
+      if (refused) {
+        return `Error: ${refused}`;
+      }
       try {
         const result = await execSprite(
           vmName,
-          ["sh", "-c", `cd ${remoteCwd} && ${command}`],
+          ["sh", "-c", `cd ${REMOTE_PROJECT_DIR} && ${command}`],
           config,
           logger,
         );
@@ -259,7 +294,6 @@ function createRemoteGlobTool(
   config: SpriteAgentConfig,
   logger: Logger,
 ): AxFunction {
-  const remoteCwd = "/home/user/project";
   return {
     name: "Glob",
     description: "Find files matching a glob pattern inside the Sprite VM.",
@@ -298,7 +332,7 @@ function createRemoteGlobTool(
 
         const result = await execSprite(
           vmName,
-          ["sh", "-c", `cd ${remoteCwd} && ${cmd}`],
+          ["sh", "-c", `cd ${REMOTE_PROJECT_DIR} && ${cmd}`],
           config,
           logger,
         );
@@ -325,7 +359,6 @@ function createRemoteGrepTool(
   config: SpriteAgentConfig,
   logger: Logger,
 ): AxFunction {
-  const remoteCwd = "/home/user/project";
   return {
     name: "Grep",
     description: "Search for a pattern in files inside the Sprite VM.",
@@ -366,7 +399,7 @@ function createRemoteGrepTool(
         args.push(pattern);
         args.push(dir);
 
-        const result = await execSprite(vmName, ["sh", "-c", `cd ${remoteCwd} && ${args.join(" ")}`], config, logger);
+        const result = await execSprite(vmName, ["sh", "-c", `cd ${REMOTE_PROJECT_DIR} && ${args.join(" ")}`], config, logger);
 
         if (result.exitCode !== 0 && result.exitCode !== 1) {
           // 1 means no matches, which is fine
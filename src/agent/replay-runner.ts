@@ -0,0 +1,167 @@
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import type { Logger } from "../logging";
+import type { Cassette, ReplayAgentConfig } from "../schemas";
+import type { AgentEvent } from "../tui/agentEvents";
+import { findRepoRoot } from "../fs/paths";
+import type { AgentResult } from "./runner";
+import { listCassettes, readCassette } from "./cassette";
+
+export interface ReplayRunAgentOptions {
+  config: ReplayAgentConfig;
+  cwd: string;
+  prompt: string;
+  logger: Logger;
+  dryRun?: boolean;
+  onStdoutChunk?: (chunk: string) => void;
+  onStderrChunk?: (chunk: string) => void;
+  onAgentEvent?: (event: AgentEvent) => void;
+  mcpServers?: Record<string, unknown>;
+}
+
+// Cassettes already played back in this process, so that a phase that runs
+// the agent twice with the same prompt gets its two recordings in order
+const usedCassettes = new Set<string>();
+
+export function resetReplayState(): void {
+  usedCassettes.clear();
+}
+
+/**
+ * Pick the cassette for a prompt: the first unused one recorded with the
+ * same prompt, otherwise (unless strict) the next unused one.
+ */
+async function selectCassette(
+  files: string[],
+  options: ReplayRunAgentOptions,
+): Promise<{ file: string; cassette: Cassette } | null> {
+  let fallback: { file: string; cassette: Cassette } | null = null;
+  for (const file of files) {
+    if (usedCassettes.has(file)) continue;
+    const cassette = await readCassette(file);
+    if (cassette.prompt === options.prompt) {
+      return { file, cassette };
+    }
+    fallback ??= { file, cassette };
+  }
+
+  if (fallback && !options.config.strict) {
+    options.logger.warn(
+      `No cassette matches the prompt; replaying ${path.basename(fallback.file)}`,
+    );
+    return fallback;
+  }
+  return null;
+}
+
+type McpToolHandler = (args: Record<string, unknown>) => Promise<unknown>;
+
+/**
+ * Find the handler of an in-process MCP tool from its SDK name,
+ * e.g. `mcp__wreckit__save_prd`.
+ */
+function findMcpHandler(
+  mcpServers: Record<string, any> | undefined,
+  toolName: string,
+): McpToolHandler | null {
+  const match = /^mcp__(.+?)__(.+)$/.exec(toolName);
+  const server = match && mcpServers?.[match[1]];
+  if (!server || !Array.isArray(server.tools)) {
+    return null;
+  }
+  const tool = server.tools.find((t: any) => t.name === match[2]);
+  return tool?.handler ?? null;
+}
+
+async function writeFileChanges(
+  root: string,
+  cassette: Cassette,
+): Promise<void> {
+  for (const change of cassette.file_changes) {
+    const filePath = path.join(root, change.path);
+    if (change.content === null) {
+      await fs.rm(filePath, { force: true });
+      continue;
+    }
+    await fs.mkdir(path.dirname(filePath), { recursive: true });
+    await fs.writeFile(filePath, Buffer.from(change.content, change.encoding));
+  }
+}
+
+/**
+ * Play back an agent run recorded with `wreckit --record`.
+ *
+ * The recorded output and events are streamed again in order. Calls to
+ * wreckit's MCP tools are made again against the servers of this run, so
+ * the workflow captures the same PRD, story updates and so on; the files the
+ * recorded run changed are then written to the working tree.
+ */
+export async function runReplayAgent(
+  options: ReplayRunAgentOptions,
+): Promise<AgentResult> {
+  const { config, logger } = options;
+  const root = findRepoRoot(options.cwd);
+
+  if (options.dryRun) {
+    logger.info(`[dry-run] Would replay a cassette from ${config.cassette_dir}`);
+    return {
+      success: true,
+      output: "[dry-run] Replay agent not executed",
+      timedOut: false,
+      exitCode: 0,
+      completionDetected: true,
+    };
+  }
+
+  const dir = path.resolve(root, config.cassette_dir);
+  const selected = await selectCassette(await listCassettes(dir), options);
+  if (!selected) {
+    const message = config.strict
+      ? `No unused cassette in ${dir} was recorded with this prompt`
+      : `No unused cassettes left in ${dir}`;
+    logger.error(message);
+    options.onAgentEvent?.({ type: "error", message });
+    return {
+      success: false,
+      output: message,
+      timedOut: false,
+      exitCode: 1,
+      completionDetected: false,
+    };
+  }
+
+  const { file, cassette } = selected;
+  usedCassettes.add(file);
+  logger.debug(`Replaying ${file}`);
+
+  for (const entry of cassette.timeline) {
+    if (entry.type === "stdout") {
+      options.onStdoutChunk?.(entry.chunk);
+      continue;
+    }
+    if (entry.type === "stderr") {
+      options.onStderrChunk?.(entry.chunk);
+      continue;
+    }
+
+    const event = entry.event as AgentEvent;
+    options.onAgentEvent?.(event);
+    if (event.type === "tool_started") {
+      const handler = findMcpHandler(options.mcpServers, event.toolName);
+      if (handler) {
+        try {
+          await handler(event.input);
+        } catch (err) {
+          logger.warn(
+            `Replayed tool ${event.toolName} failed: ${err instanceof Error ? err.message : String(err)}`,
+          );
+        }
+      }
+    }
+  }
+
+  await writeFileChanges(root, cassette);
+
+  const { usage, ...result } = cassette.result;
+  return { ...result, ...(usage && { usage }) };
+}
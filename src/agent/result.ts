@@ -1,3 +1,6 @@
+import type { AgentUsage } from "../schemas";
+import type { StallInfo } from "./stallDetector";
+
 /**
  * Result of an agent execution.
  * This is the standard return type for all agent runners.
@@ -13,4 +16,14 @@ export interface AgentResult {
   exitCode: number | null;
   /** Whether the completion signal was detected */
   completionDetected: boolean;
+  /** Tokens used and the model, when the runner can report them */
+  usage?: AgentUsage;
+  /** Label of the agent that produced the result, when run via a chain */
+  agent?: string;
+  /** Why the run was aborted as stuck */
+  stall?: StallInfo;
+  /** Scope violations the run was aborted for */
+  scopeViolation?: string;
+  /** Denied command the run was aborted for */
+  policyViolation?: string;
 }
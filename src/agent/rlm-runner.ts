@@ -3,14 +3,17 @@ import { createAxAI } from "./axai-factory";
 import { buildSdkEnv } from "./env";
 import { buildToolRegistry, JSRuntime, defaultLocalExecutor, type Executor } from "./rlm-tools";
 import { buildRemoteToolRegistry } from "./remote-tools";
+import type { PathGuard } from "./pathPolicy";
+import type { BashGuard } from "./bashPolicy";
 import { adaptMcpServersToAxTools } from "./mcp/mcporterAdapter";
 import { registerSdkController, unregisterSdkController } from "./lifecycle";
 import { AgentEvent } from "../tui/agentEvents";
 import { findRepoRoot } from "../fs/paths";
 import { syncProjectToVM, syncProjectFromVM } from "../fs/sync";
 import type { Logger } from "../logging";
-import type { RlmSdkAgentConfig } from "../schemas";
+import type { AgentUsage, RlmSdkAgentConfig } from "../schemas";
 import type { AgentResult } from "./runner";
+import { addAgentUsage, normalizeUsage } from "./usage";
 import type { AxFunction } from "@ax-llm/ax";
 
 import {
@@ -36,6 +39,10 @@ export interface RlmRunAgentOptions {
   phase?: string;
   timeoutSeconds?: number;
   itemId?: string;
+  /** Refuses Write and Edit calls outside the phase's path policy */
+  pathGuard?: PathGuard;
+  /** Refuses Bash commands the bash_policy does not allow */
+  bashGuard?: BashGuard;
 }
 
 async function ensureSpriteRunning(
@@ -208,6 +215,7 @@ export async function runRlmAgent(
 
   let timeoutId: ReturnType<typeof setTimeout> | undefined;
   let vmName: string | undefined;
+  let usage: AgentUsage | null = null;
 
   try {
     const env = await buildSdkEnv({ cwd, logger });
@@ -276,12 +284,17 @@ export async function runRlmAgent(
         spriteConfig as any,
         logger,
         options.allowedTools,
+        options.pathGuard,
+        options.bashGuard,
       );
     } else {
       builtInAxTools = buildToolRegistry(
         options.allowedTools,
         undefined, // No JS Runtime
         executor,
+        undefined,
+        options.pathGuard,
+        options.bashGuard,
       );
     }
 
@@ -386,6 +399,14 @@ IMPORTANT INSTRUCTIONS:
           throw new Error(`Anthropic API Error: ${response.error.message}`);
       }
 
+      usage = addAgentUsage(
+        usage,
+        normalizeUsage(response.usage, response.model ?? null),
+      );
+      if (usage) {
+        onAgentEvent?.({ type: "usage_progress", usage });
+      }
+
       messages.push({ role: "assistant", content: response.content });
 
       let turnOutput = "";
@@ -476,6 +497,7 @@ IMPORTANT INSTRUCTIONS:
           timedOut: false,
           exitCode: 1,
           completionDetected: true,
+          ...(usage && { usage }),
         };
       }
     }
@@ -486,6 +508,7 @@ IMPORTANT INSTRUCTIONS:
       timedOut: false,
       exitCode: 0,
       completionDetected: true,
+      ...(usage && { usage }),
     };
 
   } catch (error: any) {
@@ -497,6 +520,7 @@ IMPORTANT INSTRUCTIONS:
       timedOut: false,
       exitCode: 1,
       completionDetected: false,
+      ...(usage && { usage }),
     };
   } finally {
     unregisterSdkController(abortController);
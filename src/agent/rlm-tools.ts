@@ -4,6 +4,8 @@ import { exec } from "node:child_process";
 import { promisify } from "node:util";
 import * as vm from "node:vm";
 import type { AxFunction, AxFunctionJSONSchema } from "@ax-llm/ax";
+import { toRepoPath, type PathGuard } from "./pathPolicy";
+import type { BashGuard } from "./bashPolicy";
 
 const execAsyncLocal = promisify(exec);
 
@@ -17,6 +19,13 @@ export const defaultLocalExecutor: Executor = async (command: string) => {
   return execAsyncLocal(command);
 };
 
+/**
+ * Executor that runs commands in `cwd` instead of the process directory.
+ */
+export function createLocalExecutor(cwd: string): Executor {
+  return async (command: string) => execAsyncLocal(command, { cwd });
+}
+
 export class JSRuntime {
   private context: vm.Context;
   private executor: Executor;
@@ -86,7 +95,23 @@ export function createRunJSTool(runtime: JSRuntime): AxFunction {
   };
 }
 
-export function createTools(executor: Executor = defaultLocalExecutor): ToolRegistry {
+export function createTools(
+  executor: Executor = defaultLocalExecutor,
+  cwd?: string,
+  pathGuard?: PathGuard,
+  bashGuard?: BashGuard,
+): ToolRegistry {
+  // Relative paths resolve against the agent's working directory when given
+  const resolvePath = (filePath: string) =>
+    cwd ? path.resolve(cwd, filePath) : filePath;
+
+  // Why the phase's path policy refuses a write, if it does
+  const deniedWrite = (tool: string, filePath: string) =>
+    pathGuard?.check(
+      tool,
+      toRepoPath(pathGuard.root, cwd ?? process.cwd(), filePath),
+    ) ?? null;
+
   const ReadTool: AxFunction = {
     name: "Read",
     description: "Read the contents of a file. Returns the content as a string.",
@@ -134,6 +159,10 @@ export function createTools(executor: Executor = defaultLocalExecutor): ToolRegi
       path: string;
       content: string;
     }) => {
+      const denied = deniedWrite("Write", filePath);
+      if (denied) {
+        return `Error writing file ${filePath}: ${denied}`;
+      }
       try {
         // Use executor to write. 
         // We need to be careful with escaping.
@@ -158,8 +187,9 @@ export function createTools(executor: Executor = defaultLocalExecutor): ToolRegi
         // The pattern is: Local logic -> Sync to VM -> Remote Exec -> Sync back.
         // So RLM writes to LOCAL disk.
         
-        await fs.mkdir(path.dirname(filePath), { recursive: true });
-        await fs.writeFile(filePath, content, "utf-8");
+        const target = resolvePath(filePath);
+        await fs.mkdir(path.dirname(target), { recursive: true });
+        await fs.writeFile(target, content, "utf-8");
         return `Successfully wrote to ${filePath}`;
       } catch (error: any) {
         return `Error writing file ${filePath}: ${error.message}`;
@@ -171,7 +201,7 @@ export function createTools(executor: Executor = defaultLocalExecutor): ToolRegi
   // We assume bi-directional sync is handling the consistency.
   ReadTool.func = async ({ path: filePath }: { path: string }) => {
       try {
-        const content = await fs.readFile(filePath, "utf-8");
+        const content = await fs.readFile(resolvePath(filePath), "utf-8");
         return content;
       } catch (error: any) {
         return `Error reading file ${filePath}: ${error.message}`;
@@ -200,13 +230,18 @@ export function createTools(executor: Executor = defaultLocalExecutor): ToolRegi
       oldText: string;
       newText: string;
     }) => {
+      const denied = deniedWrite("Edit", filePath);
+      if (denied) {
+        return `Error editing file ${filePath}: ${denied}`;
+      }
       try {
-        const content = await fs.readFile(filePath, "utf-8");
+        const target = resolvePath(filePath);
+        const content = await fs.readFile(target, "utf-8");
         if (!content.includes(oldText)) {
           return `Error: oldText not found in ${filePath}`;
         }
         const newContent = content.replace(oldText, newText);
-        await fs.writeFile(filePath, newContent, "utf-8");
+        await fs.writeFile(target, newContent, "utf-8");
         return `Successfully edited ${filePath}`;
       } catch (error: any) {
         return `Error editing file ${filePath}: ${error.message}`;
@@ -305,6 +340,10 @@ export function createTools(executor: Executor = defaultLocalExecutor): ToolRegi
       required: ["command"],
     } as AxFunctionJSONSchema,
     func: async ({ command }: { command: string }) => {
+      const refused = bashGuard?.check(command);
+      if (refused) {
+        return `Error: ${refused}`;
+      }
       try {
         // Use executor for Bash
         const { stdout, stderr } = await executor(command);
@@ -329,8 +368,11 @@ export function buildToolRegistry(
   allowedTools?: string[],
   jsRuntime?: JSRuntime,
   executor: Executor = defaultLocalExecutor,
+  cwd?: string,
+  pathGuard?: PathGuard,
+  bashGuard?: BashGuard,
 ): AxFunction[] {
-  const registry = createTools(executor);
+  const registry = createTools(executor, cwd, pathGuard, bashGuard);
   
   let tools = allowedTools
     ? allowedTools
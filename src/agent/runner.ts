@@ -1,11 +1,37 @@
-import type { ConfigResolved } from "../config";
+import { getPhaseSettings, type ConfigResolved } from "../config";
 import type { Logger } from "../logging";
 import type { AgentEvent } from "../tui/agentEvents";
 import type {
   AgentConfigUnion,
+  AgentUsage,
+  BudgetConfig,
+  ModelPrice,
   ProcessAgentConfig,
   ClaudeSdkAgentConfig,
+  PhaseName,
+  PathPolicy,
+  BashPolicyConfig,
 } from "../schemas";
+import { findRepoRoot } from "../fs/paths";
+import { findBudgetBreach, type BudgetSpend } from "../domain/budget";
+import { computeCost } from "../domain/usage";
+import { BudgetExceededError } from "../errors";
+import {
+  abortAgentScope,
+  createAgentScope,
+  runInAgentScope,
+} from "./lifecycle.js";
+import { beginCassette } from "./cassette";
+import { agentLabel, classifyTransientFailure } from "./fallback";
+import {
+  createStallWatcher,
+  workingTreeFingerprint,
+  type StallInfo,
+  type StallLimits,
+} from "./stallDetector";
+import { createLiveScopeWatcher, type LiveScopeLimits } from "./scope";
+import { createPathGuard, type PathGuard } from "./pathPolicy";
+import { createBashGuard, type BashGuard } from "./bashPolicy";
 
 // ============================================================ 
 // Lifecycle Management (Re-exported from lifecycle module)
@@ -31,7 +57,8 @@ export { runProcessAgent } from "./process-runner.js";
 
 /**
  * Result returned by all agent runners.
- * Contains success status, output, timeout info, and exit code.
+ * Contains success status, output, timeout info, exit code and, where the
+ * runner can report it, token usage.
  */
 export interface AgentResult {
   success: boolean;
@@ -39,6 +66,15 @@ export interface AgentResult {
   timedOut: boolean;
   exitCode: number | null;
   completionDetected: boolean;
+  usage?: AgentUsage;
+  /** Label of the agent that produced the result, see agentLabel */
+  agent?: string;
+  /** Why the run was aborted as stuck, see stallDetector */
+  stall?: StallInfo;
+  /** Scope violations the run was aborted for, see createLiveScopeWatcher */
+  scopeViolation?: string;
+  /** Denied command the run was aborted for, see bash_policy */
+  policyViolation?: string;
 }
 
 // ============================================================ 
@@ -53,6 +89,7 @@ export interface AgentResult {
  * the modern agent dispatch system.
  * 
  * @param config - The resolved wreckit configuration
+ * @param phase - Phase being run; applies its `phases.<phase>.agent` override
  * @returns The agent configuration in union format (AgentConfigUnion)
  * 
  * @example
@@ -61,8 +98,11 @@ export interface AgentResult {
  * const result = await runAgentUnion({ config: agentConfig, cwd: "/project", ... });
  * ```
  */
-export function getAgentConfigUnion(config: ConfigResolved): AgentConfigUnion {
-  return config.agent;
+export function getAgentConfigUnion(
+  config: ConfigResolved,
+  phase?: PhaseName,
+): AgentConfigUnion {
+  return phase ? getPhaseSettings(config, phase).agent : config.agent;
 }
 
 // ============================================================ 
@@ -86,6 +126,53 @@ export interface UnionRunAgentOptions {
   allowedTools?: string[];
   /** Item ID for VM naming (used when ephemeral mode is enabled) */
   itemId?: string;
+  /** Spend caps; the run is aborted once its cost reaches one */
+  budget?: AgentBudget;
+  /** Agents to try in order when `config` fails transiently */
+  fallbacks?: AgentConfigUnion[];
+  /** Stuck agent limits; a stuck run is nudged, then aborted */
+  stall?: StallLimits;
+  /** Message to pass to the agent mid-run, polled by runners that can */
+  takeNudge?: () => string | null;
+  /** Story scope checked while the agent runs; crossing it aborts the run */
+  scope?: LiveScopeLimits;
+  /** Where file-writing tools may write, see pathPolicy */
+  pathPolicy?: PathPolicy;
+  /** Enforces `pathPolicy` in the runners that support it */
+  pathGuard?: PathGuard;
+  /** What the Bash tool may run, see bashPolicy */
+  bashPolicy?: BashPolicyConfig;
+  /** Enforces `bashPolicy` in the runners that support it */
+  bashGuard?: BashGuard;
+}
+
+/**
+ * Spend caps for an agent run and the spend that counts towards them.
+ */
+export interface AgentBudget {
+  limits: BudgetConfig;
+  prices?: Record<string, ModelPrice>;
+  /** USD already spent in each capped scope, not counting this run */
+  getSpent(): BudgetSpend;
+}
+
+/**
+ * The error for the first cap reached once `usage` is added to the spend.
+ */
+function checkBudget(
+  budget: AgentBudget,
+  usage: AgentUsage | null,
+): BudgetExceededError | null {
+  const cost = usage ? (computeCost(usage, budget.prices) ?? 0) : 0;
+  const spent = budget.getSpent();
+  const breach = findBudgetBreach(budget.limits, {
+    item: spent.item + cost,
+    phase: spent.phase + cost,
+    session: spent.session + cost,
+  });
+  return breach
+    ? new BudgetExceededError(breach.cap, breach.limit_usd, breach.spent_usd)
+    : null;
 }
 
 function exhaustiveCheck(x: never): never {
@@ -106,6 +193,8 @@ function exhaustiveCheck(x: never): never {
  * - `codex_sdk`: OpenAI Codex SDK integration
  * - `opencode_sdk`: OpenCode SDK integration
  * - `rlm`: Recursive Language Model mode (experimental)
+ * - `openai_compat`: Any OpenAI-compatible chat completions endpoint
+ * - `replay`: Plays back runs recorded with `wreckit --record`
  * 
  * **Features:**
  * - Type-safe dispatch based on agent kind
@@ -114,6 +203,17 @@ function exhaustiveCheck(x: never): never {
  * - MCP server integration
  * - Tool allowlist support
  * - Streaming output via callbacks
+ * - Token usage reported as a `usage` agent event
+ * - Spend caps via `budget`, aborting the run once a cap is reached
+ * - Recording to a cassette while `--record` is active
+ * - Fallback to the next agent of `fallbacks` on rate limits, overload,
+ *   expired credentials or a missing CLI binary
+ * - Stuck agent detection via `stall`, nudging then aborting the run
+ * - Live story scope checks via `scope`, aborting the run once exceeded
+ * - Write path policies via `pathPolicy`, for claude_sdk, rlm, sprite and
+ *   openai_compat; other runners warn that they cannot enforce them
+ * - Bash command policies via `bashPolicy`, for the same runners; with
+ *   `abort_on_violation` a denied command aborts the run
  * 
  * @param options - Union run options with AgentConfigUnion
  * @returns Promise<AgentResult> with execution results
@@ -190,6 +290,294 @@ export async function runAgentUnion(
     };
   }
 
+  const chain = [config, ...(options.fallbacks ?? [])];
+  for (let index = 0; ; index++) {
+    const agent = chain[index];
+    const label = agentLabel(agent);
+    options.onAgentEvent?.({ type: "agent_started", agent: label });
+
+    const result = await runSingleAgent({ ...options, config: agent });
+    const reason = classifyTransientFailure(result);
+    const next = chain[index + 1];
+    if (!reason || !next) {
+      return { ...result, agent: label };
+    }
+
+    const nextLabel = agentLabel(next);
+    logger.warn(`${label} failed (${reason}); falling back to ${nextLabel}`);
+    options.onAgentEvent?.({
+      type: "agent_fallback",
+      from: label,
+      to: nextLabel,
+      reason,
+    });
+  }
+}
+
+/**
+ * Repository root for a working directory, or the directory itself outside
+ * a wreckit repository.
+ */
+function repoRootOf(cwd: string): string {
+  try {
+    return findRepoRoot(cwd);
+  } catch {
+    return cwd;
+  }
+}
+
+// Backends that take a message while they run
+const NUDGE_KINDS = new Set<AgentConfigUnion["kind"]>(["openai_compat"]);
+// Backends that edit files inside a VM, so the local working tree says
+// nothing about their progress until they finish
+const REMOTE_KINDS = new Set<AgentConfigUnion["kind"]>(["sprite"]);
+// Backends whose tools honour path and bash policies; replay runs no tools
+const GUARDED_KINDS = new Set<AgentConfigUnion["kind"]>([
+  "claude_sdk",
+  "rlm",
+  "sprite",
+  "openai_compat",
+  "replay",
+]);
+
+const warned = new Set<string>();
+
+/**
+ * Warn about a limit a backend cannot honour, once per process.
+ */
+function warnUnsupported(logger: Logger, message: string): void {
+  if (!warned.has(message)) {
+    warned.add(message);
+    logger.warn(message);
+  }
+}
+
+/**
+ * Stall limits a backend can be held to: file changes are not tracked for
+ * agents that edit files inside a VM.
+ */
+function stallLimitsFor(
+  options: UnionRunAgentOptions,
+): StallLimits | undefined {
+  const { stall, config, logger } = options;
+  if (stall?.max_idle_seconds === undefined || !REMOTE_KINDS.has(config.kind)) {
+    return stall;
+  }
+  warnUnsupported(
+    logger,
+    `stall_detection.max_idle_seconds is not checked for ${config.kind} agents, whose files change inside their VM`,
+  );
+  return { ...stall, max_idle_seconds: undefined };
+}
+
+/**
+ * Run one agent of the chain, recording it to a cassette and holding it to
+ * the budget, stall limits and story scope.
+ */
+async function runSingleAgent(
+  options: UnionRunAgentOptions,
+): Promise<AgentResult> {
+  const { logger, budget } = options;
+  if (budget) {
+    const alreadyExceeded = checkBudget(budget, null);
+    if (alreadyExceeded) {
+      throw alreadyExceeded;
+    }
+  }
+
+  const recording = await beginCassette(options);
+  const runOptions = recording?.options ?? options;
+
+  // Runners that report usage while running are aborted as soon as a cap is
+  // reached; the others are checked when they finish
+  const scope = createAgentScope();
+  let progress = null as AgentUsage | null;
+  let exceeded = null as BudgetExceededError | null;
+  const enforce = (usage: AgentUsage): void => {
+    if (!budget || exceeded) return;
+    exceeded = checkBudget(budget, usage);
+    if (exceeded) {
+      logger.warn(`${exceeded.message}; aborting agent`);
+      abortAgentScope(scope, logger);
+    }
+  };
+
+  let nudge = null as string | null;
+  let stalled = null as StallInfo | null;
+  const stallLimits = stallLimitsFor(options);
+  const watcher = stallLimits
+    ? createStallWatcher({
+        limits: stallLimits,
+        canNudge: NUDGE_KINDS.has(options.config.kind),
+        fingerprint: () => workingTreeFingerprint(options.cwd, logger),
+        onNudge: (stall, message) => {
+          logger.warn(`Agent looks stuck (${stall.message}); nudging it`);
+          nudge = message;
+          runOptions.onAgentEvent?.({
+            type: "stall_detected",
+            ...stall,
+            action: "nudge",
+          });
+        },
+        onStall: (stall) => {
+          logger.warn(`Agent is stuck (${stall.message}); aborting agent`);
+          stalled = stall;
+          runOptions.onAgentEvent?.({
+            type: "stall_detected",
+            ...stall,
+            action: "abort",
+          });
+          abortAgentScope(scope, logger);
+        },
+      })
+    : null;
+
+  let scopeViolation = null as string | null;
+  // A remote agent's diff only reaches the working tree once it finishes,
+  // where the story's scope is checked again
+  let liveScope = options.scope;
+  if (liveScope && REMOTE_KINDS.has(options.config.kind)) {
+    warnUnsupported(
+      logger,
+      `story_scope.live_check is not supported for ${options.config.kind} agents; scope is checked after the run`,
+    );
+    liveScope = undefined;
+  }
+  const scopeWatcher = liveScope
+    ? createLiveScopeWatcher({
+        ...liveScope,
+        onViolation: (violation, message) => {
+          logger.warn(`Story scope exceeded; aborting agent\n${message}`);
+          scopeViolation = message;
+          runOptions.onAgentEvent?.({
+            type: "scope_exceeded",
+            violations: violation.violations.map((v) => v.message),
+          });
+          abortAgentScope(scope, logger);
+        },
+      })
+    : null;
+
+  if (options.pathPolicy && !GUARDED_KINDS.has(options.config.kind)) {
+    warnUnsupported(
+      logger,
+      `path_policies are not enforced for ${options.config.kind} agents; their writes are not checked`,
+    );
+  }
+  const pathGuard = options.pathPolicy
+    ? createPathGuard(
+        options.pathPolicy,
+        repoRootOf(options.cwd),
+        (denial) => {
+          logger.warn(`${denial.tool} denied: ${denial.reason}`);
+          runOptions.onAgentEvent?.({ type: "path_denied", ...denial });
+        },
+      )
+    : undefined;
+
+  let policyViolation = null as string | null;
+  const bashPolicy = options.bashPolicy;
+  if (bashPolicy && !GUARDED_KINDS.has(options.config.kind)) {
+    warnUnsupported(
+      logger,
+      `bash_policy is not enforced for ${options.config.kind} agents; their commands are not checked`,
+    );
+  }
+  const bashGuard = bashPolicy
+    ? createBashGuard(bashPolicy, (refusal) => {
+        logger.warn(
+          `Bash command ${refusal.action === "deny" ? "denied" : "needs approval"}: ${refusal.command} (${refusal.reason})`,
+        );
+        runOptions.onAgentEvent?.({ type: "command_denied", ...refusal });
+        if (
+          refusal.action === "deny" &&
+          bashPolicy.abort_on_violation &&
+          !policyViolation
+        ) {
+          policyViolation = `${refusal.command} (${refusal.reason})`;
+          abortAgentScope(scope, logger);
+        }
+      })
+    : undefined;
+
+  let result: AgentResult;
+  try {
+    result = await runInAgentScope(scope, () =>
+      runAgentByKind({
+        ...runOptions,
+        pathGuard,
+        bashGuard,
+        onAgentEvent: (event) => {
+          if (event.type === "usage_progress") {
+            progress = event.usage;
+            enforce(event.usage);
+          }
+          runOptions.onAgentEvent?.(event);
+          watcher?.observe(event);
+          scopeWatcher?.observe(event);
+        },
+        takeNudge: () => {
+          const message = nudge;
+          nudge = null;
+          return message;
+        },
+      }),
+    );
+  } finally {
+    watcher?.stop();
+    scopeWatcher?.stop();
+  }
+
+  await recording?.finish(result);
+
+  const usage = result.usage ?? progress;
+  if (usage) {
+    // Checked before the event: listeners add the usage to the spend that
+    // getSpent reports, and the run would otherwise be counted twice
+    enforce(usage);
+    options.onAgentEvent?.({ type: "usage", usage });
+  }
+  if (exceeded) {
+    throw exceeded;
+  }
+  if (stalled) {
+    return {
+      ...result,
+      success: false,
+      completionDetected: false,
+      output: `${result.output}\nAgent stopped as stuck: ${stalled.message}\n`,
+      stall: stalled,
+    };
+  }
+  if (scopeViolation) {
+    return {
+      ...result,
+      success: false,
+      completionDetected: false,
+      output: `${result.output}\nAgent stopped for exceeding story scope:\n${scopeViolation}\n`,
+      scopeViolation,
+    };
+  }
+  if (policyViolation) {
+    return {
+      ...result,
+      success: false,
+      completionDetected: false,
+      output: `${result.output}\nAgent stopped for running a denied command: ${policyViolation}\n`,
+      policyViolation,
+    };
+  }
+  return result;
+}
+
+/**
+ * Dispatch to the runner for the config's agent kind.
+ */
+async function runAgentByKind(
+  options: UnionRunAgentOptions,
+): Promise<AgentResult> {
+  const { config } = options;
+
   switch (config.kind) {
     case "process": {
       const { runProcessAgent } = await import("./process-runner.js");
@@ -224,6 +612,8 @@ export async function runAgentUnion(
         mcpServers: options.mcpServers,
         allowedTools: options.allowedTools,
         timeoutSeconds: options.timeoutSeconds,
+        pathGuard: options.pathGuard,
+        bashGuard: options.bashGuard,
       });
     }
 
@@ -290,6 +680,8 @@ export async function runAgentUnion(
         allowedTools: options.allowedTools,
         timeoutSeconds: options.timeoutSeconds,
         itemId: options.itemId,
+        pathGuard: options.pathGuard,
+        bashGuard: options.bashGuard,
       });
     }
 
@@ -306,9 +698,49 @@ export async function runAgentUnion(
         mockAgent: options.mockAgent,
         onStdoutChunk: options.onStdoutChunk,
         onStderrChunk: options.onStderrChunk,
+        onAgentEvent: options.onAgentEvent,
         timeoutSeconds: options.timeoutSeconds,
         ephemeral: isEphemeral,
         itemId: options.itemId,
+        pathGuard: options.pathGuard,
+        bashGuard: options.bashGuard,
+      });
+    }
+
+    case "openai_compat": {
+      const { runOpenAiCompatAgent } = await import(
+        "./openai-compat-runner.js"
+      );
+      return runOpenAiCompatAgent({
+        config,
+        cwd: options.cwd,
+        prompt: options.prompt,
+        logger: options.logger,
+        dryRun: options.dryRun,
+        onStdoutChunk: options.onStdoutChunk,
+        onStderrChunk: options.onStderrChunk,
+        onAgentEvent: options.onAgentEvent,
+        mcpServers: options.mcpServers,
+        allowedTools: options.allowedTools,
+        timeoutSeconds: options.timeoutSeconds,
+        takeNudge: options.takeNudge,
+        pathGuard: options.pathGuard,
+        bashGuard: options.bashGuard,
+      });
+    }
+
+    case "replay": {
+      const { runReplayAgent } = await import("./replay-runner.js");
+      return runReplayAgent({
+        config,
+        cwd: options.cwd,
+        prompt: options.prompt,
+        logger: options.logger,
+        dryRun: options.dryRun,
+        onStdoutChunk: options.onStdoutChunk,
+        onStderrChunk: options.onStderrChunk,
+        onAgentEvent: options.onAgentEvent,
+        mcpServers: options.mcpServers,
       });
     }
 
@@ -9,7 +9,15 @@
 import type { Logger } from "../logging";
 import type { GitFileChange } from "../git/status";
 import type { StoryScopeConfig } from "../schemas";
-import { getWorkingTreeDiffStats, configToOptions, formatScopeViolations } from "../git/scope";
+import type { AgentEvent } from "../tui/agentEvents";
+import {
+  getWorkingTreeDiffStats,
+  configToOptions,
+  formatScopeViolations,
+  validateStoryScope as validateGitDiffScope,
+  type DiffStats,
+  type StoryScopeResult,
+} from "../git/scope";
 
 // Re-export types from git/scope for convenience
 export type {
@@ -285,3 +293,93 @@ export class ScopeEnforcer {
     return this.enabled;
   }
 }
+
+/**
+ * Live story scope check: limits, and how to measure the story's diff
+ */
+export interface LiveScopeLimits {
+  config: StoryScopeConfig;
+  storyId: string;
+  getStats: () => Promise<DiffStats>;
+}
+
+export interface LiveScopeWatcherOptions extends LiveScopeLimits {
+  /** Called once, with the result and its formatted violations */
+  onViolation: (result: StoryScopeResult, message: string) => void;
+}
+
+export interface LiveScopeWatcher {
+  observe(event: AgentEvent): void;
+  stop(): void;
+}
+
+// Tools whose results mean files were written
+const EDIT_TOOLS = new Set(["Write", "Edit", "MultiEdit", "NotebookEdit"]);
+
+/**
+ * Check story scope while the agent works: after every file-writing tool
+ * result and every `live_check_interval_seconds`. The first time the diff
+ * crosses a limit, `onViolation` is called and the watcher stops.
+ */
+export function createLiveScopeWatcher(
+  options: LiveScopeWatcherOptions,
+): LiveScopeWatcher {
+  const { config, storyId } = options;
+  const scopeOptions = configToOptions(config);
+  const editToolIds = new Set<string>();
+  let stopped = false;
+  let checking = false;
+  let recheck = false;
+
+  const check = async (): Promise<void> => {
+    if (stopped) return;
+    if (checking) {
+      recheck = true;
+      return;
+    }
+    checking = true;
+    try {
+      do {
+        recheck = false;
+        const stats = await options.getStats();
+        const result = validateGitDiffScope(stats, scopeOptions, storyId);
+        if (!result.valid && !stopped) {
+          stop();
+          options.onViolation(
+            result,
+            formatScopeViolations(result, storyId),
+          );
+        }
+      } while (recheck && !stopped);
+    } catch {
+      // A failed check is retried on the next edit or interval
+    } finally {
+      checking = false;
+    }
+  };
+
+  const interval = setInterval(
+    () => void check(),
+    config.live_check_interval_seconds * 1000,
+  );
+
+  function stop(): void {
+    stopped = true;
+    clearInterval(interval);
+  }
+
+  return {
+    observe(event) {
+      if (stopped) return;
+      if (event.type === "tool_started" && EDIT_TOOLS.has(event.toolName)) {
+        editToolIds.add(event.toolUseId);
+      } else if (
+        event.type === "tool_result" &&
+        editToolIds.delete(event.toolUseId)
+      ) {
+        void check();
+      }
+    },
+    stop,
+  };
+}
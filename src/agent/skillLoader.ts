@@ -54,16 +54,20 @@ export interface SkillLoadResult {
  *
  * @param phase - The workflow phase (e.g., "research", "implement")
  * @param skillConfig - Optional skill configuration from wreckit config
+ * @param phaseToolsOverride - Optional allowlist replacing the phase default (e.g. from a pipeline stage)
  * @returns Skill load result with merged tools, MCP servers, and context requirements
  */
 export function loadSkillsForPhase(
   phase: string,
   skillConfig: SkillConfig | undefined,
+  phaseToolsOverride?: string[],
 ): SkillLoadResult {
+  const basePhaseTools = phaseToolsOverride ?? PHASE_TOOL_ALLOWLISTS[phase];
+
   // Default result if no skills configured
   if (!skillConfig) {
     return {
-      allowedTools: PHASE_TOOL_ALLOWLISTS[phase],
+      allowedTools: basePhaseTools,
       mcpServers: {},
       contextRequirements: [],
       loadedSkillIds: [],
@@ -74,7 +78,7 @@ export function loadSkillsForPhase(
   const skillIds = skillConfig.phase_skills[phase];
   if (!skillIds || skillIds.length === 0) {
     return {
-      allowedTools: PHASE_TOOL_ALLOWLISTS[phase],
+      allowedTools: basePhaseTools,
       mcpServers: {},
       contextRequirements: [],
       loadedSkillIds: [],
@@ -93,7 +97,7 @@ export function loadSkillsForPhase(
   }
 
   // Get phase tool allowlist (security boundary)
-  const phaseTools = PHASE_TOOL_ALLOWLISTS[phase];
+  const phaseTools = basePhaseTools;
 
   // Merge skill tools (union of all skill tools)
   const skillTools = new Set<string>();
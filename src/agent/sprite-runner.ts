@@ -1,14 +1,17 @@
 import { createAxAI } from "./axai-factory";
 import { buildSdkEnv } from "./env";
 import { buildRemoteToolRegistry } from "./remote-tools";
+import type { PathGuard } from "./pathPolicy";
+import type { BashGuard } from "./bashPolicy";
 import { adaptMcpServersToAxTools } from "./mcp/mcporterAdapter";
 import { registerSdkController, unregisterSdkController } from "./lifecycle";
 import { AgentEvent } from "../tui/agentEvents";
 import { findRepoRoot } from "../fs/paths";
 import { syncProjectToVM } from "../fs/sync";
 import type { Logger } from "../logging";
-import type { SpriteAgentConfig } from "../schemas";
+import type { AgentUsage, SpriteAgentConfig } from "../schemas";
 import type { AgentResult } from "./runner";
+import { addAgentUsage, normalizeUsage } from "./usage";
 import type { AxAIService, AxFunction } from "@ax-llm/ax";
 
 // Re-export core primitives
@@ -44,6 +47,10 @@ export interface SpriteRunAgentOptions {
   ephemeral?: boolean;
   /** Item ID for VM naming (used when ephemeral is true) */
   itemId?: string;
+  /** Refuses Write and Edit calls outside the phase's path policy */
+  pathGuard?: PathGuard;
+  /** Refuses Bash commands the bash_policy does not allow */
+  bashGuard?: BashGuard;
 }
 
 // ============================================================ 
@@ -160,6 +167,7 @@ export async function runSpriteAgent(
   const abortController = new AbortController();
   registerSdkController(abortController);
   let timeoutId: ReturnType<typeof setTimeout> | undefined;
+  let usage: AgentUsage | null = null;
 
   const vmName = config.vmName || (ephemeral && itemId ? `wreckit-sandbox-${itemId}-${Date.now()}` : `wreckit-sandbox-agent-${Date.now()}`);
 
@@ -180,7 +188,7 @@ export async function runSpriteAgent(
     // 3. Build Environment & Tools
     const env = await buildSdkEnv({ cwd, logger });
     const ai = createAxAI(env, logger);
-    const remoteTools = buildRemoteToolRegistry(vmName, config, logger, options.allowedTools);
+    const remoteTools = buildRemoteToolRegistry(vmName, config, logger, options.allowedTools, options.pathGuard, options.bashGuard);
     let mcpTools: AxFunction[] = [];
     if (options.mcpServers) {
       mcpTools = adaptMcpServersToAxTools(options.mcpServers, options.allowedTools);
@@ -226,6 +234,18 @@ When you are finished, summarize your work and stop.`,
         debug: false
       });
 
+      const modelUsage = (response as any).modelUsage;
+      usage = addAgentUsage(
+        usage,
+        normalizeUsage(
+          modelUsage?.tokens,
+          modelUsage?.model ?? config.model ?? null,
+        ),
+      );
+      if (usage) {
+        onAgentEvent?.({ type: "usage_progress", usage });
+      }
+
       const result = response.results[0];
       if (!result) break;
 
@@ -234,6 +254,7 @@ When you are finished, summarize your work and stop.`,
         process.stdout.write(result.content);
         fullOutput += result.content;
         if (onStdoutChunk) onStdoutChunk(result.content);
+        onAgentEvent?.({ type: "assistant_text", text: result.content });
         messages.push({ role: "assistant", content: result.content });
       }
 
@@ -344,16 +365,17 @@ When you are finished, summarize your work and stop.`,
           output: fullOutput, 
           timedOut: false, 
           exitCode: 1, 
-          completionDetected: true 
+          completionDetected: true,
+          ...(usage && { usage }),
         };
       }
     }
 
-    return { success: true, output: fullOutput, timedOut: false, exitCode: 0, completionDetected: true };
+    return { success: true, output: fullOutput, timedOut: false, exitCode: 0, completionDetected: true, ...(usage && { usage }) };
 
   } catch (err: any) {
     if (timeoutId) clearTimeout(timeoutId);
-    return { success: false, output: handleAxAIError(err, logger), timedOut: err.message === "Agent aborted", exitCode: 1, completionDetected: false };
+    return { success: false, output: handleAxAIError(err, logger), timedOut: err.message === "Agent aborted", exitCode: 1, completionDetected: false, ...(usage && { usage }) };
   } finally {
     unregisterSdkController(abortController);
     if (ephemeral && vmName && !dryRun) {
@@ -0,0 +1,224 @@
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import type { Logger } from "../logging";
+import type { StallDetectionConfig } from "../schemas";
+import type { AgentEvent } from "../tui/agentEvents";
+import { findRepoRoot } from "../fs/paths";
+import { getGitStatus } from "../git";
+
+/**
+ * How a stuck agent was spotted.
+ */
+export type StallReason =
+  | "repeated_tool_call"
+  | "no_file_changes"
+  | "error_loop";
+
+export interface StallInfo {
+  reason: StallReason;
+  message: string;
+}
+
+/**
+ * Limits a run is watched with. Without `max_idle_seconds` file changes are
+ * not tracked, for phases that are not meant to edit the repo.
+ */
+export type StallLimits = Omit<
+  StallDetectionConfig,
+  "enabled" | "max_idle_seconds"
+> & { max_idle_seconds?: number };
+
+export interface StallWatcherOptions {
+  limits: StallLimits;
+  /** Whether the backend takes a message while it runs */
+  canNudge: boolean;
+  /** State of the working tree; a change counts as progress */
+  fingerprint: () => Promise<string | null>;
+  onNudge: (stall: StallInfo, message: string) => void;
+  onStall: (stall: StallInfo) => void;
+}
+
+export interface StallWatcher {
+  observe(event: AgentEvent): void;
+  stop(): void;
+}
+
+// How often the working tree is checked at most
+const MAX_POLL_MS = 30_000;
+const MAX_ERROR_CHARS = 200;
+
+/**
+ * The error text of an event, if it reports one.
+ */
+function errorText(event: AgentEvent): string | null {
+  if (event.type === "tool_error") return event.error;
+  if (event.type === "error") return event.message;
+  if (event.type === "tool_result") {
+    const result =
+      typeof event.result === "string"
+        ? event.result
+        : JSON.stringify(event.result ?? "");
+    const firstLine = result.trimStart().split("\n")[0] ?? "";
+    return /\b(error|failed|exception)\b/i.test(firstLine) ? firstLine : null;
+  }
+  return null;
+}
+
+/**
+ * Errors that differ only in numbers (line numbers, ids, timings) are the
+ * same error.
+ */
+function normalizeError(text: string): string {
+  return text
+    .toLowerCase()
+    .replace(/\d+/g, "#")
+    .replace(/\s+/g, " ")
+    .trim()
+    .slice(0, MAX_ERROR_CHARS);
+}
+
+/**
+ * Message sent to an agent found stuck, where the backend supports it.
+ */
+export function formatNudge(stall: StallInfo): string {
+  return `You appear to be stuck: ${stall.message}. Stop repeating the same step. Re-read the last error, try a different approach, or finish with what you have.`;
+}
+
+/**
+ * Note added to the prompt when a stalled run is retried.
+ */
+export function formatStallNote(stall: StallInfo): string {
+  return `Note: a previous attempt at this task was stopped because ${stall.message}. Do not repeat that; take a different approach.`;
+}
+
+/**
+ * Watch an agent's events for signs it is stuck: the same tool call with
+ * the same input, or the same error, coming back again and again while no
+ * files change, or no file changes for `max_idle_seconds`.
+ *
+ * The first time, the agent is nudged if the backend can take a message;
+ * the next time (or straight away otherwise) `onStall` is called and the
+ * watcher stops.
+ */
+export function createStallWatcher(options: StallWatcherOptions): StallWatcher {
+  const { limits } = options;
+  const toolCalls = new Map<string, number>();
+  const errors = new Map<string, number>();
+  let nudged = false;
+  let stopped = false;
+
+  const trigger = (stall: StallInfo): void => {
+    if (stopped) return;
+    if (options.canNudge && !nudged) {
+      nudged = true;
+      options.onNudge(stall, formatNudge(stall));
+      return;
+    }
+    stop();
+    options.onStall(stall);
+  };
+
+  let interval: ReturnType<typeof setInterval> | undefined;
+  if (limits.max_idle_seconds !== undefined) {
+    const idleMs = limits.max_idle_seconds * 1000;
+    let last = null as string | null;
+    let lastChange = Date.now();
+    let polling = false;
+    const poll = async () => {
+      if (polling || stopped) return;
+      polling = true;
+      try {
+        const current = await options.fingerprint();
+        // Without the working tree, progress cannot be told
+        if (current === null) return;
+        if (current !== last) {
+          if (last !== null) {
+            toolCalls.clear();
+            errors.clear();
+          }
+          last = current;
+          lastChange = Date.now();
+        } else if (Date.now() - lastChange >= idleMs) {
+          lastChange = Date.now();
+          trigger({
+            reason: "no_file_changes",
+            message: `no files changed in ${limits.max_idle_seconds}s`,
+          });
+        }
+      } finally {
+        polling = false;
+      }
+    };
+    void poll();
+    interval = setInterval(poll, Math.min(MAX_POLL_MS, idleMs / 3));
+  }
+
+  function stop(): void {
+    stopped = true;
+    if (interval) clearInterval(interval);
+  }
+
+  return {
+    observe(event) {
+      if (stopped) return;
+      if (event.type === "tool_started") {
+        const key = `${event.toolName} ${JSON.stringify(event.input)}`;
+        const count = (toolCalls.get(key) ?? 0) + 1;
+        toolCalls.set(key, count);
+        if (count >= limits.max_repeated_tool_calls) {
+          toolCalls.delete(key);
+          trigger({
+            reason: "repeated_tool_call",
+            message: `${event.toolName} was called ${count} times with the same input and no file changes`,
+          });
+          return;
+        }
+      }
+
+      const error = errorText(event);
+      if (error) {
+        const key = normalizeError(error);
+        const count = (errors.get(key) ?? 0) + 1;
+        errors.set(key, count);
+        if (count >= limits.max_repeated_errors) {
+          errors.delete(key);
+          trigger({
+            reason: "error_loop",
+            message: `the same error came back ${count} times: ${error.slice(0, MAX_ERROR_CHARS)}`,
+          });
+        }
+      }
+    },
+    stop,
+  };
+}
+
+/**
+ * Fingerprint of the files git reports as changed: their paths, sizes and
+ * modification times. Null when the repo cannot be read.
+ */
+export async function workingTreeFingerprint(
+  cwd: string,
+  logger: Logger,
+): Promise<string | null> {
+  try {
+    const root = findRepoRoot(cwd);
+    const changes = await getGitStatus({
+      cwd: root,
+      logger,
+      untrackedFiles: "all",
+    });
+    const parts: string[] = [];
+    for (const change of changes) {
+      const stat = await fs
+        .stat(path.join(root, change.path))
+        .catch(() => null);
+      parts.push(
+        `${change.path}:${stat ? `${stat.size}:${stat.mtimeMs}` : "-"}`,
+      );
+    }
+    return parts.sort().join("\n");
+  } catch {
+    return null;
+  }
+}
@@ -33,6 +33,8 @@ export const AVAILABLE_TOOLS = {
   wreckit_save_parsed_ideas: "mcp__wreckit__save_parsed_ideas",
   wreckit_save_prd: "mcp__wreckit__save_prd",
   wreckit_update_story_status: "mcp__wreckit__update_story_status",
+  wreckit_save_reproduction: "mcp__wreckit__save_reproduction",
+  wreckit_save_story_tests: "mcp__wreckit__save_story_tests",
   wreckit_complete: "mcp__wreckit__complete",
   wreckit_save_dream_ideas: "mcp__wreckit-dream__save_dream_ideas",
 } as const;
@@ -45,8 +47,11 @@ export type ToolName = (typeof AVAILABLE_TOOLS)[keyof typeof AVAILABLE_TOOLS];
  * Philosophy:
  * - idea: MCP tools only (structured data capture, no file system access)
  * - research: Read-only tools (Read, Glob, Grep for exploration)
+ * - reproduce: Full file access + Bash (write and run a failing test)
  * - plan: Read + Write tools (Read, Write, Edit for creating plan/PRD)
+ * - tests: Full file access + Bash (write and run failing story tests)
  * - implement: Full tool access (Read, Write, Edit, Glob, Grep, Bash)
+ * - document: Read + Write tools (update docs and the changelog, no Bash)
  * - pr: Read + Bash tools (Read for verification, Bash for git operations)
  * - complete: Read + MCP tools (Read for verification, wreckit_complete)
  * - strategy: Read + Write tools (Read, Glob, Grep for analysis, Write for ROADMAP.md)
@@ -70,6 +75,17 @@ export const PHASE_TOOL_ALLOWLISTS: Record<string, ToolName[] | undefined> = {
     AVAILABLE_TOOLS.Grep,
   ],
 
+  // Reproduce phase: write a failing test for a bug and run it via Bash
+  reproduce: [
+    AVAILABLE_TOOLS.Read,
+    AVAILABLE_TOOLS.Write,
+    AVAILABLE_TOOLS.Edit,
+    AVAILABLE_TOOLS.Glob,
+    AVAILABLE_TOOLS.Grep,
+    AVAILABLE_TOOLS.Bash,
+    AVAILABLE_TOOLS.wreckit_save_reproduction,
+  ],
+
   // Plan phase: Read + Write for creating plan.md and prd.json
   plan: [
     AVAILABLE_TOOLS.Read,
@@ -80,6 +96,17 @@ export const PHASE_TOOL_ALLOWLISTS: Record<string, ToolName[] | undefined> = {
     AVAILABLE_TOOLS.wreckit_save_prd,
   ],
 
+  // Tests phase: write failing tests for each story and run them via Bash
+  tests: [
+    AVAILABLE_TOOLS.Read,
+    AVAILABLE_TOOLS.Write,
+    AVAILABLE_TOOLS.Edit,
+    AVAILABLE_TOOLS.Glob,
+    AVAILABLE_TOOLS.Grep,
+    AVAILABLE_TOOLS.Bash,
+    AVAILABLE_TOOLS.wreckit_save_story_tests,
+  ],
+
   // Implement phase: Full tool access for implementation
   implement: [
     AVAILABLE_TOOLS.Read,
@@ -91,6 +118,15 @@ export const PHASE_TOOL_ALLOWLISTS: Record<string, ToolName[] | undefined> = {
     AVAILABLE_TOOLS.wreckit_update_story_status,
   ],
 
+  // Document phase: edit documentation only; the branch diff is in the prompt
+  document: [
+    AVAILABLE_TOOLS.Read,
+    AVAILABLE_TOOLS.Write,
+    AVAILABLE_TOOLS.Edit,
+    AVAILABLE_TOOLS.Glob,
+    AVAILABLE_TOOLS.Grep,
+  ],
+
   // PR phase: Read + Bash for PR management (git operations via Bash)
   pr: [
     AVAILABLE_TOOLS.Read,
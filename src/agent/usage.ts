@@ -0,0 +1,136 @@
+import type { AgentUsage } from "../schemas";
+
+type UsageRecord = Record<string, unknown>;
+
+function isRecord(value: unknown): value is UsageRecord {
+  return typeof value === "object" && value !== null && !Array.isArray(value);
+}
+
+function firstNumber(record: UsageRecord, keys: string[]): number | undefined {
+  for (const key of keys) {
+    const value = record[key];
+    if (typeof value === "number" && Number.isFinite(value)) {
+      return value;
+    }
+  }
+  return undefined;
+}
+
+/**
+ * Normalize the usage object reported by an SDK or API into token counts.
+ *
+ * Understands the Anthropic (`input_tokens`, `cache_read_input_tokens`),
+ * OpenAI (`prompt_tokens`, `prompt_tokens_details.cached_tokens`), Codex
+ * (`cached_input_tokens`), OpenCode (`input`, `cache.read`) and Ax
+ * (`promptTokens`) shapes. OpenAI and Codex count cached tokens as part of
+ * the input; they are moved to `cache_read_tokens` here.
+ *
+ * @returns null when the object carries no token counts
+ */
+export function normalizeUsage(
+  raw: unknown,
+  model: string | null,
+): AgentUsage | null {
+  if (!isRecord(raw)) {
+    return null;
+  }
+
+  const input = firstNumber(raw, [
+    "input_tokens",
+    "prompt_tokens",
+    "promptTokens",
+    "input",
+  ]);
+  const output = firstNumber(raw, [
+    "output_tokens",
+    "completion_tokens",
+    "completionTokens",
+    "output",
+  ]);
+  if (input === undefined && output === undefined) {
+    return null;
+  }
+
+  const cache = isRecord(raw.cache) ? raw.cache : {};
+  const details = isRecord(raw.prompt_tokens_details)
+    ? raw.prompt_tokens_details
+    : {};
+  // Cached tokens that the provider also counts as input
+  const cachedInput =
+    firstNumber(raw, ["cached_input_tokens"]) ??
+    firstNumber(details, ["cached_tokens"]) ??
+    0;
+  const cacheRead =
+    firstNumber(raw, ["cache_read_input_tokens"]) ??
+    firstNumber(cache, ["read"]) ??
+    0;
+  const cacheWrite =
+    firstNumber(raw, ["cache_creation_input_tokens"]) ??
+    firstNumber(cache, ["write"]) ??
+    0;
+
+  return {
+    input_tokens: Math.max(0, (input ?? 0) - cachedInput),
+    output_tokens: output ?? 0,
+    cache_read_tokens: cacheRead + cachedInput,
+    cache_write_tokens: cacheWrite,
+    model,
+  };
+}
+
+/**
+ * Sum the usage of several requests made during one run.
+ */
+export function addAgentUsage(
+  total: AgentUsage | null,
+  usage: AgentUsage | null,
+): AgentUsage | null {
+  if (!total || !usage) {
+    return total ?? usage;
+  }
+  return {
+    input_tokens: total.input_tokens + usage.input_tokens,
+    output_tokens: total.output_tokens + usage.output_tokens,
+    cache_read_tokens: total.cache_read_tokens + usage.cache_read_tokens,
+    cache_write_tokens: total.cache_write_tokens + usage.cache_write_tokens,
+    model: total.model ?? usage.model,
+  };
+}
+
+/**
+ * Read usage from the JSON lines a process agent printed, e.g.
+ * `claude -p --output-format stream-json` (a final `result` message) or
+ * `codex exec --json` (one `turn.completed` event per turn).
+ *
+ * @returns null when the output has no usage in a known format
+ */
+export function parseUsageFromOutput(output: string): AgentUsage | null {
+  let model: string | null = null;
+  let result: unknown = null;
+  let turns: AgentUsage | null = null;
+
+  for (const line of output.split("\n")) {
+    const trimmed = line.trim();
+    if (!trimmed.startsWith("{")) continue;
+
+    let message: unknown;
+    try {
+      message = JSON.parse(trimmed);
+    } catch {
+      continue;
+    }
+    if (!isRecord(message)) continue;
+
+    if (typeof message.model === "string") {
+      model = message.model;
+    }
+    if (message.type === "result" && isRecord(message.usage)) {
+      result = message.usage;
+    } else if (message.type === "turn.completed") {
+      turns = addAgentUsage(turns, normalizeUsage(message.usage, null));
+    }
+  }
+
+  const usage = result ? normalizeUsage(result, model) : turns;
+  return usage ? { ...usage, model } : null;
+}
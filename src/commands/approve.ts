@@ -0,0 +1,155 @@
+import * as os from "node:os";
+import type { Logger } from "../logging";
+import type { Item } from "../schemas";
+import { findRootFromOptions, getItemDir } from "../fs/paths";
+import { readItem, writeItem } from "../fs/json";
+import { appendHistory } from "../fs/history";
+import { loadConfig } from "../config";
+import { applyApprovalDecision } from "../domain/gates";
+import { getItemPipeline, getPhaseEntryState } from "../domain/pipeline";
+import { regressItem } from "../workflow/regress";
+import { ErrorCodes, FileNotFoundError, WreckitError } from "../errors";
+
+export interface ApproveOptions {
+  comment?: string;
+  /** Reviewer name (defaults to the OS user) */
+  by?: string;
+  dryRun?: boolean;
+  cwd?: string;
+}
+
+export interface RejectOptions extends ApproveOptions {
+  comment: string;
+}
+
+function defaultReviewer(): string {
+  try {
+    return os.userInfo().username;
+  } catch {
+    return "human";
+  }
+}
+
+async function loadItemOrThrow(root: string, itemId: string): Promise<Item> {
+  try {
+    return await readItem(getItemDir(root, itemId));
+  } catch (err) {
+    if (err instanceof FileNotFoundError) {
+      throw new WreckitError(
+        `Item not found: ${itemId}`,
+        ErrorCodes.ITEM_NOT_FOUND,
+      );
+    }
+    throw err;
+  }
+}
+
+/**
+ * Approve the output of an item's gated phase so it can continue.
+ */
+export async function approveCommand(
+  itemId: string,
+  options: ApproveOptions,
+  logger: Logger,
+): Promise<Item> {
+  const { dryRun = false } = options;
+  const by = options.by?.trim() || defaultReviewer();
+  const root = findRootFromOptions(options);
+  const item = await loadItemOrThrow(root, itemId);
+
+  const result = applyApprovalDecision(item, "approved", {
+    by,
+    comment: options.comment,
+  });
+  if (!result.nextItem) {
+    throw new WreckitError(
+      `Cannot approve ${itemId}: ${result.error}`,
+      ErrorCodes.INVALID_STATE,
+    );
+  }
+
+  if (dryRun) {
+    logger.info(`[dry-run] Would approve ${result.phase} for ${itemId}`);
+    return item;
+  }
+
+  await writeItem(getItemDir(root, itemId), result.nextItem);
+  await appendHistory(root, itemId, {
+    event: "approved",
+    actor: by,
+    phase: result.phase,
+    ...(options.comment?.trim() ? { reason: options.comment.trim() } : {}),
+  });
+
+  logger.info(`Approved ${result.phase} for ${itemId} (${by})`);
+  return result.nextItem;
+}
+
+/**
+ * Reject the output of an item's gated phase. The item returns to the
+ * phase's entry state (superseded artifacts are archived) and the comment
+ * is passed to the agent when the phase runs again.
+ */
+export async function rejectCommand(
+  itemId: string,
+  options: RejectOptions,
+  logger: Logger,
+): Promise<Item> {
+  const { dryRun = false } = options;
+  const by = options.by?.trim() || defaultReviewer();
+  const root = findRootFromOptions(options);
+  const config = await loadConfig(root);
+  const item = await loadItemOrThrow(root, itemId);
+
+  const result = applyApprovalDecision(item, "rejected", {
+    by,
+    comment: options.comment,
+  });
+  if (!result.nextItem) {
+    throw new WreckitError(
+      `Cannot reject ${itemId}: ${result.error}`,
+      result.error.includes("comment")
+        ? ErrorCodes.PHASE_VALIDATION
+        : ErrorCodes.INVALID_STATE,
+    );
+  }
+
+  const comment = options.comment.trim();
+  const entryState = getPhaseEntryState(
+    getItemPipeline(result.nextItem, config),
+    result.phase,
+  );
+
+  if (dryRun) {
+    logger.info(
+      `[dry-run] Would reject ${result.phase} for ${itemId}` +
+        (entryState ? ` and return it to ${entryState}` : ""),
+    );
+    return item;
+  }
+
+  await writeItem(getItemDir(root, itemId), result.nextItem);
+  await appendHistory(root, itemId, {
+    event: "rejected",
+    actor: by,
+    phase: result.phase,
+    reason: comment,
+  });
+
+  let nextItem = result.nextItem;
+  if (entryState && entryState !== nextItem.state) {
+    ({ item: nextItem } = await regressItem(itemId, {
+      root,
+      config,
+      logger,
+      to: entryState,
+      reason: `${result.phase} rejected: ${comment}`,
+      actor: "human",
+    }));
+  }
+
+  logger.info(
+    `Rejected ${result.phase} for ${itemId} (${by}); item is now ${nextItem.state}`,
+  );
+  return nextItem;
+}
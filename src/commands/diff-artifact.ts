@@ -0,0 +1,128 @@
+import type { Logger } from "../logging";
+import { PrdSchema } from "../schemas";
+import { findRootFromOptions, getItemDir } from "../fs/paths";
+import { readItem } from "../fs/json";
+import {
+  ARTIFACT_NAMES,
+  listRevisions,
+  readRevision,
+  type ArtifactName,
+} from "../fs/revisions";
+import {
+  diffLines,
+  diffPrd,
+  formatPrdDiff,
+  formatUnifiedDiff,
+} from "../domain/artifactDiff";
+import { ErrorCodes, FileNotFoundError, WreckitError } from "../errors";
+
+export interface DiffArtifactOptions {
+  /** Older revision (defaults to the one before `to`) */
+  from?: number;
+  /** Newer revision (defaults to the latest) */
+  to?: number;
+  json?: boolean;
+  cwd?: string;
+}
+
+function parseArtifact(name: string): ArtifactName {
+  const artifact = ARTIFACT_NAMES.find((a) => a === name);
+  if (!artifact) {
+    throw new WreckitError(
+      `Unknown artifact '${name}'. Valid artifacts: ${ARTIFACT_NAMES.join(", ")}`,
+      ErrorCodes.PHASE_VALIDATION,
+    );
+  }
+  return artifact;
+}
+
+/**
+ * Show what changed between two recorded revisions of an item's research,
+ * plan or PRD. PRDs are compared story by story.
+ */
+export async function diffArtifactCommand(
+  itemId: string,
+  artifactName: string,
+  options: DiffArtifactOptions,
+  logger: Logger,
+): Promise<void> {
+  const artifact = parseArtifact(artifactName);
+  const root = findRootFromOptions(options);
+
+  try {
+    await readItem(getItemDir(root, itemId));
+  } catch (err) {
+    if (err instanceof FileNotFoundError) {
+      throw new WreckitError(
+        `Item not found: ${itemId}`,
+        ErrorCodes.ITEM_NOT_FOUND,
+      );
+    }
+    throw err;
+  }
+
+  const revisions = await listRevisions(root, itemId, artifact);
+  const to = options.to ?? revisions.at(-1);
+  const from =
+    options.from ?? revisions.filter((r) => to !== undefined && r < to).at(-1);
+  if (from === undefined || to === undefined) {
+    throw new WreckitError(
+      `${itemId} has ${revisions.length} revision(s) of ${artifact}; at least two are needed to diff`,
+      ErrorCodes.FILE_NOT_FOUND,
+    );
+  }
+
+  const before = await readRevision(root, itemId, artifact, from);
+  const after = await readRevision(root, itemId, artifact, to);
+
+  let lines: string[];
+  let prdDiff: ReturnType<typeof diffPrd> | null = null;
+  if (artifact === "prd") {
+    const prev = parseJsonPrd(before);
+    const next = parseJsonPrd(after);
+    prdDiff = prev && next ? diffPrd(prev, next) : null;
+  }
+
+  if (prdDiff) {
+    lines = formatPrdDiff(prdDiff);
+  } else {
+    lines = formatUnifiedDiff(
+      diffLines(before, after),
+      `${artifact} r${from}`,
+      `${artifact} r${to}`,
+    );
+  }
+
+  if (options.json) {
+    logger.json({
+      id: itemId,
+      artifact,
+      from,
+      to,
+      ...(prdDiff ? { stories: prdDiff } : { diff: lines }),
+    });
+    return;
+  }
+
+  if (lines.length === 0) {
+    logger.info(`No changes in ${artifact} between r${from} and r${to}`);
+    return;
+  }
+
+  if (prdDiff) {
+    logger.info(`${artifact} r${from} → r${to}:`);
+    logger.info("");
+  }
+  for (const line of lines) {
+    logger.info(line);
+  }
+}
+
+function parseJsonPrd(content: string) {
+  try {
+    const result = PrdSchema.safeParse(JSON.parse(content));
+    return result.success ? result.data : null;
+  } catch {
+    return null;
+  }
+}
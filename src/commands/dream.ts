@@ -322,7 +322,9 @@ export async function dreamCommand(
   }
 
   // Persist items using existing pipeline (includes deduplication)
-  const { created, skipped } = await persistItems(root, capturedIdeas);
+  const { created, skipped } = await persistItems(root, capturedIdeas, {
+    config,
+  });
 
   logger.info("Autonomous ideation complete.");
   logger.info(`  Generated: ${capturedIdeas.length} ideas`);
@@ -1,7 +1,8 @@
 import type { Logger } from "../logging";
-import type { Item, Prd } from "../schemas";
-import type { ConfigResolved } from "../config";
+import type { Item, PhaseName, Prd } from "../schemas";
+import { getPhaseSettings, type ConfigResolved } from "../config";
 import { getNextPhase } from "../workflow";
+import { getItemPipeline, type Pipeline } from "../domain/pipeline";
 
 export interface DryRunItemInfo {
   item: Item;
@@ -15,22 +16,46 @@ const PHASE_DESCRIPTIONS: Record<string, string> = {
   research: "Gather context and requirements from codebase",
   plan: "Create implementation plan and user stories (prd.json)",
   implement: "Execute user stories with AI agent",
+  critique: "Adversarial review of the implementation",
   pr: "Create/update pull request with changes",
   complete: "Mark item as done after PR merge",
 };
 
-function getPhaseSequence(currentState: string): string[] {
-  const allPhases = ["research", "plan", "implement", "pr", "complete"];
-  const stateToPhaseIndex: Record<string, number> = {
-    idea: 0,
-    researched: 1,
-    planned: 2,
-    implementing: 3,
-    in_pr: 4,
-    done: 5,
-  };
-  const startIndex = stateToPhaseIndex[currentState] ?? 0;
-  return allPhases.slice(startIndex);
+function getPhaseSequence(
+  currentState: string,
+  pipeline: Pipeline,
+): PhaseName[] {
+  const index = pipeline.stages.findIndex(
+    (stage) => stage.state === currentState,
+  );
+  return pipeline.stages
+    .slice(Math.max(index, 0) + 1)
+    .flatMap((stage) => (stage.phase ? [stage.phase] : []));
+}
+
+const ITERATING_PHASES: PhaseName[] = [
+  "research",
+  "reproduce",
+  "plan",
+  "tests",
+  "implement",
+  "document",
+];
+
+/**
+ * Agent and limits a phase would run with,
+ * e.g. "claude_sdk, 600s timeout, max 3 iterations".
+ */
+function formatPhaseLimits(config: ConfigResolved, phase: PhaseName): string {
+  const settings = getPhaseSettings(config, phase);
+  const agents = [settings.agent, ...(settings.agent_fallbacks ?? [])]
+    .map((agent) => agent.kind)
+    .join(" → ");
+  const parts = [agents, `${settings.timeout_seconds}s timeout`];
+  if (ITERATING_PHASES.includes(phase)) {
+    parts.push(`max ${settings.max_iterations} iterations`);
+  }
+  return parts.join(", ");
 }
 
 function formatBranchName(config: ConfigResolved, itemId: string): string {
@@ -39,7 +64,8 @@ function formatBranchName(config: ConfigResolved, itemId: string): string {
 
 export function formatDryRunItem(info: DryRunItemInfo, logger: Logger): void {
   const { item, prd, hasResearch, hasPlan, config } = info;
-  const nextPhase = getNextPhase(item);
+  const pipeline = getItemPipeline(item, config);
+  const nextPhase = getNextPhase(item, pipeline);
   const branchName = formatBranchName(config, item.id);
 
   logger.info("");
@@ -47,6 +73,7 @@ export function formatDryRunItem(info: DryRunItemInfo, logger: Logger): void {
   logger.info("");
 
   logger.info(`  Current State: ${item.state}`);
+  logger.info(`  Pipeline:      ${pipeline.name}`);
   if (item.last_error) {
     logger.info(`  Last Error:    ${item.last_error}`);
   }
@@ -68,11 +95,14 @@ export function formatDryRunItem(info: DryRunItemInfo, logger: Logger): void {
 
   logger.info("");
   logger.info("  Would Execute:");
-  const phases = getPhaseSequence(item.state);
+  const phases = getPhaseSequence(item.state, pipeline);
   for (const phase of phases) {
     const desc = PHASE_DESCRIPTIONS[phase] || phase;
     const marker = phase === nextPhase ? "→" : " ";
     logger.info(`    ${marker} ${phase}: ${desc}`);
+    if (phase !== "complete") {
+      logger.info(`        (${formatPhaseLimits(config, phase)})`);
+    }
   }
 
   logger.info("");
@@ -119,7 +149,7 @@ export function formatDryRunSummary(
 }
 
 export function formatDryRunPhase(
-  phase: string,
+  phase: PhaseName,
   item: Item,
   targetState: string,
   config: ConfigResolved,
@@ -135,6 +165,9 @@ export function formatDryRunPhase(
   logger.info(`  Current:     ${item.state}`);
   logger.info(`  Target:      ${targetState}`);
   logger.info(`  Action:      ${desc}`);
+  if (phase !== "complete") {
+    logger.info(`  Limits:      ${formatPhaseLimits(config, phase)}`);
+  }
   logger.info("");
   logger.info("  Git Operations:");
   logger.info(`    Branch:    ${item.branch || branchName}`);
@@ -157,7 +190,7 @@ export function formatDryRunRun(
   logger: Logger,
 ): void {
   const branchName = formatBranchName(config, item.id);
-  const phases = getPhaseSequence(item.state);
+  const phases = getPhaseSequence(item.state, getItemPipeline(item, config));
 
   logger.info("");
   logger.info(`━━━ DRY RUN: run ${item.id} ━━━`);
@@ -113,7 +113,9 @@ export async function executeRoadmapCommand(
   }
 
   // Persist items (handles deduplication via slug matching)
-  const result = await persistItems(root, ideas);
+  const result = await persistItems(root, ideas, {
+    config: await loadConfig(root),
+  });
 
   if (result.created.length === 0 && result.skipped.length === 0) {
     logger.info("No items created");
@@ -2,13 +2,15 @@ import * as fs from "node:fs/promises";
 import * as readline from "node:readline";
 import type { Logger } from "../logging";
 import { findRootFromOptions } from "../fs/paths";
+import { loadConfig } from "../config";
 import { persistItems, generateSlug } from "../domain/ideas";
 import { parseIdeasWithAgent } from "../domain/ideas-agent";
 import {
   runIdeaInterview,
   runSimpleInterview,
 } from "../domain/ideas-interview";
-import { FileNotFoundError } from "../errors";
+import { ItemKindSchema, type ItemKind } from "../schemas";
+import { ErrorCodes, FileNotFoundError, WreckitError } from "../errors";
 import { hasUncommittedChanges, isGitRepo } from "../git";
 
 export interface IdeasOptions {
@@ -16,6 +18,22 @@ export interface IdeasOptions {
   dryRun?: boolean;
   cwd?: string;
   verbose?: boolean;
+  /** Pipeline to create items under (defaults to config.default_pipeline) */
+  pipeline?: string;
+  /** Kind of work for the new items (feature, bug, refactor, spike) */
+  kind?: string;
+}
+
+function parseKind(kind: string | undefined): ItemKind | undefined {
+  if (kind === undefined) return undefined;
+  const parsed = ItemKindSchema.safeParse(kind);
+  if (!parsed.success) {
+    throw new WreckitError(
+      `Unknown item kind '${kind}'. Valid kinds: ${ItemKindSchema.options.join(", ")}`,
+      ErrorCodes.SCHEMA_VALIDATION,
+    );
+  }
+  return parsed.data;
 }
 
 export async function readStdin(): Promise<string> {
@@ -94,6 +112,7 @@ export async function ideasCommand(
   inputOverride?: string,
 ): Promise<void> {
   const root = findRootFromOptions(options);
+  const kind = parseKind(options.kind);
 
   // Warn if user has uncommitted changes before ideation
   await warnIfUncommittedChanges(root, logger, options.dryRun);
@@ -162,7 +181,11 @@ export async function ideasCommand(
     return;
   }
 
-  const result = await persistItems(root, ideas);
+  const result = await persistItems(root, ideas, {
+    pipeline: options.pipeline,
+    kind,
+    config: await loadConfig(root),
+  });
 
   if (result.created.length === 0 && result.skipped.length === 0) {
     console.log("No items created");
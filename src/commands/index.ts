@@ -27,8 +27,44 @@ export {
   type RollbackOptions,
   type RollbackResult,
 } from "./rollback";
+export { reopenCommand, type ReopenOptions } from "./reopen";
+export { logCommand, formatHistoryEntry, type LogOptions } from "./log";
+export {
+  routingCommand,
+  getPhaseRouting,
+  formatPhaseRoute,
+  type PhaseRoute,
+  type RoutingOptions,
+} from "./routing";
+export {
+  transcriptCommand,
+  formatTranscript,
+  transcriptToMarkdown,
+  type TranscriptOptions,
+} from "./transcript";
+export {
+  diffArtifactCommand,
+  type DiffArtifactOptions,
+} from "./diff-artifact";
+export {
+  parkCommand,
+  resumeCommand,
+  type ParkState,
+  type ParkCommandOptions,
+  type ResumeOptions,
+} from "./park";
+export {
+  approveCommand,
+  rejectCommand,
+  type ApproveOptions,
+  type RejectOptions,
+} from "./approve";
 export { strategyCommand, type StrategyOptions } from "./strategy";
 export {
   executeRoadmapCommand,
   type ExecuteRoadmapOptions,
 } from "./execute-roadmap";
+export {
+  spikeFollowupsCommand,
+  type SpikeFollowupsOptions,
+} from "./spike-followups";
@@ -1,6 +1,8 @@
 import type { Logger } from "../logging";
 import { findRootFromOptions } from "../fs/paths";
+import type { WorkflowState } from "../schemas";
 import { buildIdMap } from "../domain/resolveId";
+import { isParkedState } from "../domain/states";
 
 export interface ListOptions {
   json?: boolean;
@@ -40,6 +42,8 @@ export async function listCommand(
       fullId: i.fullId,
       state: i.state,
       title: i.title,
+      ...(i.parkedReason ? { reason: i.parkedReason } : {}),
+      ...(i.pendingApproval ? { pending_approval: i.pendingApproval } : {}),
     }));
     console.log(JSON.stringify(jsonItems, null, 2));
     return;
@@ -65,10 +69,22 @@ export async function listCommand(
       item.cleanTitle.length > 60
         ? item.cleanTitle.substring(0, 57) + "..."
         : item.cleanTitle;
-    const line = `${String(item.shortId).padStart(3)}  ${item.state.padEnd(stateWidth)}  ${displayTitle}`;
+    const reason = item.parkedReason
+      ? ` (${item.parkedReason})`
+      : item.pendingApproval
+        ? ` (awaiting ${item.pendingApproval} approval)`
+        : "";
+    const line = `${String(item.shortId).padStart(3)}  ${item.state.padEnd(stateWidth)}  ${displayTitle}${reason}`;
     console.log(line);
   }
 
   console.log("");
-  console.log(`Total: ${items.length} item(s)`);
+  const parkedCount = items.filter((i) =>
+    isParkedState(i.state as WorkflowState),
+  ).length;
+  console.log(
+    parkedCount > 0
+      ? `Total: ${items.length} item(s), ${parkedCount} blocked/paused/wontfix`
+      : `Total: ${items.length} item(s)`,
+  );
 }
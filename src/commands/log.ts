@@ -0,0 +1,95 @@
+import type { Logger } from "../logging";
+import type { HistoryEntry } from "../schemas";
+import { findRootFromOptions, getItemDir } from "../fs/paths";
+import { readItem } from "../fs/json";
+import { readHistory } from "../fs/history";
+import { FileNotFoundError } from "../errors";
+
+export interface LogOptions {
+  json?: boolean;
+  cwd?: string;
+}
+
+export function formatDurationMs(ms: number): string {
+  const totalSeconds = Math.round(ms / 1000);
+  if (totalSeconds < 60) {
+    return `${totalSeconds}s`;
+  }
+  const hours = Math.floor(totalSeconds / 3600);
+  const minutes = Math.floor((totalSeconds % 3600) / 60);
+  const seconds = totalSeconds % 60;
+  return hours > 0 ? `${hours}h ${minutes}m` : `${minutes}m ${seconds}s`;
+}
+
+/**
+ * Render one history entry as a single timeline line.
+ */
+export function formatHistoryEntry(entry: HistoryEntry): string {
+  const ts = entry.ts.replace("T", " ").replace(/\.\d+Z$/, "Z");
+  const duration =
+    entry.duration_ms !== undefined
+      ? ` in ${formatDurationMs(entry.duration_ms)}`
+      : "";
+  const via = entry.agent ? ` via ${entry.agent}` : "";
+
+  switch (entry.event) {
+    case "phase_started":
+      return `${ts}  ▶ ${entry.phase} started (${entry.actor})`;
+    case "phase_succeeded":
+      return `${ts}  ✓ ${entry.phase} succeeded${duration}${via}`;
+    case "phase_failed": {
+      const code = entry.error_code ? ` [${entry.error_code}]` : "";
+      const error = entry.error ? `: ${entry.error}` : "";
+      return `${ts}  ✗ ${entry.phase} failed${duration}${via}${code}${error}`;
+    }
+    case "agent_fallback":
+      return `${ts}  ↪ ${entry.phase} fell back to ${entry.agent}: ${entry.reason}`;
+    case "approval_requested":
+      return `${ts}  ⏸ ${entry.phase} awaiting approval`;
+    case "approved":
+    case "rejected": {
+      const mark = entry.event === "approved" ? "✓" : "✗";
+      const comment = entry.reason ? `: ${entry.reason}` : "";
+      return `${ts}  ${mark} ${entry.phase} ${entry.event} by ${entry.actor}${comment}`;
+    }
+    case "transition": {
+      const reason = entry.reason ? `: ${entry.reason}` : "";
+      return `${ts}  → ${entry.from} → ${entry.to} (${entry.actor})${reason}`;
+    }
+  }
+}
+
+export async function logCommand(
+  id: string,
+  options: LogOptions,
+  logger: Logger,
+): Promise<void> {
+  const root = findRootFromOptions(options);
+
+  try {
+    await readItem(getItemDir(root, id));
+  } catch (err) {
+    if (err instanceof FileNotFoundError) {
+      throw new FileNotFoundError(`Item not found: ${id}`);
+    }
+    throw err;
+  }
+
+  const history = await readHistory(root, id);
+
+  if (options.json) {
+    logger.json(history);
+    return;
+  }
+
+  if (history.length === 0) {
+    logger.info(`No history recorded for ${id}`);
+    return;
+  }
+
+  logger.info(`History for ${id}:`);
+  logger.info("");
+  for (const entry of history) {
+    logger.info(formatHistoryEntry(entry));
+  }
+}
@@ -21,6 +21,9 @@ import {
   clearBatchProgress,
 } from "../fs/json";
 import { scanItems } from "./status";
+import { isActionableState, isParkedState } from "../domain/states";
+import { addRunUsage, formatUsage } from "../domain/usage";
+import { isSessionBudgetReached } from "../domain/budget";
 import { runCommand } from "./run";
 import { writeHealingLog, type HealingLogEntry } from "../agent/healingRunner";
 import type { DoctorConfig } from "../schemas";
@@ -33,6 +36,7 @@ import {
   type DryRunItemInfo,
 } from "./dryRunFormatter";
 import { terminateAllAgents } from "../agent/runner";
+import { runWorkerPool } from "../workflow/workerPool";
 
 const STALE_THRESHOLD_MS = 24 * 60 * 60 * 1000; // 24 hours
 
@@ -91,6 +95,21 @@ function areDependenciesSatisfied(
   return item.depends_on.every((depId) => doneItemIds.has(depId));
 }
 
+/**
+ * Phase whose output the item is waiting on a human to approve, if any.
+ */
+async function getPendingApproval(
+  root: string,
+  itemId: string,
+): Promise<string | null> {
+  try {
+    const item = await readItem(getItemDir(root, itemId));
+    return item.pending_approval ?? null;
+  } catch {
+    return null;
+  }
+}
+
 export interface OrchestratorOptions {
   force?: boolean;
   dryRun?: boolean;
@@ -117,6 +136,8 @@ export interface OrchestratorResult {
   failed: string[];
   skipped: string[];
   remaining: string[];
+  /** Items stopped at a human approval gate */
+  awaitingApproval: string[];
 }
 
 function shouldUseTui(noTui?: boolean): boolean {
@@ -160,13 +181,30 @@ export async function orchestrateAll(
     failed: [],
     skipped: [],
     remaining: [],
+    awaitingApproval: [],
   };
 
-  const nonDoneItems = items.filter((item) => item.state !== "done");
+  // Parked items (blocked/paused/wontfix) are skipped like done items,
+  // but do not satisfy dependencies. So are items awaiting approval.
+  const nonDoneItems = items.filter(
+    (item) => isActionableState(item.state) && !item.pending_approval,
+  );
   const doneItems = items.filter((item) => item.state === "done");
+  const parkedItems = items.filter((item) => isParkedState(item.state));
   const allDoneIds = new Set(doneItems.map((item) => item.id));
 
-  result.skipped = doneItems.map((item) => item.id);
+  result.skipped = [...doneItems, ...parkedItems].map((item) => item.id);
+  for (const item of parkedItems) {
+    logger.info(`Skipping ${item.id} (${item.state})`);
+  }
+  for (const item of items) {
+    if (isActionableState(item.state) && item.pending_approval) {
+      result.awaitingApproval.push(item.id);
+      logger.info(
+        `Skipping ${item.id} (awaiting ${item.pending_approval} approval)`,
+      );
+    }
+  }
 
   // Check for existing batch progress (resume support)
   const { noResume = false, retryFailed = false } = options;
@@ -211,7 +249,12 @@ export async function orchestrateAll(
               `  Re-queuing ${batchProgress.failed.length} failed item(s)`,
             );
             const failedSet = new Set(batchProgress.failed);
-            const failedItems = items.filter((item) => failedSet.has(item.id));
+            const failedItems = items.filter(
+              (item) =>
+                failedSet.has(item.id) &&
+                isActionableState(item.state) &&
+                !item.pending_approval,
+            );
             workingNonDoneItems.push(...failedItems);
             result.failed = [];
             batchProgress.failed = [];
@@ -311,12 +354,45 @@ export async function orchestrateAll(
     });
   }
 
+  // Session token usage, saved with each batch progress checkpoint
+  let sessionUsage = batchProgress?.usage;
+  const trackUsage = (event: AgentEvent): void => {
+    if (event.type !== "usage") return;
+    sessionUsage = addRunUsage(sessionUsage, event.usage, config.prices);
+    if (batchProgress) {
+      batchProgress.usage = sessionUsage;
+    }
+    view?.onUsageChanged(sessionUsage);
+  };
+  if (sessionUsage) {
+    view?.onUsageChanged(sessionUsage);
+  }
+  const getSessionCostUsd = (): number => sessionUsage?.cost_usd ?? 0;
+  let budgetWarned = false;
+  const sessionBudgetReached = (): boolean => {
+    if (!isSessionBudgetReached(config.budget, getSessionCostUsd())) {
+      return false;
+    }
+    if (!budgetWarned) {
+      budgetWarned = true;
+      logger.warn(
+        `Session budget reached ($${getSessionCostUsd().toFixed(2)} of $${config.budget?.per_session_usd?.toFixed(2)}), not starting more items`,
+      );
+    }
+    return true;
+  };
+
   // Process items either sequentially or in parallel
   if (parallel <= 1) {
     // Sequential processing with dependency checking
     let remainingItems = [...workingNonDoneItems];
 
     while (remainingItems.length > 0) {
+      if (sessionBudgetReached()) {
+        result.remaining = remainingItems.map((item) => item.id);
+        break;
+      }
+
       // Find next runnable item (dependencies satisfied)
       const runnableItems = remainingItems.filter((item) =>
         areDependenciesSatisfied(item, allDoneIds),
@@ -362,6 +438,7 @@ export async function orchestrateAll(
             dryRun: false,
             mockAgent,
             noHealing, // Pass through healing flag (Item 038)
+            getSessionCostUsd,
             onAgentOutput: view
               ? (chunk) =>
                   view.onAgentEvent(item.id, {
@@ -369,9 +446,10 @@ export async function orchestrateAll(
                     text: chunk,
                   })
               : undefined,
-            onAgentEvent: view
-              ? (event: AgentEvent) => view.onAgentEvent(item.id, event)
-              : undefined,
+            onAgentEvent: (event: AgentEvent) => {
+              trackUsage(event);
+              view?.onAgentEvent(item.id, event);
+            },
             onIterationChanged: view
               ? (iteration, maxIterations) =>
                   view.onIterationChanged(iteration, maxIterations)
@@ -385,9 +463,22 @@ export async function orchestrateAll(
           },
           logger,
         );
+        remainingItems = remainingItems.filter((i) => i.id !== item.id);
+
+        const gatedPhase = await getPendingApproval(root, item.id);
+        if (gatedPhase) {
+          result.awaitingApproval.push(item.id);
+          if (batchProgress) {
+            batchProgress.current_item = null;
+            batchProgress.updated_at = new Date().toISOString();
+            await writeBatchProgress(root, batchProgress);
+          }
+          simpleProgress?.update(item.id, `awaiting ${gatedPhase} approval`);
+          continue;
+        }
+
         result.completed.push(item.id);
         allDoneIds.add(item.id);
-        remainingItems = remainingItems.filter((i) => i.id !== item.id);
 
         // Checkpoint: item completed
         if (batchProgress) {
@@ -469,6 +560,9 @@ export async function orchestrateAll(
         parallel: effectiveParallel,
         allDoneIds,
         batchProgress,
+        onAgentEvent: trackUsage,
+        getSessionCostUsd,
+        sessionBudgetReached,
       },
       result,
     );
@@ -479,6 +573,10 @@ export async function orchestrateAll(
   }
   terminateAllAgents(logger);
 
+  if (sessionUsage) {
+    logger.info(`Session usage: ${formatUsage(sessionUsage)}`);
+  }
+
   // Clean up batch progress on successful completion (all items processed)
   if (!dryRun && batchProgress && result.remaining.length === 0) {
     await clearBatchProgress(root);
@@ -501,6 +599,9 @@ async function processItemsParallel(
     parallel: number;
     allDoneIds: Set<string>;
     batchProgress: BatchProgress | null;
+    onAgentEvent: (event: AgentEvent) => void;
+    getSessionCostUsd: () => number;
+    sessionBudgetReached: () => boolean;
   },
   result: OrchestratorResult,
 ): Promise<void> {
@@ -513,102 +614,97 @@ async function processItemsParallel(
     parallel,
     allDoneIds,
     batchProgress,
+    onAgentEvent,
+    getSessionCostUsd,
+    sessionBudgetReached,
   } = context;
 
-  // Use a local copy of items still to process
-  let queue = [...items];
-
-  // Process items with concurrency control and dependency checking
-  const processNextItem = async (): Promise<void> => {
-    while (true) {
-      try {
-        let item: IndexItem | undefined;
-        let itemIndex = -1;
-
-        // Find next runnable item (thread-safe queue management via single worker)
-        for (let i = 0; i < queue.length; i++) {
-          if (areDependenciesSatisfied(queue[i], allDoneIds)) {
-            item = queue[i];
-            itemIndex = i;
-            break;
-          }
-        }
-
-        if (!item) {
-          // No runnable items available right now
-          if (queue.length === 0) return;
+  const processItem = async (item: IndexItem): Promise<void> => {
+    if (sessionBudgetReached()) {
+      result.remaining.push(item.id);
+      return;
+    }
+    simpleProgress?.update(item.id, "starting");
 
-          // Wait a bit and try again
-          await new Promise((resolve) => setTimeout(resolve, 1000));
-          continue;
-        }
+    try {
+      await runCommand(
+        item.id,
+        {
+          force,
+          dryRun: false,
+          mockAgent,
+          cwd: root,
+          onAgentEvent,
+          getSessionCostUsd,
+        },
+        logger,
+      );
 
-        // Remove from queue
-        queue.splice(itemIndex, 1);
+      const gatedPhase = await getPendingApproval(root, item.id);
+      if (gatedPhase) {
+        result.awaitingApproval.push(item.id);
+        simpleProgress?.update(item.id, `awaiting ${gatedPhase} approval`);
+        return;
+      }
 
-        simpleProgress?.update(item.id, "starting");
+      result.completed.push(item.id);
+      allDoneIds.add(item.id);
+      simpleProgress?.complete(item.id);
+      logger.info(`✓ Completed ${item.id}`);
 
-        try {
-          await runCommand(
-            item.id,
-            { force, dryRun: false, mockAgent, cwd: root },
-            logger,
-          );
-          result.completed.push(item.id);
-          allDoneIds.add(item.id);
-          simpleProgress?.complete(item.id);
-          logger.info(`✓ Completed ${item.id}`);
+      // Checkpoint: item completed (parallel mode)
+      if (batchProgress) {
+        batchProgress.completed.push(item.id);
+        batchProgress.updated_at = new Date().toISOString();
+        await writeBatchProgress(root, batchProgress);
+      }
+    } catch (error) {
+      const errorMessage =
+        error instanceof Error ? error.message : String(error);
+      result.failed.push(item.id);
 
-          // Checkpoint: item completed (parallel mode)
-          if (batchProgress) {
-            batchProgress.completed.push(item.id);
-            batchProgress.updated_at = new Date().toISOString();
-            await writeBatchProgress(root, batchProgress);
-          }
-        } catch (error) {
-          const errorMessage =
-            error instanceof Error ? error.message : String(error);
-          result.failed.push(item.id);
+      // Checkpoint: item failed (parallel mode)
+      if (batchProgress) {
+        batchProgress.failed.push(item.id);
+        batchProgress.updated_at = new Date().toISOString();
+        await writeBatchProgress(root, batchProgress);
+      }
 
-          // Checkpoint: item failed (parallel mode)
-          if (batchProgress) {
-            batchProgress.failed.push(item.id);
-            batchProgress.updated_at = new Date().toISOString();
-            await writeBatchProgress(root, batchProgress);
-          }
+      // Persist error to item.json
+      try {
+        const itemDir = getItemDir(root, item.id);
+        const currentItem = await readItem(itemDir);
+        await writeItem(itemDir, {
+          ...currentItem,
+          last_error: errorMessage,
+        });
+      } catch {
+        /* ignore */
+      }
 
-          // Persist error to item.json
-          try {
-            const itemDir = getItemDir(root, item.id);
-            const currentItem = await readItem(itemDir);
-            await writeItem(itemDir, {
-              ...currentItem,
-              last_error: errorMessage,
-            });
-          } catch {
-            /* ignore */
-          }
+      simpleProgress?.fail(item.id, errorMessage);
+      logger.error(`✗ Failed ${item.id}: ${errorMessage}`);
+    }
+  };
 
-          simpleProgress?.fail(item.id, errorMessage);
-          logger.error(`✗ Failed ${item.id}: ${errorMessage}`);
-        }
+  const blocked = await runWorkerPool(items, {
+    concurrency: parallel,
+    isReady: (item) => areDependenciesSatisfied(item, allDoneIds),
+    run: async (item) => {
+      try {
+        await processItem(item);
       } catch (fatalError) {
         const msg =
           fatalError instanceof Error ? fatalError.message : String(fatalError);
         logger.error(`FATAL Worker Error: ${msg}`);
-        // If we hit a truly fatal error in the worker logic, we should probably stop this worker
-        return;
       }
-    }
-  };
+    },
+  });
 
-  // Create workers
-  const workers: Promise<void>[] = [];
-  for (let i = 0; i < parallel; i++) {
-    workers.push(processNextItem());
+  if (blocked.length > 0) {
+    logger.warn(`${blocked.length} items blocked by unsatisfied dependencies`);
+    result.remaining.push(...blocked.map((item) => item.id));
   }
-
-  await Promise.all(workers);
 }
 
 export async function orchestrateNext(
@@ -644,7 +740,8 @@ export async function orchestrateNext(
     const itemDir = getItemDir(root, nextItemId);
     const item = await readItem(itemDir);
     const { getNextPhase } = await import("../workflow");
-    const nextPhase = getNextPhase(item);
+    const { getItemPipeline } = await import("../domain/pipeline");
+    const nextPhase = getNextPhase(item, getItemPipeline(item, config));
     formatDryRunRun(item, nextPhase || "unknown", config, logger);
     return { itemId: nextItemId, success: true };
   }
@@ -678,9 +775,10 @@ export async function getNextIncompleteItem(
     items.filter((i) => i.state === "done").map((i) => i.id),
   );
 
-  // Find first non-done item with satisfied dependencies
+  // Find first actionable item with satisfied dependencies
   const nextItem = items.find((item) => {
-    if (item.state === "done") return false;
+    if (!isActionableState(item.state)) return false;
+    if (item.pending_approval) return false;
     return areDependenciesSatisfied(item, doneIds);
   });
 
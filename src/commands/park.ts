@@ -0,0 +1,120 @@
+import type { Logger } from "../logging";
+import type { Item, WorkflowState } from "../schemas";
+import { findRootFromOptions, getItemDir } from "../fs/paths";
+import { readItem, writeItem } from "../fs/json";
+import { appendHistory } from "../fs/history";
+import { applyPark, applyResume } from "../domain/transitions";
+import { ErrorCodes, FileNotFoundError, WreckitError } from "../errors";
+
+export type ParkState = Extract<WorkflowState, "blocked" | "paused" | "wontfix">;
+
+export interface ParkCommandOptions {
+  reason?: string;
+  /** Unblock condition (blocked only) */
+  until?: string;
+  dryRun?: boolean;
+  cwd?: string;
+}
+
+export interface ResumeOptions {
+  dryRun?: boolean;
+  cwd?: string;
+}
+
+async function loadItemOrThrow(root: string, itemId: string): Promise<Item> {
+  try {
+    return await readItem(getItemDir(root, itemId));
+  } catch (err) {
+    if (err instanceof FileNotFoundError) {
+      throw new WreckitError(
+        `Item not found: ${itemId}`,
+        ErrorCodes.ITEM_NOT_FOUND,
+      );
+    }
+    throw err;
+  }
+}
+
+/**
+ * Move an item to blocked, paused or wontfix.
+ * Blocked and wontfix require a reason.
+ */
+export async function parkCommand(
+  itemId: string,
+  state: ParkState,
+  options: ParkCommandOptions,
+  logger: Logger,
+): Promise<Item> {
+  const { dryRun = false } = options;
+  const reason = options.reason?.trim() || null;
+
+  if (!reason && state !== "paused") {
+    throw new WreckitError(
+      `A reason is required to mark an item ${state}`,
+      ErrorCodes.PHASE_VALIDATION,
+    );
+  }
+
+  const root = findRootFromOptions(options);
+  const item = await loadItemOrThrow(root, itemId);
+
+  const result = applyPark(item, state, {
+    reason,
+    unblockCondition: options.until?.trim() || null,
+    actor: "human",
+  });
+  if (!result.nextItem) {
+    throw new WreckitError(
+      `Cannot mark ${itemId} ${state}: ${result.error}`,
+      ErrorCodes.INVALID_TRANSITION,
+    );
+  }
+
+  if (dryRun) {
+    logger.info(`[dry-run] Would mark ${itemId} ${state} (was ${item.state})`);
+    return item;
+  }
+
+  await writeItem(getItemDir(root, itemId), result.nextItem);
+  await appendHistory(root, itemId, result.history);
+
+  logger.info(`Marked ${itemId} ${state} (was ${item.state})`);
+  if (result.nextItem.unblock_condition) {
+    logger.info(`Unblock when: ${result.nextItem.unblock_condition}`);
+  }
+  return result.nextItem;
+}
+
+/**
+ * Return a blocked, paused or wontfix item to the state it was parked from.
+ */
+export async function resumeCommand(
+  itemId: string,
+  options: ResumeOptions,
+  logger: Logger,
+): Promise<Item> {
+  const { dryRun = false } = options;
+  const root = findRootFromOptions(options);
+  const item = await loadItemOrThrow(root, itemId);
+
+  const result = applyResume(item, { actor: "human" });
+  if (!result.nextItem) {
+    throw new WreckitError(
+      `Cannot resume ${itemId}: ${result.error}`,
+      ErrorCodes.INVALID_TRANSITION,
+    );
+  }
+
+  if (dryRun) {
+    logger.info(
+      `[dry-run] Would resume ${itemId}: ${item.state} → ${result.nextItem.state}`,
+    );
+    return item;
+  }
+
+  await writeItem(getItemDir(root, itemId), result.nextItem);
+  await appendHistory(root, itemId, result.history);
+
+  logger.info(`Resumed ${itemId}: ${item.state} → ${result.nextItem.state}`);
+  return result.nextItem;
+}
@@ -1,28 +1,33 @@
 import type { Logger } from "../logging";
-import type { WorkflowState } from "../schemas";
+import type { PhaseName, WorkflowState } from "../schemas";
 import { findRepoRoot, findRootFromOptions, getItemDir } from "../fs/paths";
 import { readItem } from "../fs/json";
 import { loadConfig } from "../config";
 import { FileNotFoundError, WreckitError, isWreckitError } from "../errors";
 import {
   runPhaseResearch,
+  runPhaseReproduce,
   runPhasePlan,
+  runPhaseTests,
   runPhaseImplement,
   runPhaseCritique,
+  runPhaseDocument,
   runPhasePr,
   runPhaseComplete,
   type PhaseResult,
   type WorkflowOptions,
 } from "../workflow";
+import {
+  PHASE_TARGET_STATES,
+  getItemPipeline,
+  getPhaseEntryState,
+  getPipelineStates,
+  type Pipeline,
+} from "../domain/pipeline";
+import { isParkedState } from "../domain/states";
 import { formatDryRunPhase } from "./dryRunFormatter";
 
-export type Phase =
-  | "research"
-  | "plan"
-  | "implement"
-  | "critique"
-  | "pr"
-  | "complete";
+export type Phase = PhaseName;
 
 export interface PhaseOptions {
   force?: boolean;
@@ -32,72 +37,101 @@ export interface PhaseOptions {
 }
 
 /**
- * Configuration mapping phases to their required and target states.
+ * Configuration for each phase runner.
  *
  * Each phase specifies:
- * - requiredState: The state(s) an item must be in to run this phase
- * - targetState: The state the item will be in after successful phase completion
+ * - reentrant: Whether the phase may also run on an item already in its target state
  * - skipIfInTarget: Whether to skip execution if already in target state
  * - runFn: The workflow function that implements the phase
  *
- * NOTE: Phase names and state mappings have a bidirectional relationship with getNextPhase() in
- * src/workflow/itemWorkflow.ts:607-626. Changes here may require updates there.
+ * Required and target states come from the item's pipeline (see src/domain/pipeline.ts),
+ * so a phase runs from whatever state precedes its stage in that pipeline.
  */
 const PHASE_CONFIG: Record<
   Phase,
   {
-    requiredState: WorkflowState | WorkflowState[];
-    targetState: WorkflowState;
+    reentrant: boolean;
     skipIfInTarget: boolean;
     runFn: (itemId: string, options: WorkflowOptions) => Promise<PhaseResult>;
   }
 > = {
   research: {
-    requiredState: "idea",
-    targetState: "researched",
+    reentrant: false,
     skipIfInTarget: true,
     runFn: runPhaseResearch,
   },
+  reproduce: {
+    reentrant: false,
+    skipIfInTarget: true,
+    runFn: runPhaseReproduce,
+  },
   plan: {
-    requiredState: "researched",
-    targetState: "planned",
+    reentrant: false,
     skipIfInTarget: true,
     runFn: runPhasePlan,
   },
+  tests: {
+    reentrant: false,
+    skipIfInTarget: true,
+    runFn: runPhaseTests,
+  },
   implement: {
-    requiredState: ["planned", "implementing"],
-    targetState: "implementing",
+    reentrant: true,
     skipIfInTarget: false,
     runFn: runPhaseImplement,
   },
   critique: {
-    requiredState: ["implementing", "critique"],
-    targetState: "critique",
+    reentrant: true,
     skipIfInTarget: true,
     runFn: runPhaseCritique,
   },
+  document: {
+    reentrant: false,
+    skipIfInTarget: true,
+    runFn: runPhaseDocument,
+  },
   pr: {
-    requiredState: "critique",
-    targetState: "in_pr",
+    reentrant: false,
     skipIfInTarget: true,
     runFn: runPhasePr,
   },
   complete: {
-    requiredState: "in_pr",
-    targetState: "done",
+    reentrant: false,
     skipIfInTarget: true,
     runFn: runPhaseComplete,
   },
 };
 
+interface PhaseTransition {
+  requiredState: WorkflowState[];
+  targetState: WorkflowState;
+}
+
+/**
+ * Resolve the required and target states of a phase within a pipeline.
+ *
+ * @returns The transition, or null if the pipeline has no stage for the phase
+ */
+export function getPhaseTransition(
+  phase: Phase,
+  pipeline: Pipeline,
+): PhaseTransition | null {
+  const entryState = getPhaseEntryState(pipeline, phase);
+  if (entryState === null) {
+    return null;
+  }
+  const targetState = PHASE_TARGET_STATES[phase];
+  const requiredState = PHASE_CONFIG[phase].reentrant
+    ? [entryState, targetState]
+    : [entryState];
+  return { requiredState, targetState };
+}
+
 function isInRequiredState(
   currentState: WorkflowState,
-  required: WorkflowState | WorkflowState[],
+  required: WorkflowState[],
 ): boolean {
-  if (Array.isArray(required)) {
-    return required.includes(currentState);
-  }
-  return currentState === required;
+  return required.includes(currentState);
 }
 
 function isInTargetState(
@@ -112,33 +146,24 @@ function isInTargetState(
  *
  * A transition is invalid if:
  * 1. The current state is "done" (terminal) and phase is not "complete"
- * 2. The current state index is greater than the target state index (backward transition)
- *
- * Uses a local stateOrder array - must stay synchronized with WORKFLOW_STATES in src/domain/states.ts:3-10
+ * 2. The current state comes after the target state in the pipeline (backward transition)
  *
  * @param phase - The phase being executed
  * @param currentState - The item's current workflow state
+ * @param targetState - The state the phase produces
+ * @param pipeline - The item's pipeline, which defines state ordering
  * @returns true if the transition should be blocked
  */
 function isInvalidTransition(
   phase: Phase,
   currentState: WorkflowState,
+  targetState: WorkflowState,
+  pipeline: Pipeline,
 ): boolean {
-  const config = PHASE_CONFIG[phase];
-  // IMPORTANT: This array MUST match WORKFLOW_STATES in src/domain/states.ts:3-10
-  // This is a local duplicate for encapsulation - update both locations if state ordering changes
-  const stateOrder: WorkflowState[] = [
-    "idea",
-    "researched",
-    "planned",
-    "implementing",
-    "critique",
-    "in_pr",
-    "done",
-  ];
+  const stateOrder = getPipelineStates(pipeline);
 
   const currentIndex = stateOrder.indexOf(currentState);
-  const targetIndex = stateOrder.indexOf(config.targetState);
+  const targetIndex = stateOrder.indexOf(targetState);
 
   if (currentState === "done" && phase !== "complete") {
     return true;
@@ -177,9 +202,35 @@ export async function runPhaseCommand(
     throw err;
   }
 
+  if (isParkedState(item.state)) {
+    throw new WreckitError(
+      `Item ${itemId} is ${item.state}; run 'wreckit resume ${itemId}' before running ${phase}`,
+      "INVALID_STATE",
+    );
+  }
+
+  // Re-running the gated phase itself is allowed (it replaces the output under review)
+  if (item.pending_approval && item.pending_approval !== phase) {
+    throw new WreckitError(
+      `Item ${itemId} is awaiting ${item.pending_approval} approval; run 'wreckit approve ${itemId}' or 'wreckit reject ${itemId}' before running ${phase}`,
+      "INVALID_STATE",
+    );
+  }
+
   const phaseConfig = PHASE_CONFIG[phase];
+  const pipeline = getItemPipeline(item, config);
+  const transition = getPhaseTransition(phase, pipeline);
 
-  if (isInvalidTransition(phase, item.state)) {
+  if (!transition) {
+    throw new WreckitError(
+      `Phase '${phase}' is not part of pipeline '${pipeline.name}'`,
+      "INVALID_TRANSITION",
+    );
+  }
+
+  if (
+    isInvalidTransition(phase, item.state, transition.targetState, pipeline)
+  ) {
     throw new WreckitError(
       `Cannot run ${phase} on item in state '${item.state}' - invalid transition`,
       "INVALID_TRANSITION",
@@ -189,7 +240,7 @@ export async function runPhaseCommand(
   if (
     !force &&
     phaseConfig.skipIfInTarget &&
-    isInTargetState(item.state, phaseConfig.targetState)
+    isInTargetState(item.state, transition.targetState)
   ) {
     logger.info(
       `Item ${itemId} is already in state '${item.state}', skipping (use --force to override)`,
@@ -199,12 +250,10 @@ export async function runPhaseCommand(
 
   if (
     !force &&
-    !isInRequiredState(item.state, phaseConfig.requiredState) &&
-    !isInTargetState(item.state, phaseConfig.targetState)
+    !isInRequiredState(item.state, transition.requiredState) &&
+    !isInTargetState(item.state, transition.targetState)
   ) {
-    const requiredStr = Array.isArray(phaseConfig.requiredState)
-      ? phaseConfig.requiredState.join("' or '")
-      : phaseConfig.requiredState;
+    const requiredStr = transition.requiredState.join("' or '");
     throw new WreckitError(
       `Item is in state '${item.state}', expected '${requiredStr}' for ${phase} phase`,
       "INVALID_STATE",
@@ -212,7 +261,7 @@ export async function runPhaseCommand(
   }
 
   if (dryRun) {
-    formatDryRunPhase(phase, item, phaseConfig.targetState, config, logger);
+    formatDryRunPhase(phase, item, transition.targetState, config, logger);
     return;
   }
 
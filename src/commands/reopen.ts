@@ -0,0 +1,83 @@
+import type { Logger } from "../logging";
+import { findRootFromOptions, getItemDir } from "../fs/paths";
+import { readItem } from "../fs/json";
+import { loadConfig } from "../config";
+import { ErrorCodes, FileNotFoundError, WreckitError } from "../errors";
+import { ItemStateSchema, type WorkflowState } from "../schemas";
+import { regressItem, type RegressResult } from "../workflow/regress";
+
+export interface ReopenOptions {
+  to: string;
+  reason: string;
+  dryRun?: boolean;
+  cwd?: string;
+}
+
+/**
+ * Send an item back to an earlier state, archiving superseded artifacts.
+ *
+ * @throws WreckitError if the target state is unknown or the reason is empty
+ * @throws TransitionError if the target is not an earlier state in the item's pipeline
+ */
+export async function reopenCommand(
+  itemId: string,
+  options: ReopenOptions,
+  logger: Logger,
+): Promise<RegressResult> {
+  const { dryRun = false } = options;
+
+  const parsedState = ItemStateSchema.safeParse(options.to);
+  if (!parsedState.success) {
+    throw new WreckitError(
+      `Unknown state '${options.to}'. Valid states: ${ItemStateSchema.options.join(", ")}`,
+      ErrorCodes.INVALID_STATE,
+    );
+  }
+  const to: WorkflowState = parsedState.data;
+
+  const reason = options.reason.trim();
+  if (!reason) {
+    throw new WreckitError(
+      "A reason is required to reopen an item",
+      ErrorCodes.PHASE_VALIDATION,
+    );
+  }
+
+  const root = findRootFromOptions(options);
+  const config = await loadConfig(root);
+
+  try {
+    await readItem(getItemDir(root, itemId));
+  } catch (err) {
+    if (err instanceof FileNotFoundError) {
+      throw new WreckitError(
+        `Item not found: ${itemId}`,
+        ErrorCodes.ITEM_NOT_FOUND,
+      );
+    }
+    throw err;
+  }
+
+  const result = await regressItem(itemId, {
+    root,
+    config,
+    logger,
+    to,
+    reason,
+    actor: "human",
+    dryRun,
+  });
+
+  if (!dryRun) {
+    if (result.archivedFiles.length > 0) {
+      logger.info(
+        `Archived ${result.archivedFiles.join(", ")} to ${result.archiveDir}`,
+      );
+    }
+    if (result.storiesReset > 0) {
+      logger.info(`Reset ${result.storiesReset} stories to pending`);
+    }
+  }
+
+  return result;
+}
@@ -1,6 +1,8 @@
 import type { Logger } from "../logging";
 import { findRootFromOptions, getItemDir } from "../fs/paths";
 import { readItem, writeItem } from "../fs/json";
+import { appendHistory } from "../fs/history";
+import { createTransitionEntry } from "../domain/transitions";
 import { loadConfig } from "../config";
 import { FileNotFoundError, WreckitError } from "../errors";
 import { runGitCommand, type GitOptions } from "../git";
@@ -114,6 +116,13 @@ export async function rollbackCommand(
     updated_at: new Date().toISOString(),
   };
   await writeItem(itemDir, updatedItem);
+  await appendHistory(
+    root,
+    itemId,
+    createTransitionEntry(item.state, "implementing", "human", {
+      reason: `rollback to ${item.rollback_sha}`,
+    }),
+  );
 
   logger.info(`Rolled back ${config.base_branch} to ${item.rollback_sha}`);
   logger.info(`Item ${itemId} reset to 'implementing' state`);
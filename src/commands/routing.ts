@@ -0,0 +1,85 @@
+import type { Logger } from "../logging";
+import { PhaseNameSchema, type PhaseName } from "../schemas";
+import {
+  getPhaseSettings,
+  loadConfig,
+  type ConfigOverrides,
+  type ConfigResolved,
+} from "../config";
+import { findRootFromOptions } from "../fs/paths";
+import { agentLabel } from "../agent/fallback";
+
+export interface RoutingOptions {
+  json?: boolean;
+  cwd?: string;
+  agentKind?: string;
+  sandbox?: boolean;
+}
+
+export interface PhaseRoute {
+  phase: PhaseName;
+  /** Agent labels in fallback order, see agentLabel */
+  agents: string[];
+  /** Config key the agent comes from */
+  source: string;
+  timeout_seconds: number;
+  max_iterations: number;
+}
+
+/**
+ * The agent, fallbacks and limits every phase runs with, and where in the
+ * config the agent was set.
+ */
+export function getPhaseRouting(config: ConfigResolved): PhaseRoute[] {
+  return PhaseNameSchema.options
+    .filter((phase) => phase !== "complete")
+    .map((phase) => {
+      const settings = getPhaseSettings(config, phase);
+      const source = config.phases?.[phase]?.agent
+        ? `phases.${phase}.agent`
+        : config.phase_agents?.[phase]
+          ? `phase_agents.${phase}`
+          : "agent";
+      return {
+        phase,
+        agents: [settings.agent, ...(settings.agent_fallbacks ?? [])].map(
+          agentLabel,
+        ),
+        source,
+        timeout_seconds: settings.timeout_seconds,
+        max_iterations: settings.max_iterations,
+      };
+    });
+}
+
+export function formatPhaseRoute(route: PhaseRoute): string {
+  return `  ${route.phase.padEnd(10)} ${route.agents.join(" → ")}  (${route.source}, ${route.timeout_seconds}s timeout, max ${route.max_iterations} iterations)`;
+}
+
+export async function routingCommand(
+  options: RoutingOptions,
+  logger: Logger,
+): Promise<void> {
+  const root = findRootFromOptions(options);
+  const overrides: ConfigOverrides = {
+    agentKind: options.agentKind,
+    sandbox: options.sandbox,
+  };
+  const config = await loadConfig(root, overrides);
+  const routing = getPhaseRouting(config);
+
+  if (options.json) {
+    logger.json(routing);
+    return;
+  }
+
+  logger.info("Agent routing by phase:");
+  logger.info("");
+  for (const route of routing) {
+    logger.info(formatPhaseRoute(route));
+  }
+  if (options.agentKind || options.sandbox) {
+    logger.info("");
+    logger.info("CLI agent overrides replace phase routing and fallbacks.");
+  }
+}
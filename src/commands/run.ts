@@ -16,14 +16,19 @@ import { loadConfig } from "../config";
 import { FileNotFoundError, WreckitError, isWreckitError } from "../errors";
 import {
   runPhaseResearch,
+  runPhaseReproduce,
   runPhasePlan,
+  runPhaseTests,
   runPhaseImplement,
   runPhaseCritique,
+  runPhaseDocument,
   runPhasePr,
   runPhaseComplete,
   getNextPhase,
   type WorkflowOptions,
 } from "../workflow";
+import { PHASE_TARGET_STATES, getItemPipeline } from "../domain/pipeline";
+import { isParkedState } from "../domain/states";
 import { formatDryRunRun } from "./dryRunFormatter";
 
 export interface RunOptions {
@@ -40,6 +45,8 @@ export interface RunOptions {
   noHealing?: boolean;
   /** Run in sandbox mode with ephemeral Sprite VM */
   sandbox?: boolean;
+  /** Orchestrator session cost so far, for budget.per_session_usd */
+  getSessionCostUsd?: () => number;
 }
 
 async function phaseArtifactsExist(
@@ -55,8 +62,11 @@ async function phaseArtifactsExist(
       const prdExists = await pathExists(getPrdPath(root, itemId));
       return planExists && prdExists;
     }
+    case "reproduce":
+    case "tests":
     case "implement":
     case "critique":
+    case "document":
     case "pr":
     case "complete":
       return false;
@@ -82,6 +92,7 @@ export async function runCommand(
     cwd,
     noHealing = false,
     sandbox,
+    getSessionCostUsd,
   } = options;
 
   const root = findRootFromOptions(options);
@@ -107,6 +118,13 @@ export async function runCommand(
     return;
   }
 
+  if (isParkedState(item.state)) {
+    logger.info(
+      `Item ${itemId} is ${item.state}, skipping (run 'wreckit resume ${itemId}' to continue)`,
+    );
+    return;
+  }
+
   const workflowOptions: WorkflowOptions = {
     root,
     config,
@@ -120,13 +138,17 @@ export async function runCommand(
     onStoryChanged,
     onPhaseChanged,
     noHealing, // Pass through healing flag
+    getSessionCostUsd,
   };
 
   const phaseRunners = {
     research: runPhaseResearch,
+    reproduce: runPhaseReproduce,
     plan: runPhasePlan,
+    tests: runPhaseTests,
     implement: runPhaseImplement,
     critique: runPhaseCritique,
+    document: runPhaseDocument,
     pr: runPhasePr,
     complete: runPhaseComplete,
   };
@@ -139,7 +161,15 @@ export async function runCommand(
       return;
     }
 
-    const nextPhase = getNextPhase(item);
+    if (item.pending_approval) {
+      logger.info(
+        `Item ${itemId} is awaiting ${item.pending_approval} approval (run 'wreckit approve ${itemId}' or 'wreckit reject ${itemId} --comment ...')`,
+      );
+      return;
+    }
+
+    const pipeline = getItemPipeline(item, config);
+    const nextPhase = getNextPhase(item, pipeline);
     if (!nextPhase) {
       logger.info(
         `Item ${itemId} is in state '${item.state}' with no next phase`,
@@ -177,15 +207,7 @@ export async function runCommand(
     logger.info(`Running ${nextPhase} phase on ${itemId}`);
 
     // Map phase names to workflow states for TUI display
-    const phaseToState: Record<string, string> = {
-      research: "researched",
-      plan: "planned",
-      implement: "implementing",
-      critique: "critique",
-      pr: "in_pr",
-      complete: "done",
-    };
-    onPhaseChanged?.(phaseToState[nextPhase] ?? nextPhase);
+    onPhaseChanged?.(PHASE_TARGET_STATES[nextPhase] ?? nextPhase);
 
     const runner = phaseRunners[nextPhase];
     const result = await runner(itemId, workflowOptions);
@@ -10,10 +10,16 @@ import {
   getResearchPath,
   getPlanPath,
   getPrdPath,
+  getReportPath,
 } from "../fs/paths";
-import { checkPathAccess } from "../fs/util";
-import { readItem, readJsonWithSchema } from "../fs/json";
+import { checkPathAccess, pathExists } from "../fs/util";
+import {
+  readItem,
+  readJsonWithSchema,
+  readTestBaseline,
+} from "../fs/json";
 import { FileNotFoundError } from "../errors";
+import { formatUsage, getItemUsage } from "../domain/usage";
 
 export interface ShowOptions {
   json?: boolean;
@@ -93,6 +99,17 @@ export async function showCommand(
   logger.info(`ID: ${item.id}`);
   logger.info(`Title: ${item.title}`);
   logger.info(`State: ${item.state}`);
+  if (item.pending_approval) {
+    logger.info(`Awaiting approval: ${item.pending_approval}`);
+  }
+
+  if (item.kind) {
+    logger.info(`Kind: ${item.kind}`);
+  }
+
+  if (item.pipeline) {
+    logger.info(`Pipeline: ${item.pipeline}`);
+  }
 
   if (item.overview) {
     logger.info(`Overview: ${item.overview}`);
@@ -101,6 +118,66 @@ export async function showCommand(
   logger.info("");
   logger.info(`Research: ${hasResearch ? "✓" : "✗"}`);
   logger.info(`Plan: ${hasPlan ? "✓" : "✗"}`);
+  if (item.kind === "spike") {
+    const hasReport = await pathExists(getReportPath(root, item.id));
+    logger.info(`Report: ${hasReport ? "✓" : "✗"}`);
+  }
+
+  if (item.reproduction) {
+    const { command, failing_run, passing_run } = item.reproduction;
+    const status = passing_run
+      ? "fixed (failed, then passed)"
+      : failing_run
+        ? "failing"
+        : "not run";
+    logger.info(`Reproduction: ${status} — ${command}`);
+  }
+
+  if (item.story_tests) {
+    const count = item.story_tests.stories.reduce(
+      (sum, entry) => sum + entry.tests.length,
+      0,
+    );
+    logger.info(
+      `Story tests: ${count} for ${item.story_tests.stories.length} stories — ${item.story_tests.command}`,
+    );
+  }
+
+  if (item.documentation) {
+    const files = item.documentation.changed_files;
+    logger.info(
+      `Documentation: ${files.length > 0 ? files.join(", ") : "no changes"}`,
+    );
+  }
+
+  const usage = getItemUsage(item);
+  if (usage) {
+    logger.info(`Usage: ${formatUsage(usage)} (${usage.runs} runs)`);
+    for (const [phase, totals] of Object.entries(item.usage ?? {})) {
+      if (totals) {
+        logger.info(`  ${phase}: ${formatUsage(totals)}`);
+      }
+    }
+    if (usage.models.length > 0) {
+      logger.info(`  Models: ${usage.models.join(", ")}`);
+    }
+  }
+
+  if (item.kind === "refactor") {
+    const baseline = await readTestBaseline(getItemDir(root, item.id)).catch(
+      () => null,
+    );
+    if (baseline) {
+      const passing = baseline.tests.filter(
+        (t) => t.status === "passed",
+      ).length;
+      logger.info(
+        `Test baseline: ${passing}/${baseline.tests.length} passing (${baseline.recorded_at})`,
+      );
+    } else {
+      logger.info("Test baseline: -");
+    }
+  }
 
   if (prd) {
     const pending = prd.user_stories.filter(
@@ -0,0 +1,94 @@
+import * as fs from "node:fs/promises";
+import type { Logger } from "../logging";
+import { findRootFromOptions, getItemDir, getReportPath } from "../fs/paths";
+import { loadConfig } from "../config";
+import { readItem } from "../fs/json";
+import { pathExists } from "../fs/util";
+import { ErrorCodes, FileNotFoundError, WreckitError } from "../errors";
+import { parseFollowUpIdeas } from "../domain/spike";
+import { persistItems, generateSlug } from "../domain/ideas";
+import type { Item } from "../schemas";
+
+export interface SpikeFollowupsOptions {
+  dryRun?: boolean;
+  cwd?: string;
+}
+
+/**
+ * Create items for the follow-up ideas a spike's report.md recommends.
+ *
+ * Ideas come from the list under the report's "Follow-up Ideas" section.
+ * Items that already exist (same slug) are skipped, so the command can be
+ * run again after editing the report.
+ *
+ * @returns The created items
+ */
+export async function spikeFollowupsCommand(
+  itemId: string,
+  options: SpikeFollowupsOptions,
+  logger: Logger,
+): Promise<Item[]> {
+  const root = findRootFromOptions(options);
+
+  let spike: Item;
+  try {
+    spike = await readItem(getItemDir(root, itemId));
+  } catch (err) {
+    if (err instanceof FileNotFoundError) {
+      throw new WreckitError(
+        `Item not found: ${itemId}`,
+        ErrorCodes.ITEM_NOT_FOUND,
+      );
+    }
+    throw err;
+  }
+
+  const reportPath = getReportPath(root, itemId);
+  if (!(await pathExists(reportPath))) {
+    throw new FileNotFoundError(
+      `report.md not found for ${itemId}. Run 'wreckit research ${itemId}' on a spike item first.`,
+    );
+  }
+
+  const ideas = parseFollowUpIdeas(await fs.readFile(reportPath, "utf-8")).map(
+    (idea) => ({
+      ...idea,
+      motivation: `Recommended by spike ${spike.id}: ${spike.title}`,
+    }),
+  );
+
+  if (ideas.length === 0) {
+    logger.info(`No follow-up ideas found in ${itemId}/report.md`);
+    return [];
+  }
+
+  if (options.dryRun) {
+    logger.info(`Would create ${ideas.length} items from ${itemId}:`);
+    for (const idea of ideas) {
+      logger.info(`  ${generateSlug(idea.title)}`);
+    }
+    return [];
+  }
+
+  const result = await persistItems(root, ideas, {
+    config: await loadConfig(root),
+  });
+
+  if (result.created.length > 0) {
+    logger.info(
+      `Created ${result.created.length} items from spike ${itemId}:`,
+    );
+    for (const item of result.created) {
+      logger.info(`  ${item.id}`);
+    }
+  }
+
+  if (result.skipped.length > 0) {
+    logger.info(`Skipped ${result.skipped.length} existing items:`);
+    for (const id of result.skipped) {
+      logger.info(`  ${id}`);
+    }
+  }
+
+  return result.created;
+}
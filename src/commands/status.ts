@@ -5,6 +5,7 @@ import type { Index, IndexItem, Item } from "../schemas";
 import { findRepoRoot, findRootFromOptions, getItemsDir } from "../fs/paths";
 import { readItem } from "../fs/json";
 import { buildIdMap } from "../domain/resolveId";
+import { formatUsage, sumUsageTotals } from "../domain/usage";
 import {
   FileNotFoundError,
   InvalidJsonError,
@@ -44,6 +45,9 @@ export async function scanItems(root: string): Promise<IndexItem[]> {
         state: item.state,
         title: item.title,
         depends_on: item.depends_on,
+        ...(item.pending_approval
+          ? { pending_approval: item.pending_approval }
+          : {}),
       });
     } catch (err) {
       // Expected errors: skip silently (Spec 002 Gap 3)
@@ -74,6 +78,9 @@ export async function statusCommand(
       fullId: i.fullId,
       state: i.state,
       title: i.title,
+      ...(i.parkedReason ? { reason: i.parkedReason } : {}),
+      ...(i.pendingApproval ? { pending_approval: i.pendingApproval } : {}),
+      ...(i.usage ? { usage: i.usage } : {}),
     }));
     logger.json({
       schema_version: 1,
@@ -92,7 +99,19 @@ export async function statusCommand(
   console.log(header);
 
   for (const item of items) {
-    const line = `${String(item.shortId).padStart(3)}  ${item.state}`;
+    const reason = item.parkedReason
+      ? `  (${item.parkedReason})`
+      : item.pendingApproval
+        ? `  (awaiting ${item.pendingApproval} approval)`
+        : "";
+    const cost = item.usage ? `  $${item.usage.cost_usd.toFixed(2)}` : "";
+    const line = `${String(item.shortId).padStart(3)}  ${item.state}${reason}${cost}`;
     console.log(line);
   }
+
+  const usages = items.flatMap((item) => (item.usage ? [item.usage] : []));
+  if (usages.length > 0) {
+    console.log("");
+    console.log(`Usage: ${formatUsage(sumUsageTotals(usages))}`);
+  }
 }
@@ -0,0 +1,291 @@
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import type { Logger } from "../logging";
+import { PhaseNameSchema, type PhaseName } from "../schemas";
+import { findRootFromOptions, getItemDir } from "../fs/paths";
+import { readItem } from "../fs/json";
+import {
+  listTranscripts,
+  readTranscript,
+  type TranscriptEntry,
+  type TranscriptFile,
+} from "../fs/transcripts";
+import { FileNotFoundError, WreckitError, ErrorCodes } from "../errors";
+
+export interface TranscriptOptions {
+  phase?: string;
+  markdown?: boolean;
+  /** Write Markdown to this file instead of printing */
+  output?: string;
+  json?: boolean;
+  cwd?: string;
+}
+
+const MAX_INPUT_CHARS = 200;
+const MAX_RESULT_CHARS = 4000;
+
+function truncate(text: string, max: number): string {
+  if (text.length <= max) return text;
+  return `${text.slice(0, max)}… [${text.length - max} more chars]`;
+}
+
+function stringify(value: unknown): string {
+  return typeof value === "string" ? value : JSON.stringify(value, null, 2);
+}
+
+export function formatToolDuration(ms: number): string {
+  return ms < 1000 ? `${ms}ms` : `${(ms / 1000).toFixed(1)}s`;
+}
+
+function took(durationMs: number | undefined): string {
+  return durationMs !== undefined
+    ? ` (${formatToolDuration(durationMs)})`
+    : "";
+}
+
+/**
+ * Tool names by tool use id, since results only carry the id.
+ */
+function toolNames(entries: TranscriptEntry[]): Map<string, string> {
+  const names = new Map<string, string>();
+  for (const { event } of entries) {
+    if (event.type === "tool_started") {
+      names.set(event.toolUseId, event.toolName);
+    }
+  }
+  return names;
+}
+
+/**
+ * Render a transcript as timeline lines. Progress-only usage events are
+ * left out.
+ */
+export function formatTranscript(entries: TranscriptEntry[]): string[] {
+  const names = toolNames(entries);
+  const lines: string[] = [];
+  for (const { ts, event, duration_ms } of entries) {
+    const time = ts.slice(11, 19);
+    switch (event.type) {
+      case "assistant_text":
+        lines.push(
+          `${time}  ${event.text.trim().replace(/\n/g, "\n          ")}`,
+        );
+        break;
+      case "tool_started":
+        lines.push(
+          `${time}  → ${event.toolName} ${truncate(JSON.stringify(event.input), MAX_INPUT_CHARS)}`,
+        );
+        break;
+      case "tool_result":
+        lines.push(
+          `${time}  ← ${names.get(event.toolUseId) ?? "tool"}${took(duration_ms)}`,
+        );
+        break;
+      case "tool_error":
+        lines.push(
+          `${time}  ✗ ${names.get(event.toolUseId) ?? "tool"}${took(duration_ms)}: ${event.error}`,
+        );
+        break;
+      case "agent_started":
+        lines.push(`${time}  ▶ ${event.agent}`);
+        break;
+      case "agent_fallback":
+        lines.push(
+          `${time}  ↪ ${event.from} ${event.reason}, falling back to ${event.to}`,
+        );
+        break;
+      case "run_result":
+        lines.push(
+          `${time}  ✓ run finished${event.subtype ? ` (${event.subtype})` : ""}`,
+        );
+        break;
+      case "usage":
+        lines.push(
+          `${time}  Σ ${event.usage.input_tokens} in / ${event.usage.output_tokens} out tokens${event.usage.model ? ` (${event.usage.model})` : ""}`,
+        );
+        break;
+      case "stall_detected":
+        lines.push(`${time}  ⚠ stuck: ${event.message} (${event.action})`);
+        break;
+      case "scope_exceeded":
+        lines.push(
+          `${time}  ⚠ scope exceeded: ${event.violations.join("; ")}`,
+        );
+        break;
+      case "path_denied":
+        lines.push(`${time}  ⛔ ${event.tool} denied: ${event.reason}`);
+        break;
+      case "command_denied":
+        lines.push(
+          `${time}  ⛔ Bash ${event.action === "deny" ? "denied" : "needs approval"}: ${event.command} (${event.reason})`,
+        );
+        break;
+      case "error":
+        lines.push(`${time}  ✗ ${event.message}`);
+        break;
+      case "usage_progress":
+        break;
+    }
+  }
+  return lines;
+}
+
+/**
+ * Render a transcript as Markdown: agent text as paragraphs, tool calls
+ * with their input and a collapsed result.
+ */
+export function transcriptToMarkdown(entries: TranscriptEntry[]): string {
+  const names = toolNames(entries);
+  const blocks: string[] = [];
+  for (const { ts, event, duration_ms } of entries) {
+    switch (event.type) {
+      case "assistant_text":
+        blocks.push(event.text.trim());
+        break;
+      case "tool_started":
+        blocks.push(
+          `**→ ${event.toolName}** · ${ts}\n\n\`\`\`json\n${JSON.stringify(event.input, null, 2)}\n\`\`\``,
+        );
+        break;
+      case "tool_result":
+        blocks.push(
+          `<details><summary>${names.get(event.toolUseId) ?? "tool"} result${took(duration_ms)}</summary>\n\n\`\`\`\`\n${truncate(stringify(event.result), MAX_RESULT_CHARS)}\n\`\`\`\`\n\n</details>`,
+        );
+        break;
+      case "tool_error":
+        blocks.push(
+          `> **${names.get(event.toolUseId) ?? "tool"} failed${took(duration_ms)}:** ${event.error}`,
+        );
+        break;
+      case "agent_started":
+        blocks.push(`_Agent: ${event.agent}_`);
+        break;
+      case "agent_fallback":
+        blocks.push(
+          `> ↪ ${event.from} ${event.reason}, falling back to ${event.to}`,
+        );
+        break;
+      case "run_result":
+        blocks.push(
+          `_Run finished${event.subtype ? ` (${event.subtype})` : ""}_`,
+        );
+        break;
+      case "usage":
+        blocks.push(
+          `_Usage: ${event.usage.input_tokens} input / ${event.usage.output_tokens} output tokens_`,
+        );
+        break;
+      case "stall_detected":
+        blocks.push(`> ⚠ **Stuck (${event.action}):** ${event.message}`);
+        break;
+      case "scope_exceeded":
+        blocks.push(
+          `> ⚠ **Story scope exceeded:** ${event.violations.join("; ")}`,
+        );
+        break;
+      case "path_denied":
+        blocks.push(`> ⛔ **${event.tool} denied:** ${event.reason}`);
+        break;
+      case "command_denied":
+        blocks.push(
+          `> ⛔ **Bash ${event.action === "deny" ? "denied" : "needs approval"}:** \`${event.command}\` (${event.reason})`,
+        );
+        break;
+      case "error":
+        blocks.push(`> **Error:** ${event.message}`);
+        break;
+      case "usage_progress":
+        break;
+    }
+  }
+  return blocks.join("\n\n");
+}
+
+function parsePhase(name: string): PhaseName {
+  const phase = PhaseNameSchema.options.find((p) => p === name);
+  if (!phase) {
+    throw new WreckitError(
+      `Unknown phase '${name}'. Valid phases: ${PhaseNameSchema.options.join(", ")}`,
+      ErrorCodes.PHASE_VALIDATION,
+    );
+  }
+  return phase;
+}
+
+function transcriptName(file: TranscriptFile): string {
+  return `${file.phase}-${file.run}`;
+}
+
+export async function transcriptCommand(
+  id: string,
+  options: TranscriptOptions,
+  logger: Logger,
+): Promise<void> {
+  const root = findRootFromOptions(options);
+
+  const phase = options.phase ? parsePhase(options.phase) : undefined;
+
+  try {
+    await readItem(getItemDir(root, id));
+  } catch (err) {
+    if (err instanceof FileNotFoundError) {
+      throw new FileNotFoundError(`Item not found: ${id}`);
+    }
+    throw err;
+  }
+
+  const files = await listTranscripts(root, id, phase);
+  if (files.length === 0) {
+    logger.info(
+      `No transcripts recorded for ${id}${phase ? ` in ${phase}` : ""}`,
+    );
+    return;
+  }
+
+  const transcripts = await Promise.all(
+    files.map(async (file) => ({
+      file,
+      entries: await readTranscript(file.path),
+    })),
+  );
+
+  if (options.json) {
+    logger.json(
+      transcripts.map(({ file, entries }) => ({
+        phase: file.phase,
+        run: file.run,
+        entries,
+      })),
+    );
+    return;
+  }
+
+  if (options.markdown || options.output) {
+    const markdown = [
+      `# Transcript: ${id}`,
+      ...transcripts.map(
+        ({ file, entries }) =>
+          `## ${transcriptName(file)}\n\n${transcriptToMarkdown(entries)}`,
+      ),
+    ].join("\n\n");
+    if (options.output) {
+      const outputPath = path.resolve(
+        options.cwd ?? process.cwd(),
+        options.output,
+      );
+      await fs.writeFile(outputPath, markdown + "\n", "utf-8");
+      logger.info(`Wrote transcript to ${outputPath}`);
+    } else {
+      logger.info(markdown);
+    }
+    return;
+  }
+
+  for (const { file, entries } of transcripts) {
+    logger.info(`━━━ ${transcriptName(file)} ━━━`);
+    for (const line of formatTranscript(entries)) {
+      logger.info(line);
+    }
+    logger.info("");
+  }
+}
@@ -7,6 +7,18 @@ import {
   type AgentConfigUnion,
   type SkillConfig,
   type StoryScopeConfig,
+  type PipelineConfig,
+  type PhaseName,
+  type GateMode,
+  type PhaseSettings,
+  type PhaseAgent,
+  type ParallelStoriesConfig,
+  type DocumentConfig,
+  type ModelPrice,
+  type BudgetConfig,
+  type StallDetectionConfig,
+  type PathPolicy,
+  type BashPolicyConfig,
 } from "./schemas";
 import {
   getWreckitDir,
@@ -37,6 +49,8 @@ export interface ConfigResolved {
   branch_prefix: string;
   merge_mode: "pr" | "direct";
   agent: AgentConfigUnion;
+  // Agents tried after `agent` on transient failures (see src/agent/fallback.ts)
+  agent_fallbacks?: AgentConfigUnion[];
   max_iterations: number;
   timeout_seconds: number;
   pr_checks: PrChecksResolved;
@@ -47,6 +61,36 @@ export interface ConfigResolved {
   doctor?: import("./schemas").DoctorConfig;
   // Add optional story scope configuration (Item 084)
   story_scope?: StoryScopeConfig;
+  // Named workflow pipelines (see src/domain/pipeline.ts)
+  pipelines?: Record<string, PipelineConfig>;
+  default_pipeline?: string;
+  // Approval gates keyed by phase (see src/domain/gates.ts)
+  gates?: Partial<Record<PhaseName, GateMode>>;
+  // Per-phase agent, timeout and iteration overrides
+  phases?: Partial<Record<PhaseName, PhaseSettings>>;
+  // Agent or fallback chain per phase, below `phases.<phase>.agent`
+  phase_agents?: Partial<Record<PhaseName, PhaseAgent>>;
+  // Opt-in parallel story implementation (see src/workflow/parallelStories.ts)
+  parallel_stories?: ParallelStoriesConfig;
+  // Documentation globs for the document phase (see src/domain/documentation.ts)
+  document?: DocumentConfig;
+  // Model prices in USD per million tokens (see src/domain/usage.ts)
+  prices?: Record<string, ModelPrice>;
+  // Spend caps in USD (see src/domain/budget.ts)
+  budget?: BudgetConfig;
+  // Stuck agent limits (see src/agent/stallDetector.ts)
+  stall_detection?: StallDetectionConfig;
+  // Per-phase write path policies (see src/agent/pathPolicy.ts)
+  path_policies?: Partial<Record<PhaseName, PathPolicy>>;
+  // Bash command policy (see src/agent/bashPolicy.ts)
+  bash_policy?: BashPolicyConfig;
+}
+
+export interface PhaseSettingsResolved {
+  agent: AgentConfigUnion;
+  agent_fallbacks?: AgentConfigUnion[];
+  timeout_seconds: number;
+  max_iterations: number;
 }
 
 export interface ConfigOverrides {
@@ -126,7 +170,10 @@ function migrateAgentConfig(agent: any): AgentConfigUnion {
 }
 
 export function mergeWithDefaults(partial: Partial<Config>): ConfigResolved {
-  const agent = migrateAgentConfig(partial.agent);
+  // A list of agents is a fallback chain led by its first entry
+  const [agent, ...agentFallbacks] = (
+    Array.isArray(partial.agent) ? partial.agent : [partial.agent]
+  ).map(migrateAgentConfig);
 
   const prChecks = partial.pr_checks
     ? {
@@ -163,6 +210,7 @@ export function mergeWithDefaults(partial: Partial<Config>): ConfigResolved {
     branch_prefix: partial.branch_prefix ?? DEFAULT_CONFIG.branch_prefix,
     merge_mode: partial.merge_mode ?? DEFAULT_CONFIG.merge_mode,
     agent,
+    agent_fallbacks: agentFallbacks.length > 0 ? agentFallbacks : undefined,
     max_iterations: partial.max_iterations ?? DEFAULT_CONFIG.max_iterations,
     timeout_seconds: partial.timeout_seconds ?? DEFAULT_CONFIG.timeout_seconds,
     pr_checks: prChecks,
@@ -170,6 +218,18 @@ export function mergeWithDefaults(partial: Partial<Config>): ConfigResolved {
     skills: partial.skills, // Add optional skills (Item 033)
     doctor: partial.doctor, // Add optional doctor (Item 038)
     story_scope: partial.story_scope, // Add optional story scope (Item 084)
+    pipelines: partial.pipelines,
+    default_pipeline: partial.default_pipeline,
+    gates: partial.gates,
+    phases: partial.phases,
+    phase_agents: partial.phase_agents,
+    parallel_stories: partial.parallel_stories,
+    document: partial.document,
+    prices: partial.prices,
+    budget: partial.budget,
+    stall_detection: partial.stall_detection,
+    path_policies: partial.path_policies,
+    bash_policy: partial.bash_policy,
   };
 }
 
@@ -234,6 +294,8 @@ export function applyOverrides(
   logger?: { info: (msg: string) => void },
 ): ConfigResolved {
   let agent = config.agent;
+  // An agent chosen on the command line replaces chains and phase routing
+  const agentOverridden = !!overrides.sandbox || !!overrides.agentKind;
 
   // Apply sandbox mode first (highest priority)
   if (overrides.sandbox) {
@@ -290,6 +352,7 @@ export function applyOverrides(
     branch_prefix: overrides.branchPrefix ?? config.branch_prefix,
     merge_mode: config.merge_mode,
     agent,
+    agent_fallbacks: agentOverridden ? undefined : config.agent_fallbacks,
     max_iterations: overrides.maxIterations ?? config.max_iterations,
     timeout_seconds: overrides.timeoutSeconds ?? config.timeout_seconds,
     pr_checks: config.pr_checks,
@@ -297,9 +360,109 @@ export function applyOverrides(
     skills: config.skills,
     doctor: config.doctor,
     story_scope: config.story_scope,
+    pipelines: config.pipelines,
+    default_pipeline: config.default_pipeline,
+    gates: config.gates,
+    phases: stripOverriddenPhaseSettings(config.phases, overrides),
+    phase_agents: agentOverridden ? undefined : config.phase_agents,
+    parallel_stories: config.parallel_stories,
+    document: config.document,
+    prices: config.prices,
+    budget: config.budget,
+    stall_detection: config.stall_detection,
+    path_policies: config.path_policies,
+    bash_policy: config.bash_policy,
   };
 }
 
+/**
+ * CLI overrides apply to every phase, so they replace the matching
+ * per-phase settings rather than being shadowed by them.
+ */
+function stripOverriddenPhaseSettings(
+  phases: ConfigResolved["phases"],
+  overrides: ConfigOverrides,
+): ConfigResolved["phases"] {
+  if (!phases) {
+    return phases;
+  }
+  const agentOverridden = !!overrides.sandbox || !!overrides.agentKind;
+  const result: NonNullable<ConfigResolved["phases"]> = {};
+  for (const [phase, settings] of Object.entries(phases) as [
+    PhaseName,
+    PhaseSettings,
+  ][]) {
+    const next: PhaseSettings = { ...settings };
+    if (agentOverridden) delete next.agent;
+    if (overrides.timeoutSeconds !== undefined) delete next.timeout_seconds;
+    if (overrides.maxIterations !== undefined) delete next.max_iterations;
+    result[phase] = next;
+  }
+  return result;
+}
+
+/**
+ * Research, reproduce, plan, tests and document retry on validation
+ * failure; the global max_iterations only bounds the implement loop.
+ */
+const DEFAULT_VALIDATION_ATTEMPTS = 3;
+
+/**
+ * Effective agent and limits for a phase. Entries under `phases.<phase>`
+ * override the global `agent` (or agent chain), `timeout_seconds` and
+ * `max_iterations`; `phase_agents.<phase>` sits between the two for the
+ * agent.
+ */
+export function getPhaseSettings(
+  config: ConfigResolved,
+  phase: PhaseName,
+): PhaseSettingsResolved {
+  const settings = config.phases?.[phase];
+  const defaultIterations =
+    phase === "research" ||
+    phase === "reproduce" ||
+    phase === "plan" ||
+    phase === "tests" ||
+    phase === "document"
+      ? DEFAULT_VALIDATION_ATTEMPTS
+      : config.max_iterations;
+  // A phase's own agent or chain replaces the global chain
+  const override = settings?.agent ?? config.phase_agents?.[phase];
+  const [agent, ...agentFallbacks] = override
+    ? [override].flat()
+    : [config.agent, ...(config.agent_fallbacks ?? [])];
+  return {
+    agent,
+    ...(agentFallbacks.length > 0 && { agent_fallbacks: agentFallbacks }),
+    timeout_seconds: settings?.timeout_seconds ?? config.timeout_seconds,
+    max_iterations: settings?.max_iterations ?? defaultIterations,
+  };
+}
+
+/**
+ * Merge `agent` from config.local.json into the base config's agent. Local
+ * settings such as `env` apply to the first agent of a fallback chain; a
+ * local chain replaces the base agent entirely.
+ */
+function mergeLocalAgent(base: Config["agent"] | undefined, local: any): any {
+  if (Array.isArray(local)) {
+    return local;
+  }
+  const merge = (agent: any) => ({
+    ...agent,
+    ...local,
+    env: {
+      ...agent?.env,
+      ...local.env,
+    },
+  });
+  if (Array.isArray(base)) {
+    const [primary, ...rest] = base;
+    return [merge(primary), ...rest];
+  }
+  return merge(base);
+}
+
 export async function loadConfig(
   root: string,
   overrides?: ConfigOverrides,
@@ -330,14 +493,7 @@ export async function loadConfig(
     
     // Deep merge local agent settings if present
     if (localData.agent) {
-      partial.agent = {
-        ...(partial.agent as any),
-        ...localData.agent,
-        env: {
-          ...(partial.agent as any)?.env,
-          ...localData.agent.env,
-        },
-      };
+      partial.agent = mergeLocalAgent(partial.agent, localData.agent);
     }
     
     // Merge other top-level fields
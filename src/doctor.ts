@@ -32,7 +32,13 @@ import { pathExists, checkPathAccess } from "./fs/util";
 import { scanItems } from "./commands/status";
 import { initPromptTemplates } from "./prompts";
 import { writeItem, writeIndex, readItem, clearBatchProgress } from "./fs/json";
-import { validateStoryQuality } from "./domain/validation";
+import {
+  validateStoryQuality,
+  validateStoryDependencies,
+  findStoryDependencyCycles,
+  findMissingStoryDependencies,
+} from "./domain/validation";
+import { isParkedState } from "./domain/states";
 import {
   FileNotFoundError,
   InvalidJsonError,
@@ -114,6 +120,30 @@ function findMissingDependencies(
   return missing;
 }
 
+/**
+ * Find dependencies on blocked, paused or wontfix items.
+ * Done and actionable items are not reported; parked items never satisfy
+ * a dependency until resumed and completed.
+ */
+function findParkedDependencies(
+  items: Item[],
+): Array<{ itemId: string; dependency: Item }> {
+  const parked: Array<{ itemId: string; dependency: Item }> = [];
+  const itemMap = new Map(items.map((i) => [i.id, i]));
+
+  for (const item of items) {
+    if (item.state === "done" || item.state === "wontfix") continue;
+    for (const depId of item.depends_on ?? []) {
+      const dependency = itemMap.get(depId);
+      if (dependency && isParkedState(dependency.state)) {
+        parked.push({ itemId: item.id, dependency });
+      }
+    }
+  }
+
+  return parked;
+}
+
 async function diagnoseDependencies(root: string, logger: Logger): Promise<Diagnostic[]> {
   const diagnostics: Diagnostic[] = [];
   const itemsDir = getItemsDir(root);
@@ -179,6 +209,20 @@ async function diagnoseDependencies(root: string, logger: Logger): Promise<Diagn
     });
   }
 
+  // Check for dependencies on parked items
+  for (const { itemId, dependency } of findParkedDependencies(items)) {
+    const wontfix = dependency.state === "wontfix";
+    diagnostics.push({
+      itemId,
+      severity: wontfix ? "warning" : "info",
+      code: wontfix ? "DEPENDS_ON_WONTFIX" : "DEPENDS_ON_PARKED",
+      message: wontfix
+        ? `Depends on ${dependency.id}, which is marked wontfix and will never complete`
+        : `Waiting on ${dependency.state} item ${dependency.id}${dependency.parked_reason ? ` (${dependency.parked_reason})` : ""}`,
+      fixable: false,
+    });
+  }
+
   return diagnostics;
 }
 
@@ -436,14 +480,41 @@ async function diagnoseItem(
           fixable: false,
         });
       } else {
+        // Story dependency graph, reported like item-level dependencies
+        const stories = prdResult.data.user_stories;
+        const dependencyErrors = new Set(validateStoryDependencies(stories));
+        for (const cycle of findStoryDependencyCycles(stories)) {
+          diagnostics.push({
+            itemId,
+            severity: "error",
+            code: "STORY_CIRCULAR_DEPENDENCY",
+            message: `Circular story dependency detected: ${cycle.join(" -> ")}`,
+            fixable: false,
+          });
+        }
+        for (const { storyId, missingDep } of findMissingStoryDependencies(
+          stories,
+        )) {
+          diagnostics.push({
+            itemId,
+            severity: "warning",
+            code: "STORY_MISSING_DEPENDENCY",
+            message: `Story ${storyId} depends on non-existent story: ${missingDep}`,
+            fixable: false,
+          });
+        }
+
         // Deep PRD validation (Spec 010 Gap 1: Deep PRD Validation)
         const storyQuality = validateStoryQuality(prdResult.data);
-        if (!storyQuality.valid) {
+        const qualityErrors = storyQuality.errors.filter(
+          (e) => !dependencyErrors.has(e),
+        );
+        if (qualityErrors.length > 0) {
           diagnostics.push({
             itemId,
             severity: "warning",
             code: "POOR_STORY_QUALITY",
-            message: `prd.json story quality issues: ${storyQuality.errors.join("; ")}`,
+            message: `prd.json story quality issues: ${qualityErrors.join("; ")}`,
             fixable: false,
           });
         }
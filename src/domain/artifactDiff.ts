@@ -0,0 +1,208 @@
+import type { Prd, Story } from "../schemas";
+
+export interface DiffLine {
+  op: " " | "+" | "-";
+  text: string;
+}
+
+/**
+ * Line diff of two texts based on their longest common subsequence.
+ */
+export function diffLines(before: string, after: string): DiffLine[] {
+  const a = before.split("\n");
+  const b = after.split("\n");
+
+  // lcs[i][j] = length of the LCS of a[i..] and b[j..]
+  const lcs: number[][] = Array.from({ length: a.length + 1 }, () =>
+    new Array<number>(b.length + 1).fill(0),
+  );
+  for (let i = a.length - 1; i >= 0; i--) {
+    for (let j = b.length - 1; j >= 0; j--) {
+      lcs[i][j] =
+        a[i] === b[j]
+          ? lcs[i + 1][j + 1] + 1
+          : Math.max(lcs[i + 1][j], lcs[i][j + 1]);
+    }
+  }
+
+  const lines: DiffLine[] = [];
+  let i = 0;
+  let j = 0;
+  while (i < a.length && j < b.length) {
+    if (a[i] === b[j]) {
+      lines.push({ op: " ", text: a[i] });
+      i++;
+      j++;
+    } else if (lcs[i + 1][j] >= lcs[i][j + 1]) {
+      lines.push({ op: "-", text: a[i++] });
+    } else {
+      lines.push({ op: "+", text: b[j++] });
+    }
+  }
+  while (i < a.length) lines.push({ op: "-", text: a[i++] });
+  while (j < b.length) lines.push({ op: "+", text: b[j++] });
+  return lines;
+}
+
+/**
+ * Render a line diff as unified-diff hunks with `context` unchanged lines
+ * around each change.
+ *
+ * @returns Output lines, empty if the texts are identical
+ */
+export function formatUnifiedDiff(
+  lines: DiffLine[],
+  fromLabel: string,
+  toLabel: string,
+  context = 3,
+): string[] {
+  const changed = lines
+    .map((line, index) => (line.op === " " ? -1 : index))
+    .filter((index) => index !== -1);
+  if (changed.length === 0) {
+    return [];
+  }
+
+  // Group changes whose context windows overlap into hunks
+  const ranges: Array<[number, number]> = [];
+  for (const index of changed) {
+    const start = Math.max(0, index - context);
+    const end = Math.min(lines.length - 1, index + context);
+    const last = ranges.at(-1);
+    if (last && start <= last[1] + 1) {
+      last[1] = end;
+    } else {
+      ranges.push([start, end]);
+    }
+  }
+
+  // Line numbers in the old and new text at each diff position
+  const oldLine: number[] = [];
+  const newLine: number[] = [];
+  let o = 1;
+  let n = 1;
+  for (const line of lines) {
+    oldLine.push(o);
+    newLine.push(n);
+    if (line.op !== "+") o++;
+    if (line.op !== "-") n++;
+  }
+
+  const output = [`--- ${fromLabel}`, `+++ ${toLabel}`];
+  for (const [start, end] of ranges) {
+    const hunk = lines.slice(start, end + 1);
+    const oldCount = hunk.filter((l) => l.op !== "+").length;
+    const newCount = hunk.filter((l) => l.op !== "-").length;
+    output.push(
+      `@@ -${oldLine[start]},${oldCount} +${newLine[start]},${newCount} @@`,
+    );
+    for (const line of hunk) {
+      output.push(`${line.op}${line.text}`);
+    }
+  }
+  return output;
+}
+
+export interface StoryFieldChange {
+  field: "title" | "priority" | "depends_on" | "notes";
+  from: string;
+  to: string;
+}
+
+export interface StoryChange {
+  id: string;
+  title: string;
+  fields: StoryFieldChange[];
+  criteriaAdded: string[];
+  criteriaRemoved: string[];
+}
+
+export interface PrdDiff {
+  added: Story[];
+  removed: Story[];
+  changed: StoryChange[];
+}
+
+function describeField(story: Story, field: StoryFieldChange["field"]): string {
+  switch (field) {
+    case "title":
+      return story.title;
+    case "priority":
+      return String(story.priority);
+    case "depends_on":
+      return (story.depends_on ?? []).join(", ") || "(none)";
+    case "notes":
+      return story.notes;
+  }
+}
+
+/**
+ * Story-level diff of two PRDs, matching stories by id. Story status is
+ * ignored since it reflects implementation progress, not the plan.
+ */
+export function diffPrd(before: Prd, after: Prd): PrdDiff {
+  const beforeById = new Map(before.user_stories.map((s) => [s.id, s]));
+  const afterById = new Map(after.user_stories.map((s) => [s.id, s]));
+
+  const added = after.user_stories.filter((s) => !beforeById.has(s.id));
+  const removed = before.user_stories.filter((s) => !afterById.has(s.id));
+
+  const changed: StoryChange[] = [];
+  for (const next of after.user_stories) {
+    const prev = beforeById.get(next.id);
+    if (!prev) continue;
+
+    const fields: StoryFieldChange[] = [];
+    for (const field of ["title", "priority", "depends_on", "notes"] as const) {
+      const from = describeField(prev, field);
+      const to = describeField(next, field);
+      if (from !== to) {
+        fields.push({ field, from, to });
+      }
+    }
+    const criteriaAdded = next.acceptance_criteria.filter(
+      (c) => !prev.acceptance_criteria.includes(c),
+    );
+    const criteriaRemoved = prev.acceptance_criteria.filter(
+      (c) => !next.acceptance_criteria.includes(c),
+    );
+
+    if (fields.length || criteriaAdded.length || criteriaRemoved.length) {
+      changed.push({
+        id: next.id,
+        title: next.title,
+        fields,
+        criteriaAdded,
+        criteriaRemoved,
+      });
+    }
+  }
+
+  return { added, removed, changed };
+}
+
+export function formatPrdDiff(diff: PrdDiff): string[] {
+  const output: string[] = [];
+  for (const story of diff.added) {
+    output.push(`+ ${story.id}: ${story.title}`);
+    for (const criterion of story.acceptance_criteria) {
+      output.push(`    + ${criterion}`);
+    }
+  }
+  for (const story of diff.removed) {
+    output.push(`- ${story.id}: ${story.title}`);
+  }
+  for (const change of diff.changed) {
+    output.push(`~ ${change.id}: ${change.title}`);
+    for (const field of change.fields) {
+      output.push(`    ${field.field}: ${field.from} → ${field.to}`);
+    }
+    for (const criterion of change.criteriaRemoved) {
+      output.push(`    - ${criterion}`);
+    }
+    for (const criterion of change.criteriaAdded) {
+      output.push(`    + ${criterion}`);
+    }
+  }
+  return output;
+}
@@ -0,0 +1,52 @@
+import type { ItemKind, TestOutcome } from "../schemas";
+
+export interface BehaviorComparison {
+  /** Tests that passed in the baseline and fail now */
+  broken: string[];
+  /** Tests that passed in the baseline and were not reported at all */
+  missing: string[];
+}
+
+/**
+ * Refactor items must preserve behavior: every story is checked against
+ * the test results recorded before implementation started.
+ */
+export function requiresBehaviorCheck(kind: ItemKind | undefined): boolean {
+  return kind === "refactor";
+}
+
+/**
+ * Compare current test outcomes with the baseline. A test reported more
+ * than once counts as failed if any of its runs failed.
+ */
+export function compareWithBaseline(
+  baseline: TestOutcome[],
+  current: TestOutcome[],
+): BehaviorComparison {
+  const statusByName = (outcomes: TestOutcome[]) => {
+    const statuses = new Map<string, TestOutcome["status"]>();
+    for (const { name, status } of outcomes) {
+      if (statuses.get(name) !== "failed") {
+        statuses.set(name, status);
+      }
+    }
+    return statuses;
+  };
+
+  const before = statusByName(baseline);
+  const after = statusByName(current);
+  const broken: string[] = [];
+  const missing: string[] = [];
+
+  for (const [name, status] of before) {
+    if (status !== "passed") continue;
+    const now = after.get(name);
+    if (now === "failed") {
+      broken.push(name);
+    } else if (now === undefined) {
+      missing.push(name);
+    }
+  }
+
+  return { broken, missing };
+}
@@ -0,0 +1,53 @@
+import type { BudgetConfig } from "../schemas";
+
+export type BudgetCap = "item" | "phase" | "session";
+
+/**
+ * USD spent so far in each capped scope.
+ */
+export interface BudgetSpend {
+  item: number;
+  phase: number;
+  session: number;
+}
+
+export interface BudgetBreach {
+  cap: BudgetCap;
+  limit_usd: number;
+  spent_usd: number;
+}
+
+const CAP_KEYS: Array<[BudgetCap, keyof BudgetConfig]> = [
+  ["phase", "per_phase_usd"],
+  ["item", "per_item_usd"],
+  ["session", "per_session_usd"],
+];
+
+/**
+ * The first cap that the spend has reached, narrowest scope first.
+ *
+ * @returns null when no cap is configured or none is reached
+ */
+export function findBudgetBreach(
+  budget: BudgetConfig | undefined,
+  spend: BudgetSpend,
+): BudgetBreach | null {
+  for (const [cap, key] of CAP_KEYS) {
+    const limit = budget?.[key];
+    if (limit !== undefined && spend[cap] >= limit) {
+      return { cap, limit_usd: limit, spent_usd: spend[cap] };
+    }
+  }
+  return null;
+}
+
+/**
+ * Whether the orchestrator should stop starting new items.
+ */
+export function isSessionBudgetReached(
+  budget: BudgetConfig | undefined,
+  sessionCostUsd: number,
+): boolean {
+  const limit = budget?.per_session_usd;
+  return limit !== undefined && sessionCostUsd >= limit;
+}
@@ -0,0 +1,25 @@
+import { DocumentConfigSchema } from "../schemas";
+import { matchesAnyGlob } from "./globs";
+
+/**
+ * Documentation globs used when `document.paths` is not configured.
+ */
+export const DEFAULT_DOCUMENTATION_PATHS: string[] =
+  DocumentConfigSchema.parse({}).paths;
+
+/**
+ * Changed paths the document phase is not allowed to touch. wreckit's own
+ * item files under `.wreckit/` are always allowed.
+ *
+ * @param changedPaths - Repo-relative paths changed by the agent
+ * @param patterns - Documentation globs from `document.paths`
+ */
+export function findNonDocumentationPaths(
+  changedPaths: string[],
+  patterns: string[],
+): string[] {
+  return changedPaths.filter(
+    (changed) =>
+      !changed.startsWith(".wreckit/") && !matchesAnyGlob(changed, patterns),
+  );
+}
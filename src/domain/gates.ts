@@ -0,0 +1,106 @@
+import type { Approval, GateMode, Item, PhaseName } from "../schemas";
+
+/**
+ * The subset of config needed to resolve approval gates.
+ */
+export interface GateSource {
+  gates?: Partial<Record<PhaseName, GateMode>>;
+}
+
+export function getGateMode(source: GateSource, phase: PhaseName): GateMode {
+  return source.gates?.[phase] ?? "auto";
+}
+
+/**
+ * Returns true if a phase's output must be approved by a human before
+ * the item may continue.
+ */
+export function requiresApproval(source: GateSource, phase: PhaseName): boolean {
+  return getGateMode(source, phase) === "human";
+}
+
+export function isAwaitingApproval(
+  item: Pick<Item, "pending_approval">,
+): boolean {
+  return !!item.pending_approval;
+}
+
+/**
+ * Rejections of a phase recorded since its last approval, oldest first.
+ * These are the comments the next run of the phase must address.
+ */
+export function getOutstandingRejections(
+  item: Pick<Item, "approvals">,
+  phase: string,
+): Approval[] {
+  const decisions = (item.approvals ?? []).filter((a) => a.phase === phase);
+  let lastApproved = -1;
+  decisions.forEach((a, index) => {
+    if (a.decision === "approved") lastApproved = index;
+  });
+  return decisions
+    .slice(lastApproved + 1)
+    .filter((a) => a.decision === "rejected");
+}
+
+/**
+ * Render outstanding rejection comments for a prompt.
+ *
+ * @returns Markdown list of comments, or undefined if there are none
+ */
+export function formatReviewFeedback(
+  item: Pick<Item, "approvals">,
+  phase: string,
+): string | undefined {
+  const rejections = getOutstandingRejections(item, phase);
+  if (rejections.length === 0) {
+    return undefined;
+  }
+  return rejections
+    .map((r) => `- ${r.comment ?? "(no comment)"} — ${r.by}, ${r.at}`)
+    .join("\n");
+}
+
+export interface ApprovalDecisionOptions {
+  by: string;
+  comment?: string | null;
+  now?: string;
+}
+
+export type ApprovalDecisionResult =
+  | { nextItem: Item; phase: PhaseName; error?: never }
+  | { nextItem?: never; phase?: never; error: string };
+
+/**
+ * Pure function that records an approval or rejection of the item's
+ * pending gate and clears it. Rejections require a comment.
+ */
+export function applyApprovalDecision(
+  item: Readonly<Item>,
+  decision: Approval["decision"],
+  options: ApprovalDecisionOptions,
+): ApprovalDecisionResult {
+  const phase = item.pending_approval;
+  if (!phase) {
+    return { error: `Item ${item.id} is not awaiting approval` };
+  }
+
+  const comment = options.comment?.trim() || null;
+  if (decision === "rejected" && !comment) {
+    return { error: "A comment is required when rejecting" };
+  }
+
+  const now = options.now ?? new Date().toISOString();
+  return {
+    phase,
+    nextItem: {
+      ...item,
+      pending_approval: null,
+      approvals: [
+        ...(item.approvals ?? []),
+        { phase, decision, by: options.by, at: now, comment },
+      ],
+      updated_at: now,
+    },
+  };
+}
@@ -0,0 +1,47 @@
+/**
+ * Convert a glob pattern into a regular expression over repo-relative,
+ * forward-slash paths.
+ *
+ * - `**` matches any number of path segments (including none)
+ * - `*` matches within a single segment
+ * - `?` matches a single character other than `/`
+ *
+ * A pattern without a `/` matches the file name at any depth, like
+ * `.gitignore` patterns, so `*.md` matches `docs/guide.md`.
+ */
+export function globToRegExp(pattern: string): RegExp {
+  const normalized = pattern.replace(/^\.?\/+/, "");
+  const anchored = normalized.includes("/")
+    ? normalized
+    : `**/${normalized}`;
+
+  let source = "";
+  for (let i = 0; i < anchored.length; i++) {
+    const char = anchored[i];
+    if (char === "*" && anchored[i + 1] === "*") {
+      if (anchored[i + 2] === "/") {
+        // "**/" also matches no directory at all
+        source += "(?:.*/)?";
+        i += 2;
+      } else {
+        source += ".*";
+        i += 1;
+      }
+    } else if (char === "*") {
+      source += "[^/]*";
+    } else if (char === "?") {
+      source += "[^/]";
+    } else {
+      source += char.replace(/[.+^${}()|[\]\\]/g, "\\$&");
+    }
+  }
+  return new RegExp(`^${source}$`);
+}
+
+/**
+ * Whether a repo-relative path matches any of the glob patterns.
+ */
+export function matchesAnyGlob(filePath: string, patterns: string[]): boolean {
+  const normalized = filePath.replace(/\\/g, "/").replace(/^\.?\/+/, "");
+  return patterns.some((pattern) => globToRegExp(pattern).test(normalized));
+}
@@ -1,8 +1,13 @@
 import * as fs from "node:fs/promises";
 import * as path from "node:path";
-import type { Item, PriorityHint } from "../schemas";
+import type { Item, ItemKind, PriorityHint } from "../schemas";
 import { getItemsDir, getItemDir } from "../fs/paths";
 import { writeJsonPretty } from "../fs/json";
+import {
+  getKindPipelineName,
+  resolvePipeline,
+  type PipelineSource,
+} from "./pipeline";
 
 export interface ParsedIdea {
   /** Short, human-readable summary of the idea */
@@ -237,7 +242,12 @@ export async function allocateItemId(
   return { id, dir, number: nextNumber };
 }
 
-export function createItemFromIdea(id: string, idea: ParsedIdea): Item {
+export function createItemFromIdea(
+  id: string,
+  idea: ParsedIdea,
+  pipeline?: string,
+  kind?: ItemKind,
+): Item {
   const now = new Date().toISOString();
   const overview = buildOverviewFromParsedIdea(idea);
 
@@ -268,6 +278,10 @@ export function createItemFromIdea(id: string, idea: ParsedIdea): Item {
     // Dependency management and campaign grouping
     depends_on: idea.dependsOn,
     campaign: idea.campaign,
+
+    // Workflow pipeline this item follows
+    pipeline,
+    kind,
   };
 }
 
@@ -292,13 +306,35 @@ async function getAllKnownItems(root: string): Promise<Map<string, string>> {
   return slugToId;
 }
 
+export interface PersistItemsOptions {
+  /**
+   * Pipeline to create items under (defaults to the kind's pipeline, then
+   * config.default_pipeline)
+   */
+  pipeline?: string;
+  /** Kind of work for the new items */
+  kind?: ItemKind;
+  /**
+   * Configured pipelines and default_pipeline, passed in by the command
+   * layer (built-in pipelines only when unset)
+   */
+  config?: PipelineSource;
+}
+
 export async function persistItems(
   root: string,
   ideas: ParsedIdea[],
+  options: PersistItemsOptions = {},
 ): Promise<{ created: Item[]; skipped: string[] }> {
   const created: Item[] = [];
   const skipped: string[] = [];
 
+  // Resolve up front so an unknown pipeline fails before anything is written
+  const pipeline = resolvePipeline(
+    options.config ?? {},
+    options.pipeline ?? getKindPipelineName(options.kind),
+  ).name;
+
   // 1. Build a map of all known slugs to IDs (existing + new)
   const slugToIdMap = await getAllKnownItems(root);
   const allAllocatedIds = new Set<string>(slugToIdMap.values());
@@ -348,7 +384,7 @@ export async function persistItems(
       });
     }
 
-    const item = createItemFromIdea(id, idea);
+    const item = createItemFromIdea(id, idea, pipeline, options.kind);
     await fs.mkdir(dir, { recursive: true });
     await writeJsonPretty(path.join(dir, "item.json"), item);
 
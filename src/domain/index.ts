@@ -2,22 +2,62 @@ export {
   WORKFLOW_STATES,
   getNextState,
   getAllowedNextStates,
+  getAllowedPreviousStates,
   isTerminalState,
+  isParkedState,
+  isActionableState,
   getStateIndex,
 } from "./states";
 
+export {
+  type Pipeline,
+  type PipelineStage,
+  type PipelineSource,
+  DEFAULT_PIPELINE,
+  DEFAULT_PIPELINE_NAME,
+  BUG_PIPELINE,
+  BUG_PIPELINE_NAME,
+  SPIKE_PIPELINE,
+  SPIKE_PIPELINE_NAME,
+  TDD_PIPELINE,
+  TDD_PIPELINE_NAME,
+  getKindPipelineName,
+  PHASE_TARGET_STATES,
+  PARKED_STATES,
+  validatePipelineConfig,
+  buildPipeline,
+  resolvePipeline,
+  getItemPipeline,
+  getPipelineStates,
+  getStageForState,
+  getStageForPhase,
+  getPhaseEntryState,
+  getNextPhaseInPipeline,
+} from "./pipeline";
+
 export {
   type ValidationContext,
   type ValidationResult,
   canEnterResearched,
+  canEnterReproduced,
+  canLeaveBugFix,
+  canEnterDoneWithReport,
+  canEnterTestsWritten,
   canEnterPlanned,
   canEnterImplementing,
   canEnterCritique,
+  canEnterDocumented,
   canEnterInPr,
   canEnterDone,
   validateTransition,
+  validateRegression,
   allStoriesDone,
   hasPendingStories,
+  findStoryDependencyCycles,
+  findMissingStoryDependencies,
+  validateStoryDependencies,
+  getNextStory,
+  isStoryReady,
   validateResearchQuality,
   type ResearchQualityOptions,
   type ResearchQualityResult,
@@ -26,6 +66,91 @@ export {
 
 export {
   type TransitionResult,
+  type RecordedTransitionResult,
   type TransitionError,
   applyStateTransition,
+  createTransitionEntry,
+  createAdvanceEntries,
+  applyRegression,
+  type RegressionOptions,
+  applyPark,
+  applyResume,
+  type ParkOptions,
 } from "./transitions";
+
+export {
+  type GateSource,
+  type ApprovalDecisionOptions,
+  type ApprovalDecisionResult,
+  getGateMode,
+  requiresApproval,
+  isAwaitingApproval,
+  getOutstandingRejections,
+  formatReviewFeedback,
+  applyApprovalDecision,
+} from "./gates";
+
+export {
+  type DiffLine,
+  type PrdDiff,
+  type StoryChange,
+  type StoryFieldChange,
+  diffLines,
+  formatUnifiedDiff,
+  diffPrd,
+  formatPrdDiff,
+} from "./artifactDiff";
+
+export {
+  requiresReproduction,
+  recordTestRun,
+  isReproduced,
+  isFixVerified,
+} from "./reproduction";
+
+export {
+  type BehaviorComparison,
+  requiresBehaviorCheck,
+  compareWithBaseline,
+} from "./behavior";
+
+export {
+  type ReportValidationResult,
+  REPORT_SECTIONS,
+  requiresReport,
+  validateSpikeReport,
+  parseFollowUpIdeas,
+} from "./spike";
+
+export {
+  getStoriesWithoutTests,
+  checkTestsFailBeforeImplementation,
+  formatStoryTests,
+} from "./storyTests";
+
+export { globToRegExp, matchesAnyGlob } from "./globs";
+
+export {
+  DEFAULT_DOCUMENTATION_PATHS,
+  findNonDocumentationPaths,
+} from "./documentation";
+
+export {
+  emptyUsageTotals,
+  findModelPrice,
+  computeCost,
+  addRunUsage,
+  sumUsageTotals,
+  getItemUsage,
+  getUnpricedModels,
+  formatTokens,
+  formatUsage,
+} from "./usage";
+
+export {
+  findBudgetBreach,
+  isSessionBudgetReached,
+  type BudgetCap,
+  type BudgetSpend,
+  type BudgetBreach,
+} from "./budget";
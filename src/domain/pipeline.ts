@@ -0,0 +1,343 @@
+import type {
+  Item,
+  ItemKind,
+  PhaseName,
+  PipelineConfig,
+  WorkflowState,
+} from "../schemas";
+import { ConfigError } from "../errors";
+
+/**
+ * A resolved pipeline stage.
+ * `phase` is null only for the entry stage.
+ */
+export interface PipelineStage {
+  state: WorkflowState;
+  phase: PhaseName | null;
+  prompt?: string;
+  allowedTools?: string[];
+}
+
+export interface Pipeline {
+  name: string;
+  stages: PipelineStage[];
+}
+
+/**
+ * The subset of config needed to resolve pipelines.
+ * Kept structural so domain code does not depend on config loading.
+ */
+export interface PipelineSource {
+  pipelines?: Record<string, PipelineConfig>;
+  default_pipeline?: string;
+}
+
+/**
+ * The state each phase runner leaves an item in on success.
+ * A pipeline stage must pair a phase with its target state.
+ */
+export const PHASE_TARGET_STATES: Record<PhaseName, WorkflowState> = {
+  research: "researched",
+  reproduce: "reproduced",
+  plan: "planned",
+  tests: "tests_written",
+  implement: "implementing",
+  critique: "critique",
+  document: "documented",
+  pr: "in_pr",
+  complete: "done",
+};
+
+/**
+ * States that take an item out of its pipeline without deleting it.
+ * They can never appear as pipeline stages.
+ */
+export const PARKED_STATES: WorkflowState[] = ["blocked", "paused", "wontfix"];
+
+export const DEFAULT_PIPELINE_NAME = "default";
+
+/**
+ * The built-in pipeline: idea → researched → planned → implementing → critique → in_pr → done
+ */
+export const DEFAULT_PIPELINE: Pipeline = {
+  name: DEFAULT_PIPELINE_NAME,
+  stages: [
+    { state: "idea", phase: null },
+    { state: "researched", phase: "research" },
+    { state: "planned", phase: "plan" },
+    { state: "implementing", phase: "implement" },
+    { state: "critique", phase: "critique" },
+    { state: "in_pr", phase: "pr" },
+    { state: "done", phase: "complete" },
+  ],
+};
+
+export const BUG_PIPELINE_NAME = "bug";
+
+/**
+ * Built-in pipeline for bug items: a failing test must reproduce the bug
+ * before the fix is planned.
+ * idea → researched → reproduced → planned → implementing → critique → in_pr → done
+ */
+export const BUG_PIPELINE: Pipeline = {
+  name: BUG_PIPELINE_NAME,
+  stages: [
+    { state: "idea", phase: null },
+    { state: "researched", phase: "research" },
+    { state: "reproduced", phase: "reproduce" },
+    { state: "planned", phase: "plan" },
+    { state: "implementing", phase: "implement" },
+    { state: "critique", phase: "critique" },
+    { state: "in_pr", phase: "pr" },
+    { state: "done", phase: "complete" },
+  ],
+};
+
+export const SPIKE_PIPELINE_NAME = "spike";
+
+/**
+ * Built-in pipeline for spike items: research answers the question in a
+ * report, and the item completes without a branch or PR.
+ * idea → researched → done
+ */
+export const SPIKE_PIPELINE: Pipeline = {
+  name: SPIKE_PIPELINE_NAME,
+  stages: [
+    { state: "idea", phase: null },
+    { state: "researched", phase: "research" },
+    { state: "done", phase: "complete" },
+  ],
+};
+
+export const TDD_PIPELINE_NAME = "tdd";
+
+/**
+ * Built-in pipeline that writes failing tests for every story before
+ * implementing them.
+ * idea → researched → planned → tests_written → implementing → critique → in_pr → done
+ */
+export const TDD_PIPELINE: Pipeline = {
+  name: TDD_PIPELINE_NAME,
+  stages: [
+    { state: "idea", phase: null },
+    { state: "researched", phase: "research" },
+    { state: "planned", phase: "plan" },
+    { state: "tests_written", phase: "tests" },
+    { state: "implementing", phase: "implement" },
+    { state: "critique", phase: "critique" },
+    { state: "in_pr", phase: "pr" },
+    { state: "done", phase: "complete" },
+  ],
+};
+
+const BUILT_IN_PIPELINES: Record<string, Pipeline> = {
+  [DEFAULT_PIPELINE_NAME]: DEFAULT_PIPELINE,
+  [BUG_PIPELINE_NAME]: BUG_PIPELINE,
+  [SPIKE_PIPELINE_NAME]: SPIKE_PIPELINE,
+  [TDD_PIPELINE_NAME]: TDD_PIPELINE,
+};
+
+/**
+ * The pipeline new items of a kind are created under when none is given.
+ *
+ * @returns A pipeline name, or undefined to use config.default_pipeline
+ */
+export function getKindPipelineName(kind?: ItemKind): string | undefined {
+  switch (kind) {
+    case "bug":
+      return BUG_PIPELINE_NAME;
+    case "spike":
+      return SPIKE_PIPELINE_NAME;
+    default:
+      return undefined;
+  }
+}
+
+/**
+ * Check a pipeline definition for structural problems.
+ *
+ * @returns Array of error messages (empty if valid)
+ */
+export function validatePipelineConfig(
+  name: string,
+  pipeline: PipelineConfig,
+): string[] {
+  const errors: string[] = [];
+  const stages = pipeline.stages;
+
+  if (stages.length < 2) {
+    errors.push(`Pipeline '${name}' must have at least two stages`);
+    return errors;
+  }
+
+  const first = stages[0];
+  if (first.state !== "idea") {
+    errors.push(
+      `Pipeline '${name}' must start with state 'idea' (got '${first.state}')`,
+    );
+  }
+  if (first.phase) {
+    errors.push(`Pipeline '${name}' entry stage cannot have a phase`);
+  }
+
+  const last = stages[stages.length - 1];
+  if (last.state !== "done") {
+    errors.push(
+      `Pipeline '${name}' must end with state 'done' (got '${last.state}')`,
+    );
+  }
+
+  for (const stage of stages) {
+    if (PARKED_STATES.includes(stage.state)) {
+      errors.push(
+        `Pipeline '${name}' cannot include parked state '${stage.state}'`,
+      );
+    }
+  }
+
+  const seenStates = new Set<WorkflowState>();
+  const seenPhases = new Set<PhaseName>();
+  for (const [index, stage] of stages.entries()) {
+    if (seenStates.has(stage.state)) {
+      errors.push(`Pipeline '${name}' lists state '${stage.state}' twice`);
+    }
+    seenStates.add(stage.state);
+
+    if (index === 0) continue;
+
+    if (!stage.phase) {
+      errors.push(
+        `Pipeline '${name}' stage '${stage.state}' must name a phase`,
+      );
+      continue;
+    }
+    if (seenPhases.has(stage.phase)) {
+      errors.push(`Pipeline '${name}' uses phase '${stage.phase}' twice`);
+    }
+    seenPhases.add(stage.phase);
+
+    const expected = PHASE_TARGET_STATES[stage.phase];
+    if (expected !== stage.state) {
+      errors.push(
+        `Pipeline '${name}' maps phase '${stage.phase}' to state '${stage.state}', but that phase produces '${expected}'`,
+      );
+    }
+  }
+
+  return errors;
+}
+
+/**
+ * Convert a validated pipeline definition into a resolved Pipeline.
+ *
+ * @throws ConfigError if the definition is invalid
+ */
+export function buildPipeline(name: string, pipeline: PipelineConfig): Pipeline {
+  const errors = validatePipelineConfig(name, pipeline);
+  if (errors.length > 0) {
+    throw new ConfigError(`Invalid pipeline:\n${errors.join("\n")}`);
+  }
+
+  return {
+    name,
+    stages: pipeline.stages.map((stage) => ({
+      state: stage.state,
+      phase: stage.phase ?? null,
+      prompt: stage.prompt,
+      allowedTools: stage.allowed_tools,
+    })),
+  };
+}
+
+/**
+ * Resolve a pipeline by name.
+ * A configured pipeline with a built-in name ("default", "bug", "spike",
+ * "tdd") replaces the built-in one.
+ *
+ * @param source - Config holding pipeline definitions
+ * @param name - Pipeline name (defaults to config.default_pipeline, then "default")
+ * @throws ConfigError if the name is unknown or the definition is invalid
+ */
+export function resolvePipeline(
+  source: PipelineSource,
+  name?: string,
+): Pipeline {
+  const pipelineName =
+    name ?? source.default_pipeline ?? DEFAULT_PIPELINE_NAME;
+  const configured = source.pipelines?.[pipelineName];
+
+  if (configured) {
+    return buildPipeline(pipelineName, configured);
+  }
+  if (BUILT_IN_PIPELINES[pipelineName]) {
+    return BUILT_IN_PIPELINES[pipelineName];
+  }
+
+  throw new ConfigError(
+    `Unknown pipeline '${pipelineName}'. Define it under "pipelines" in .wreckit/config.json`,
+  );
+}
+
+/**
+ * Resolve the pipeline an item was created under.
+ * Items without a recorded pipeline use the built-in default, not
+ * config.default_pipeline, so changing the default never reroutes
+ * existing work.
+ */
+export function getItemPipeline(
+  item: Pick<Item, "pipeline">,
+  source: PipelineSource,
+): Pipeline {
+  return resolvePipeline(source, item.pipeline ?? DEFAULT_PIPELINE_NAME);
+}
+
+export function getPipelineStates(pipeline: Pipeline): WorkflowState[] {
+  return pipeline.stages.map((stage) => stage.state);
+}
+
+export function getStageForState(
+  pipeline: Pipeline,
+  state: WorkflowState,
+): PipelineStage | undefined {
+  return pipeline.stages.find((stage) => stage.state === state);
+}
+
+export function getStageForPhase(
+  pipeline: Pipeline,
+  phase: PhaseName,
+): PipelineStage | undefined {
+  return pipeline.stages.find((stage) => stage.phase === phase);
+}
+
+/**
+ * Returns the state an item must be in for a phase to run, i.e. the state
+ * of the stage before the one the phase produces.
+ *
+ * @returns The entry state, or null if the phase is not in the pipeline
+ */
+export function getPhaseEntryState(
+  pipeline: Pipeline,
+  phase: PhaseName,
+): WorkflowState | null {
+  const index = pipeline.stages.findIndex((stage) => stage.phase === phase);
+  if (index <= 0) {
+    return null;
+  }
+  return pipeline.stages[index - 1].state;
+}
+
+/**
+ * Returns the phase that moves an item out of the given state.
+ *
+ * @returns The next phase, or null at the end of the pipeline
+ */
+export function getNextPhaseInPipeline(
+  pipeline: Pipeline,
+  state: WorkflowState,
+): PhaseName | null {
+  const index = pipeline.stages.findIndex((stage) => stage.state === state);
+  if (index === -1 || index >= pipeline.stages.length - 1) {
+    return null;
+  }
+  return pipeline.stages[index + 1].phase;
+}
@@ -0,0 +1,46 @@
+import type { ItemKind, Reproduction } from "../schemas";
+import { getStageForPhase, type Pipeline } from "./pipeline";
+
+/**
+ * Bug items whose pipeline has a reproduce stage must prove the fix with
+ * the reproduction test before leaving implementation.
+ */
+export function requiresReproduction(
+  kind: ItemKind | undefined,
+  pipeline: Pipeline,
+): boolean {
+  return (
+    kind === "bug" && getStageForPhase(pipeline, "reproduce") !== undefined
+  );
+}
+
+/**
+ * Record a run of the reproduction test. A failing run supersedes any
+ * earlier passing run, so the fix has to pass again afterwards.
+ */
+export function recordTestRun(
+  reproduction: Reproduction,
+  exitCode: number,
+  now = new Date().toISOString(),
+): Reproduction {
+  const run = { at: now, exit_code: exitCode, passed: exitCode === 0 };
+  return run.passed
+    ? { ...reproduction, passing_run: run }
+    : { ...reproduction, failing_run: run, passing_run: null };
+}
+
+/** The reproduction test has been seen failing. */
+export function isReproduced(
+  reproduction: Reproduction | null | undefined,
+): boolean {
+  return !!reproduction?.failing_run;
+}
+
+/** The reproduction test failed and then passed. */
+export function isFixVerified(
+  reproduction: Reproduction | null | undefined,
+): boolean {
+  const failing = reproduction?.failing_run;
+  const passing = reproduction?.passing_run;
+  return !!failing && !!passing && passing.at >= failing.at;
+}
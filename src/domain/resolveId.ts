@@ -1,6 +1,7 @@
 import { scanItems, parseItemId } from "./indexing";
 import { AmbiguousIdError, ItemNotFoundError } from "../errors";
-import type { Item } from "../schemas";
+import type { Item, UsageTotals } from "../schemas";
+import { getItemUsage } from "./usage";
 import { logger, type Logger } from "../logging";
 
 export interface ResolvedItem {
@@ -8,6 +9,12 @@ export interface ResolvedItem {
   fullId: string;
   title: string;
   state: string;
+  /** Reason for blocked/paused/wontfix items */
+  parkedReason?: string;
+  /** Gated phase awaiting human approval */
+  pendingApproval?: string;
+  /** Token usage and cost of all the item's phases */
+  usage?: UsageTotals;
 }
 
 export interface ResolveIdOptions {
@@ -25,6 +32,9 @@ export async function buildIdMap(
     fullId: item.id,
     title: item.title,
     state: item.state,
+    parkedReason: item.parked_reason ?? undefined,
+    pendingApproval: item.pending_approval ?? undefined,
+    usage: getItemUsage(item) ?? undefined,
   }));
 }
 
@@ -0,0 +1,116 @@
+/**
+ * Spike items answer a question instead of changing code. Research writes
+ * a report.md next to research.md and the item completes without a branch
+ * or PR.
+ *
+ * Example report:
+ * ```markdown
+ * # Spike Report: Should we migrate to X?
+ *
+ * ## Findings
+ * ## Options
+ * ## Recommendation
+ *
+ * ## Follow-up Ideas
+ * - **Migrate auth to X**: Start with the session store
+ * ```
+ */
+import type { ItemKind } from "../schemas";
+import type { ParsedIdea } from "./ideas";
+import { getStageForPhase, type Pipeline } from "./pipeline";
+
+export const REPORT_SECTIONS = ["Findings", "Options", "Recommendation"];
+
+const FOLLOW_UP_SECTIONS = ["follow-up ideas", "follow-ups", "follow ups"];
+
+export interface ReportValidationResult {
+  valid: boolean;
+  errors: string[];
+}
+
+/**
+ * Spikes whose pipeline has no PR stage are finished by their report
+ * rather than by a merged PR.
+ */
+export function requiresReport(
+  kind: ItemKind | undefined,
+  pipeline: Pipeline,
+): boolean {
+  return kind === "spike" && getStageForPhase(pipeline, "pr") === undefined;
+}
+
+/**
+ * Split markdown into its level-2 sections, keyed by lowercase title.
+ */
+function splitSections(content: string): Map<string, string[]> {
+  const sections = new Map<string, string[]>();
+  let current: string[] | null = null;
+  for (const line of content.split("\n")) {
+    const header = line.match(/^##\s+(.+?)\s*$/);
+    if (header) {
+      current = [];
+      sections.set(header[1].toLowerCase(), current);
+    } else if (/^#\s/.test(line)) {
+      current = null;
+    } else if (current) {
+      current.push(line);
+    }
+  }
+  return sections;
+}
+
+/**
+ * Check that a spike report has non-empty Findings, Options and
+ * Recommendation sections.
+ */
+export function validateSpikeReport(content: string): ReportValidationResult {
+  const sections = splitSections(content);
+  const errors: string[] = [];
+
+  const missing = REPORT_SECTIONS.filter(
+    (name) => !sections.has(name.toLowerCase()),
+  );
+  if (missing.length > 0) {
+    errors.push(`Missing required sections: ${missing.join(", ")}`);
+  }
+
+  for (const name of REPORT_SECTIONS) {
+    const lines = sections.get(name.toLowerCase());
+    if (lines && lines.join("").trim() === "") {
+      errors.push(`Section ${name} is empty`);
+    }
+  }
+
+  return { valid: errors.length === 0, errors };
+}
+
+/**
+ * Parse the list under a report's "Follow-up Ideas" section into ideas.
+ * Items may be written as `**Title**: description`, `Title: description`
+ * or just `Title`.
+ */
+export function parseFollowUpIdeas(content: string): ParsedIdea[] {
+  const sections = splitSections(content);
+  const lines =
+    FOLLOW_UP_SECTIONS.map((name) => sections.get(name)).find(Boolean) ?? [];
+
+  const ideas: ParsedIdea[] = [];
+  for (const line of lines) {
+    const item = line.match(/^\s*(?:[-*+]|\d+[.)])\s+(.+)$/);
+    if (!item) continue;
+    const text = item[1].trim();
+
+    const bold = text.match(/^\*\*(.+?)\*\*\s*(?:[:—–-]\s*)?(.*)$/);
+    const plain = text.match(/^([^:]+):\s+(.+)$/);
+    const [title, description] = bold
+      ? [bold[1], bold[2]]
+      : plain
+        ? [plain[1], plain[2]]
+        : [text, ""];
+
+    const cleanTitle = title.replace(/[:.]+$/, "").trim();
+    if (!cleanTitle || /^none\b/i.test(cleanTitle)) continue;
+    ideas.push({ title: cleanTitle, description: description.trim() });
+  }
+  return ideas;
+}
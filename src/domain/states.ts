@@ -1,43 +1,50 @@
 import type { WorkflowState } from "../schemas";
+import {
+  DEFAULT_PIPELINE,
+  PARKED_STATES,
+  getPipelineStates,
+  type Pipeline,
+} from "./pipeline";
 
 /**
- * The canonical ordering of workflow states.
+ * The canonical ordering of workflow states in the built-in pipeline.
  *
- * IMPORTANT: This is the source of truth for state ordering. Any changes must be synchronized with:
- * - src/commands/phase.ts:89-96 (stateOrder array in isInvalidTransition)
- * - src/workflow/itemWorkflow.ts:607-626 (getNextPhase switch statement)
+ * State ordering is derived from the pipeline definition (see src/domain/pipeline.ts).
+ * Projects can define their own pipelines in .wreckit/config.json; the helpers below
+ * accept a pipeline and fall back to the built-in one.
  *
- * The state machine follows a linear progression: idea → researched → planned → implementing → critique → in_pr → done
+ * The built-in pipeline follows a linear progression: idea → researched → planned → implementing → critique → in_pr → done
  */
-export const WORKFLOW_STATES: WorkflowState[] = [
-  "idea",
-  "researched",
-  "planned",
-  "implementing",
-  "critique",
-  "in_pr",
-  "done",
-];
-
-export function getStateIndex(state: WorkflowState): number {
-  return WORKFLOW_STATES.indexOf(state);
+export const WORKFLOW_STATES: WorkflowState[] =
+  getPipelineStates(DEFAULT_PIPELINE);
+
+export function getStateIndex(
+  state: WorkflowState,
+  pipeline: Pipeline = DEFAULT_PIPELINE,
+): number {
+  return getPipelineStates(pipeline).indexOf(state);
 }
 
 /**
  * Returns the next state in the workflow progression.
  *
- * Uses WORKFLOW_STATES to determine the linear state sequence.
- * Returns null for the terminal "done" state.
+ * Uses the pipeline's stage order to determine the linear state sequence.
+ * Returns null for the terminal "done" state and for states not in the pipeline.
  *
  * @param current - The current workflow state
+ * @param pipeline - The pipeline to follow (defaults to the built-in pipeline)
  * @returns The next state, or null if at the end of the workflow
  */
-export function getNextState(current: WorkflowState): WorkflowState | null {
-  const index = getStateIndex(current);
-  if (index === -1 || index >= WORKFLOW_STATES.length - 1) {
+export function getNextState(
+  current: WorkflowState,
+  pipeline: Pipeline = DEFAULT_PIPELINE,
+): WorkflowState | null {
+  const states = getPipelineStates(pipeline);
+  const index = states.indexOf(current);
+  if (index === -1 || index >= states.length - 1) {
     return null;
   }
-  return WORKFLOW_STATES[index + 1];
+  return states[index + 1];
 }
 
 /**
@@ -47,13 +54,53 @@ export function getNextState(current: WorkflowState): WorkflowState | null {
  * Wrapper around getNextState() that returns an array for API convenience.
  *
  * @param current - The current workflow state
+ * @param pipeline - The pipeline to follow (defaults to the built-in pipeline)
  * @returns Array of allowed next states (will contain 0 or 1 states)
  */
-export function getAllowedNextStates(current: WorkflowState): WorkflowState[] {
-  const next = getNextState(current);
+export function getAllowedNextStates(
+  current: WorkflowState,
+  pipeline: Pipeline = DEFAULT_PIPELINE,
+): WorkflowState[] {
+  const next = getNextState(current, pipeline);
   return next ? [next] : [];
 }
 
+/**
+ * Returns the states an item may be sent back to from its current state.
+ *
+ * Backward transitions (reopen/regress) may target any earlier state in the pipeline.
+ *
+ * @param current - The current workflow state
+ * @param pipeline - The pipeline to follow (defaults to the built-in pipeline)
+ * @returns Earlier states in pipeline order (empty for the entry state)
+ */
+export function getAllowedPreviousStates(
+  current: WorkflowState,
+  pipeline: Pipeline = DEFAULT_PIPELINE,
+): WorkflowState[] {
+  const states = getPipelineStates(pipeline);
+  const index = states.indexOf(current);
+  if (index <= 0) {
+    return [];
+  }
+  return states.slice(0, index);
+}
+
 export function isTerminalState(state: WorkflowState): boolean {
   return state === "done";
 }
+
+/**
+ * Parked items keep the state they were parked from and are skipped
+ * by the orchestrator until resumed.
+ */
+export function isParkedState(state: WorkflowState): boolean {
+  return PARKED_STATES.includes(state);
+}
+
+/**
+ * Returns true if the orchestrator should work on an item in this state.
+ */
+export function isActionableState(state: WorkflowState): boolean {
+  return !isTerminalState(state) && !isParkedState(state);
+}
@@ -0,0 +1,51 @@
+import type { Prd, StoryTests, TestOutcome } from "../schemas";
+
+/**
+ * PRD stories that the tests phase wrote no tests for.
+ */
+export function getStoriesWithoutTests(
+  prd: Prd,
+  storyTests: StoryTests,
+): string[] {
+  const covered = new Set(
+    storyTests.stories
+      .filter((entry) => entry.tests.length > 0)
+      .map((entry) => entry.story_id),
+  );
+  return prd.user_stories
+    .map((story) => story.id)
+    .filter((id) => !covered.has(id));
+}
+
+/**
+ * Check a run of freshly written tests: they must compile and run, but
+ * fail because the stories are not implemented yet.
+ *
+ * @param outcomes - Per-test outcomes parsed from the run's output
+ * @returns Why the run is not acceptable, or null if it is
+ */
+export function checkTestsFailBeforeImplementation(
+  outcomes: TestOutcome[],
+  passed: boolean,
+): string | null {
+  if (passed) {
+    return "the tests already pass, so they do not test the unimplemented stories";
+  }
+  if (outcomes.length > 0 && !outcomes.some((t) => t.status === "failed")) {
+    return "the command failed but no test failed; the tests may not compile";
+  }
+  return null;
+}
+
+/**
+ * Render the tests each story has to turn green for the implement prompt.
+ */
+export function formatStoryTests(storyTests: StoryTests): string {
+  return storyTests.stories
+    .map((entry) => {
+      const files = entry.test_files.map((f) => `\`${f}\``).join(", ");
+      const tests = entry.tests.map((name) => `  - ${name}`).join("\n");
+      return `- **${entry.story_id}** (${files})\n${tests}`;
+    })
+    .join("\n");
+}
@@ -1,36 +1,97 @@
-import type { Item } from "../schemas";
+import type { HistoryEntry, Item, WorkflowState } from "../schemas";
 import type { ValidationContext } from "./validation";
-import { validateTransition } from "./validation";
-import { getNextState } from "./states";
+import { validateTransition, validateRegression } from "./validation";
+import { getNextState, isParkedState } from "./states";
+import {
+  DEFAULT_PIPELINE,
+  getPipelineStates,
+  type Pipeline,
+} from "./pipeline";
 
 export interface TransitionResult {
   nextItem: Item;
   error?: never;
 }
 
+/**
+ * A transition applied outside the phase runners, which record their own
+ * history.
+ */
+export interface RecordedTransitionResult extends TransitionResult {
+  /** Audit record for the item's history.jsonl */
+  history: HistoryEntry;
+}
+
 export interface TransitionError {
   nextItem?: never;
+  history?: never;
   error: string;
 }
 
+/**
+ * Build the history.jsonl record for a state change.
+ */
+export function createTransitionEntry(
+  from: WorkflowState,
+  to: WorkflowState,
+  actor: string,
+  extra: Partial<Pick<HistoryEntry, "phase" | "reason" | "ts">> = {},
+): HistoryEntry {
+  return {
+    ts: extra.ts ?? new Date().toISOString(),
+    event: "transition",
+    actor,
+    from,
+    to,
+    ...(extra.phase !== undefined && { phase: extra.phase }),
+    ...(extra.reason !== undefined && { reason: extra.reason }),
+  };
+}
+
+/**
+ * Build one history.jsonl record per pipeline step of a forward move, so
+ * a phase that advances an item past several states logs each of them.
+ */
+export function createAdvanceEntries(
+  from: WorkflowState,
+  to: WorkflowState,
+  actor: string,
+  pipeline: Pipeline = DEFAULT_PIPELINE,
+  extra: Partial<Pick<HistoryEntry, "phase" | "ts">> = {},
+): HistoryEntry[] {
+  const states = getPipelineStates(pipeline);
+  const start = states.indexOf(from);
+  const end = states.indexOf(to);
+  if (start === -1 || end <= start) {
+    return [createTransitionEntry(from, to, actor, extra)];
+  }
+  return states
+    .slice(start + 1, end + 1)
+    .map((state, i) =>
+      createTransitionEntry(states[start + i], state, actor, extra),
+    );
+}
+
 /**
  * Pure function that applies a state transition to an item.
  * - Never mutates the input item
  * - Validates the transition before applying
  * - Returns new Item with updated state and updated_at
  * - Returns error if transition is invalid
+ * - Follows the given pipeline's stage order (defaults to the built-in pipeline)
  */
 export function applyStateTransition(
   item: Readonly<Item>,
   ctx: ValidationContext,
+  pipeline: Pipeline = DEFAULT_PIPELINE,
 ): TransitionResult | TransitionError {
-  const nextState = getNextState(item.state);
+  const nextState = getNextState(item.state, pipeline);
 
   if (nextState === null) {
     return { error: `Cannot transition from terminal state: ${item.state}` };
   }
 
-  const validation = validateTransition(item.state, nextState, ctx);
+  const validation = validateTransition(item.state, nextState, ctx, pipeline);
   if (!validation.valid) {
     return { error: validation.reason ?? "Transition validation failed" };
   }
@@ -43,3 +104,184 @@ export function applyStateTransition(
 
   return { nextItem };
 }
+
+export interface RegressionOptions {
+  reason: string;
+  /** Who requested the regression ("human" or an automated phase such as "critique") */
+  actor: string;
+  pipeline?: Pipeline;
+  /** Item-relative directory where superseded artifacts were archived */
+  archiveDir?: string | null;
+  /** Value for last_error after the regression (defaults to null) */
+  lastError?: string | null;
+  now?: string;
+}
+
+/**
+ * Pure function that sends an item back to an earlier state.
+ * - Never mutates the input item
+ * - Validates the target against the pipeline order
+ * - Appends the regression (with its reason) to item.regressions
+ * - Clears completion metadata when leaving "done"
+ * - Clears any pending approval gate
+ * - Drops reproduction test runs that the regression supersedes
+ * - Drops story tests when the item is sent back before planning
+ * - Drops the documentation record when documenting has to be repeated
+ */
+export function applyRegression(
+  item: Readonly<Item>,
+  target: WorkflowState,
+  options: RegressionOptions,
+): RecordedTransitionResult | TransitionError {
+  const pipeline = options.pipeline ?? DEFAULT_PIPELINE;
+  const validation = validateRegression(item.state, target, pipeline);
+  if (!validation.valid) {
+    return { error: validation.reason ?? "Regression validation failed" };
+  }
+
+  const now = options.now ?? new Date().toISOString();
+  const nextItem: Item = {
+    ...item,
+    state: target,
+    last_error: options.lastError ?? null,
+    updated_at: now,
+    regressions: [
+      ...(item.regressions ?? []),
+      {
+        from: item.state,
+        to: target,
+        reason: options.reason,
+        actor: options.actor,
+        at: now,
+        archive_dir: options.archiveDir ?? null,
+      },
+    ],
+  };
+
+  if (item.state === "done") {
+    nextItem.completed_at = null;
+    nextItem.merged_at = null;
+    nextItem.merge_commit_sha = null;
+  }
+
+  // The output awaiting review is being discarded, so the gate no longer applies
+  if (item.pending_approval) {
+    nextItem.pending_approval = null;
+  }
+
+  // Superseded reproduction runs have to be repeated
+  if (item.reproduction) {
+    const states = getPipelineStates(pipeline);
+    const targetIndex = states.indexOf(target);
+    if (targetIndex < states.indexOf("reproduced")) {
+      nextItem.reproduction = null;
+    } else if (targetIndex <= states.indexOf("implementing")) {
+      nextItem.reproduction = { ...item.reproduction, passing_run: null };
+    }
+  }
+
+  // Story tests belong to the PRD; they are kept unless it is replanned
+  if (item.story_tests) {
+    const states = getPipelineStates(pipeline);
+    if (states.indexOf(target) < states.indexOf("planned")) {
+      nextItem.story_tests = null;
+    }
+  }
+
+  // Documentation describes the implementation, so it is redone after rework
+  if (item.documentation) {
+    const states = getPipelineStates(pipeline);
+    if (states.indexOf(target) < states.indexOf("documented")) {
+      nextItem.documentation = null;
+    }
+  }
+
+  return {
+    nextItem,
+    history: createTransitionEntry(item.state, target, options.actor, {
+      ts: now,
+      reason: options.reason,
+    }),
+  };
+}
+
+export interface ParkOptions {
+  reason?: string | null;
+  /** Condition under which a blocked item can continue */
+  unblockCondition?: string | null;
+  actor?: string;
+  now?: string;
+}
+
+/**
+ * Pure function that parks an item in a blocked, paused or wontfix state.
+ * The current state is remembered so the item can be resumed later.
+ * Re-parking a parked item keeps the original state to resume to.
+ */
+export function applyPark(
+  item: Readonly<Item>,
+  target: WorkflowState,
+  options: ParkOptions = {},
+): RecordedTransitionResult | TransitionError {
+  if (!isParkedState(target)) {
+    return { error: `${target} is not a parked state` };
+  }
+  if (item.state === target) {
+    return { error: `Item is already ${target}` };
+  }
+  if (item.state === "done") {
+    return { error: "Cannot park an item that is done" };
+  }
+
+  const now = options.now ?? new Date().toISOString();
+  const parkedFrom = isParkedState(item.state)
+    ? (item.parked_from ?? "idea")
+    : item.state;
+
+  return {
+    nextItem: {
+      ...item,
+      state: target,
+      parked_from: parkedFrom,
+      parked_at: now,
+      parked_reason: options.reason ?? null,
+      unblock_condition:
+        target === "blocked" ? (options.unblockCondition ?? null) : null,
+      updated_at: now,
+    },
+    history: createTransitionEntry(item.state, target, options.actor ?? "human", {
+      ts: now,
+      ...(options.reason ? { reason: options.reason } : {}),
+    }),
+  };
+}
+
+/**
+ * Pure function that returns a parked item to the state it was parked from.
+ */
+export function applyResume(
+  item: Readonly<Item>,
+  options: Pick<ParkOptions, "actor" | "now"> = {},
+): RecordedTransitionResult | TransitionError {
+  if (!isParkedState(item.state)) {
+    return { error: `Item is not parked (state: ${item.state})` };
+  }
+
+  const now = options.now ?? new Date().toISOString();
+  const target = item.parked_from ?? "idea";
+
+  return {
+    nextItem: {
+      ...item,
+      state: target,
+      parked_from: null,
+      parked_at: null,
+      parked_reason: null,
+      unblock_condition: null,
+      updated_at: now,
+    },
+    history: createTransitionEntry(item.state, target, options.actor ?? "human", {
+      ts: now,
+    }),
+  };
+}
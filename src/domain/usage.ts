@@ -0,0 +1,157 @@
+import type {
+  AgentUsage,
+  Item,
+  ModelPrice,
+  TokenUsage,
+  UsageTotals,
+} from "../schemas";
+
+const TOKENS_PER_PRICE_UNIT = 1_000_000;
+
+export function emptyUsageTotals(): UsageTotals {
+  return {
+    input_tokens: 0,
+    output_tokens: 0,
+    cache_read_tokens: 0,
+    cache_write_tokens: 0,
+    cost_usd: 0,
+    runs: 0,
+    models: [],
+  };
+}
+
+/**
+ * Price for a model: an exact entry, or else the longest entry that the
+ * model name starts with (so "claude-sonnet-4" prices
+ * "claude-sonnet-4-20250514").
+ */
+export function findModelPrice(
+  model: string | null,
+  prices: Record<string, ModelPrice> | undefined,
+): ModelPrice | null {
+  if (!model || !prices) {
+    return null;
+  }
+  if (prices[model]) {
+    return prices[model];
+  }
+  const prefix = Object.keys(prices)
+    .filter((key) => model.startsWith(key))
+    .sort((a, b) => b.length - a.length)[0];
+  return prefix ? prices[prefix] : null;
+}
+
+/**
+ * Cost of a run in USD, or null if its model has no price.
+ */
+export function computeCost(
+  usage: AgentUsage,
+  prices: Record<string, ModelPrice> | undefined,
+): number | null {
+  const price = findModelPrice(usage.model, prices);
+  if (!price) {
+    return null;
+  }
+  return (
+    (usage.input_tokens * price.input +
+      usage.output_tokens * price.output +
+      usage.cache_read_tokens * (price.cache_read ?? price.input) +
+      usage.cache_write_tokens * (price.cache_write ?? price.input)) /
+    TOKENS_PER_PRICE_UNIT
+  );
+}
+
+function addTokens<T extends TokenUsage>(totals: T, usage: TokenUsage): T {
+  return {
+    ...totals,
+    input_tokens: totals.input_tokens + usage.input_tokens,
+    output_tokens: totals.output_tokens + usage.output_tokens,
+    cache_read_tokens: totals.cache_read_tokens + usage.cache_read_tokens,
+    cache_write_tokens: totals.cache_write_tokens + usage.cache_write_tokens,
+  };
+}
+
+/**
+ * Add one agent run to accumulated totals.
+ */
+export function addRunUsage(
+  totals: UsageTotals | undefined,
+  usage: AgentUsage,
+  prices: Record<string, ModelPrice> | undefined,
+): UsageTotals {
+  const base = addTokens(totals ?? emptyUsageTotals(), usage);
+  const models =
+    usage.model && !base.models.includes(usage.model)
+      ? [...base.models, usage.model]
+      : base.models;
+  return {
+    ...base,
+    cost_usd: base.cost_usd + (computeCost(usage, prices) ?? 0),
+    runs: base.runs + 1,
+    models,
+  };
+}
+
+/**
+ * Combine several totals, e.g. an item's phases.
+ */
+export function sumUsageTotals(list: UsageTotals[]): UsageTotals {
+  return list.reduce(
+    (sum, totals) => ({
+      ...addTokens(sum, totals),
+      cost_usd: sum.cost_usd + totals.cost_usd,
+      runs: sum.runs + totals.runs,
+      models: [
+        ...sum.models,
+        ...totals.models.filter((model) => !sum.models.includes(model)),
+      ],
+    }),
+    emptyUsageTotals(),
+  );
+}
+
+/**
+ * Usage of all of an item's phases, or null if none was recorded.
+ */
+export function getItemUsage(item: Pick<Item, "usage">): UsageTotals | null {
+  const phases = Object.values(item.usage ?? {}).filter(
+    (totals): totals is UsageTotals => totals !== undefined,
+  );
+  return phases.length > 0 ? sumUsageTotals(phases) : null;
+}
+
+/**
+ * Models in the totals that have no price, so their cost is not counted.
+ */
+export function getUnpricedModels(
+  totals: UsageTotals,
+  prices: Record<string, ModelPrice> | undefined,
+): string[] {
+  return totals.models.filter((model) => !findModelPrice(model, prices));
+}
+
+/**
+ * Compact token count, e.g. 950, 12.3k, 1.2M.
+ */
+export function formatTokens(count: number): string {
+  if (count >= 1_000_000) {
+    return `${(count / 1_000_000).toFixed(1)}M`;
+  }
+  if (count >= 1_000) {
+    return `${(count / 1_000).toFixed(1)}k`;
+  }
+  return String(count);
+}
+
+/**
+ * One-line summary, e.g. "12.3k in, 4.1k out, 80.0k cached, $0.42".
+ */
+export function formatUsage(totals: TokenUsage & { cost_usd: number }): string {
+  const cached = totals.cache_read_tokens + totals.cache_write_tokens;
+  return [
+    `${formatTokens(totals.input_tokens)} in`,
+    `${formatTokens(totals.output_tokens)} out`,
+    ...(cached > 0 ? [`${formatTokens(cached)} cached`] : []),
+    `$${totals.cost_usd.toFixed(2)}`,
+  ].join(", ");
+}
@@ -1,5 +1,21 @@
-import type { Prd, WorkflowState } from "../schemas";
-import { getAllowedNextStates } from "./states";
+import type {
+  Documentation,
+  ItemKind,
+  Prd,
+  Reproduction,
+  Story,
+  StoryTests,
+  WorkflowState,
+} from "../schemas";
+import { getAllowedNextStates, getAllowedPreviousStates } from "./states";
+import { DEFAULT_PIPELINE, type Pipeline } from "./pipeline";
+import {
+  isFixVerified,
+  isReproduced,
+  requiresReproduction,
+} from "./reproduction";
+import { requiresReport } from "./spike";
+import { getStoriesWithoutTests } from "./storyTests";
 import type { ParsedIdea } from "./ideas";
 
 export interface ValidationContext {
@@ -8,6 +24,11 @@ export interface ValidationContext {
   prd: Prd | null;
   hasPr: boolean;
   prMerged: boolean;
+  kind?: ItemKind;
+  reproduction?: Reproduction | null;
+  hasReportMd?: boolean;
+  storyTests?: StoryTests | null;
+  documentation?: Documentation | null;
 }
 
 export interface ValidationResult {
@@ -29,6 +50,120 @@ export function hasPendingStories(prd: Prd | null): boolean {
   return prd.user_stories.some((story) => story.status === "pending");
 }
 
+type StoryRef = Pick<Story, "id"> & { depends_on?: string[] };
+
+/**
+ * Detect circular story dependencies using DFS.
+ * Returns array of cycles found (each cycle is an array of story IDs,
+ * with the first ID repeated at the end).
+ */
+export function findStoryDependencyCycles(stories: StoryRef[]): string[][] {
+  const cycles: string[][] = [];
+  const storyMap = new Map(stories.map((s) => [s.id, s]));
+  const visited = new Set<string>();
+  const stack: string[] = [];
+  const onStack = new Set<string>();
+
+  function visit(id: string): void {
+    visited.add(id);
+    stack.push(id);
+    onStack.add(id);
+
+    for (const depId of storyMap.get(id)?.depends_on ?? []) {
+      if (!storyMap.has(depId)) continue;
+      if (onStack.has(depId)) {
+        cycles.push([...stack.slice(stack.indexOf(depId)), depId]);
+      } else if (!visited.has(depId)) {
+        visit(depId);
+      }
+    }
+
+    stack.pop();
+    onStack.delete(id);
+  }
+
+  for (const story of stories) {
+    if (!visited.has(story.id)) {
+      visit(story.id);
+    }
+  }
+
+  return cycles;
+}
+
+/**
+ * Find story dependencies that reference stories not in the PRD.
+ */
+export function findMissingStoryDependencies(
+  stories: StoryRef[],
+): Array<{ storyId: string; missingDep: string }> {
+  const ids = new Set(stories.map((s) => s.id));
+  const missing: Array<{ storyId: string; missingDep: string }> = [];
+
+  for (const story of stories) {
+    for (const depId of story.depends_on ?? []) {
+      if (!ids.has(depId)) {
+        missing.push({ storyId: story.id, missingDep: depId });
+      }
+    }
+  }
+
+  return missing;
+}
+
+/**
+ * Check a PRD's story dependency graph for cycles, self-references and
+ * references to unknown stories.
+ *
+ * @returns Array of error messages (empty if valid)
+ */
+export function validateStoryDependencies(stories: StoryRef[]): string[] {
+  const errors: string[] = [];
+
+  for (const cycle of findStoryDependencyCycles(stories)) {
+    errors.push(`Circular story dependency: ${cycle.join(" -> ")}`);
+  }
+  for (const { storyId, missingDep } of findMissingStoryDependencies(stories)) {
+    errors.push(`Story "${storyId}" depends on unknown story "${missingDep}"`);
+  }
+
+  return errors;
+}
+
+/**
+ * Whether a pending story has all its dependencies done. References to
+ * unknown stories are ignored so a hand-edited PRD cannot stall the loop
+ * (doctor reports them).
+ */
+export function isStoryReady(prd: Prd, story: Story): boolean {
+  if (story.status !== "pending") {
+    return false;
+  }
+  return (story.depends_on ?? []).every(
+    (depId) =>
+      (prd.user_stories.find((s) => s.id === depId)?.status ?? "done") ===
+      "done",
+  );
+}
+
+/**
+ * Pick the next story to implement: the highest-priority pending story whose
+ * dependencies are all done.
+ *
+ * @returns The next story, or null if no pending story is ready
+ */
+export function getNextStory(prd: Prd | null): Story | null {
+  if (!prd) {
+    return null;
+  }
+
+  const ready = prd.user_stories
+    .filter((story) => isStoryReady(prd, story))
+    .sort((a, b) => a.priority - b.priority);
+
+  return ready[0] ?? null;
+}
+
 export function canEnterResearched(
   ctx: Pick<ValidationContext, "hasResearchMd">,
 ): ValidationResult {
@@ -38,6 +173,37 @@ export function canEnterResearched(
   return { valid: true };
 }
 
+export function canEnterReproduced(
+  ctx: Pick<ValidationContext, "reproduction">,
+): ValidationResult {
+  if (!ctx.reproduction) {
+    return { valid: false, reason: "no reproduction test recorded" };
+  }
+  if (!isReproduced(ctx.reproduction)) {
+    return {
+      valid: false,
+      reason: `reproduction test has not been seen failing: ${ctx.reproduction.command}`,
+    };
+  }
+  return { valid: true };
+}
+
+/**
+ * A bug fix is accepted only once its reproduction test has failed and
+ * then passed.
+ */
+export function canLeaveBugFix(
+  ctx: Pick<ValidationContext, "reproduction">,
+): ValidationResult {
+  if (!isFixVerified(ctx.reproduction)) {
+    return {
+      valid: false,
+      reason: "reproduction test has not failed and then passed",
+    };
+  }
+  return { valid: true };
+}
+
 export function canEnterPlanned(
   ctx: Pick<ValidationContext, "hasPlanMd" | "prd">,
 ): ValidationResult {
@@ -72,6 +238,21 @@ export function canEnterCritique(
   return { valid: true };
 }
 
+/**
+ * Documentation is written for a finished implementation.
+ */
+export function canEnterDocumented(
+  ctx: Pick<ValidationContext, "prd" | "documentation">,
+): ValidationResult {
+  if (!allStoriesDone(ctx.prd)) {
+    return { valid: false, reason: "not all stories are done" };
+  }
+  if (!ctx.documentation) {
+    return { valid: false, reason: "documentation update not recorded" };
+  }
+  return { valid: true };
+}
+
 export function canEnterInPr(
   ctx: Pick<ValidationContext, "prd" | "hasPr">,
 ): ValidationResult {
@@ -93,12 +274,52 @@ export function canEnterDone(
   return { valid: true };
 }
 
+/**
+ * Every story needs failing tests before implementation starts.
+ */
+export function canEnterTestsWritten(
+  ctx: Pick<ValidationContext, "prd" | "storyTests">,
+): ValidationResult {
+  if (!ctx.storyTests) {
+    return { valid: false, reason: "no story tests recorded" };
+  }
+  if (ctx.prd) {
+    const missing = getStoriesWithoutTests(ctx.prd, ctx.storyTests);
+    if (missing.length > 0) {
+      return {
+        valid: false,
+        reason: `stories without tests: ${missing.join(", ")}`,
+      };
+    }
+  }
+  if (!ctx.storyTests.failing_run) {
+    return {
+      valid: false,
+      reason: `story tests have not been seen failing: ${ctx.storyTests.command}`,
+    };
+  }
+  return { valid: true };
+}
+
+/**
+ * Spikes finish with their report instead of a merged PR.
+ */
+export function canEnterDoneWithReport(
+  ctx: Pick<ValidationContext, "hasReportMd">,
+): ValidationResult {
+  if (!ctx.hasReportMd) {
+    return { valid: false, reason: "report.md does not exist" };
+  }
+  return { valid: true };
+}
+
 export function validateTransition(
   current: WorkflowState,
   target: WorkflowState,
   ctx: ValidationContext,
+  pipeline: Pipeline = DEFAULT_PIPELINE,
 ): ValidationResult {
-  const allowed = getAllowedNextStates(current);
+  const allowed = getAllowedNextStates(current, pipeline);
   if (!allowed.includes(target)) {
     return {
       valid: false,
@@ -106,25 +327,68 @@ export function validateTransition(
     };
   }
 
+  if (
+    (target === "critique" ||
+      target === "documented" ||
+      target === "in_pr") &&
+    requiresReproduction(ctx.kind, pipeline)
+  ) {
+    const fixed = canLeaveBugFix(ctx);
+    if (!fixed.valid) {
+      return fixed;
+    }
+  }
+
   switch (target) {
     case "researched":
       return canEnterResearched(ctx);
+    case "reproduced":
+      return canEnterReproduced(ctx);
     case "planned":
       return canEnterPlanned(ctx);
+    case "tests_written":
+      return canEnterTestsWritten(ctx);
     case "implementing":
       return canEnterImplementing(ctx);
     case "critique":
       // Critique requires all stories to be done (same as in_pr entry requirement)
       return canEnterCritique(ctx);
+    case "documented":
+      return canEnterDocumented(ctx);
     case "in_pr":
       return canEnterInPr(ctx);
     case "done":
-      return canEnterDone(ctx);
+      return requiresReport(ctx.kind, pipeline)
+        ? canEnterDoneWithReport(ctx)
+        : canEnterDone(ctx);
     default:
       return { valid: false, reason: `unknown target state: ${target}` };
   }
 }
 
+/**
+ * Validate a backward transition (reopen/regress).
+ * Unlike forward transitions there are no artifact requirements: the target
+ * only has to be an earlier state in the item's pipeline.
+ */
+export function validateRegression(
+  current: WorkflowState,
+  target: WorkflowState,
+  pipeline: Pipeline = DEFAULT_PIPELINE,
+): ValidationResult {
+  const allowed = getAllowedPreviousStates(current, pipeline);
+  if (!allowed.includes(target)) {
+    return {
+      valid: false,
+      reason:
+        allowed.length > 0
+          ? `cannot regress from ${current} to ${target} (allowed: ${allowed.join(", ")})`
+          : `cannot regress from ${current}: no earlier state in pipeline '${pipeline.name}'`,
+    };
+  }
+  return { valid: true };
+}
+
 /**
  * Payload size limits for idea ingestion as specified in 001-ideas-ingestion.md
  * These limits prevent denial-of-service and cost blowups
@@ -808,6 +1072,7 @@ export function validateStoryQuality(
       title: string;
       acceptance_criteria: string[];
       priority: number;
+      depends_on?: string[];
     }>;
   },
   options: Partial<StoryQualityOptions> = {},
@@ -853,6 +1118,9 @@ export function validateStoryQuality(
     );
   }
 
+  // Validate the story dependency graph
+  errors.push(...validateStoryDependencies(prd.user_stories));
+
   return {
     valid: errors.length === 0,
     storyCount,
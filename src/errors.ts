@@ -42,12 +42,14 @@ export const ErrorCodes = {
   RESEARCH_QUALITY: "RESEARCH_QUALITY",
   PLAN_QUALITY: "PLAN_QUALITY",
   STORY_QUALITY: "STORY_QUALITY",
+  BEHAVIOR_REGRESSION: "BEHAVIOR_REGRESSION",
 
   // Git operation errors
   BRANCH_ERROR: "BRANCH_ERROR",
   PUSH_ERROR: "PUSH_ERROR",
   PR_CREATION_ERROR: "PR_CREATION_ERROR",
   MERGE_CONFLICT: "MERGE_CONFLICT",
+  STORY_MERGE_CONFLICT: "STORY_MERGE_CONFLICT",
   REMOTE_VALIDATION: "REMOTE_VALIDATION",
 
   // Sprite/Wisp errors (Item 073)
@@ -60,6 +62,9 @@ export const ErrorCodes = {
 
   // Artifact read errors (for permission/I/O issues)
   ARTIFACT_READ_ERROR: "ARTIFACT_READ_ERROR",
+
+  // Spend errors
+  BUDGET_EXCEEDED: "BUDGET_EXCEEDED",
 } as const;
 
 export type ErrorCode = (typeof ErrorCodes)[keyof typeof ErrorCodes];
@@ -385,6 +390,46 @@ export class MergeConflictError extends WreckitError {
   }
 }
 
+/**
+ * Thrown when a story implemented in its own worktree cannot be merged back
+ * into the item branch. The story branch is kept for manual resolution.
+ */
+export class StoryMergeConflictError extends WreckitError {
+  constructor(
+    public readonly storyId: string,
+    public readonly storyBranch: string,
+    public readonly itemBranch: string,
+    public readonly conflictedFiles: string[],
+  ) {
+    super(
+      `Story ${storyId} conflicts with ${itemBranch}` +
+        (conflictedFiles.length > 0
+          ? ` in ${conflictedFiles.join(", ")}`
+          : "") +
+        `. Merge ${storyBranch} manually, then mark the story done.`,
+      ErrorCodes.STORY_MERGE_CONFLICT,
+    );
+    this.name = "StoryMergeConflictError";
+  }
+}
+
+/**
+ * Returned when a refactor story makes tests fail that passed before the
+ * refactor started. The story is put back to pending.
+ */
+export class BehaviorRegressionError extends WreckitError {
+  constructor(
+    public readonly storyIds: string[],
+    public readonly brokenTests: string[],
+  ) {
+    super(
+      `Story ${storyIds.join(", ")} broke ${brokenTests.length} previously passing test(s): ${brokenTests.join(", ")}`,
+      ErrorCodes.BEHAVIOR_REGRESSION,
+    );
+    this.name = "BehaviorRegressionError";
+  }
+}
+
 /**
  * Thrown when remote URL validation fails.
  */
@@ -402,6 +447,24 @@ export class RemoteValidationError extends WreckitError {
   }
 }
 
+/**
+ * Thrown when agent spend reaches one of the `budget` caps. The running
+ * agent is aborted and no further agent runs start for the capped scope.
+ */
+export class BudgetExceededError extends WreckitError {
+  constructor(
+    public readonly cap: "item" | "phase" | "session",
+    public readonly limitUsd: number,
+    public readonly spentUsd: number,
+  ) {
+    super(
+      `Budget exceeded: ${cap} spend of $${spentUsd.toFixed(2)} reached budget.per_${cap}_usd ($${limitUsd.toFixed(2)})`,
+      ErrorCodes.BUDGET_EXCEEDED,
+    );
+    this.name = "BudgetExceededError";
+  }
+}
+
 export function isWreckitError(error: unknown): error is WreckitError {
   return error instanceof WreckitError;
 }
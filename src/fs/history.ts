@@ -0,0 +1,52 @@
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import { HistoryEntrySchema, type HistoryEntry } from "../schemas";
+import { getHistoryPath } from "./paths";
+
+/**
+ * Append one entry to an item's history.jsonl.
+ * The file is append-only; entries are never rewritten.
+ */
+export async function appendHistory(
+  root: string,
+  itemId: string,
+  entry: Omit<HistoryEntry, "ts"> & { ts?: string },
+): Promise<void> {
+  const historyPath = getHistoryPath(root, itemId);
+  const line = JSON.stringify({ ts: new Date().toISOString(), ...entry });
+  await fs.mkdir(path.dirname(historyPath), { recursive: true });
+  await fs.appendFile(historyPath, line + "\n", "utf-8");
+}
+
+/**
+ * Read an item's history, oldest first.
+ * Returns an empty array if the item has no history yet; malformed lines are skipped.
+ */
+export async function readHistory(
+  root: string,
+  itemId: string,
+): Promise<HistoryEntry[]> {
+  let content: string;
+  try {
+    content = await fs.readFile(getHistoryPath(root, itemId), "utf-8");
+  } catch (err) {
+    if ((err as NodeJS.ErrnoException).code === "ENOENT") {
+      return [];
+    }
+    throw err;
+  }
+
+  const entries: HistoryEntry[] = [];
+  for (const line of content.split("\n")) {
+    if (!line.trim()) continue;
+    try {
+      const result = HistoryEntrySchema.safeParse(JSON.parse(line));
+      if (result.success) {
+        entries.push(result.data);
+      }
+    } catch {
+      // Skip malformed lines (e.g. a partial write from a crash)
+    }
+  }
+  return entries;
+}
@@ -14,7 +14,12 @@ export {
   getResearchPath,
   getPlanPath,
   getProgressLogPath,
+  getTestBaselinePath,
+  getHistoryPath,
   getPromptPath,
+  getItemArchiveDir,
+  getRevisionsDir,
+  getTranscriptsDir,
   getRoadmapPath,
   getSkillsPath,
   getBuildMetadataPath,
@@ -35,6 +40,8 @@ export {
   writeItem,
   readPrd,
   writePrd,
+  readTestBaseline,
+  writeTestBaseline,
   readIndex,
   writeIndex,
   readBatchProgress,
@@ -54,3 +61,24 @@ export {
 export { safeWriteJson, cleanupOrphanedTmpFiles } from "./atomic";
 
 export { FileLock, withRetry } from "./lock";
+
+export { appendHistory, readHistory } from "./history";
+
+export {
+  ARTIFACT_NAMES,
+  PHASE_ARTIFACTS,
+  type ArtifactName,
+  getArtifactPath,
+  listRevisions,
+  readRevision,
+  recordRevision,
+} from "./revisions";
+
+export {
+  listTranscripts,
+  readTranscript,
+  createTranscriptWriter,
+  type TranscriptEntry,
+  type TranscriptFile,
+  type TranscriptWriter,
+} from "./transcripts";
@@ -12,11 +12,13 @@ import {
   PrdSchema,
   IndexSchema,
   BatchProgressSchema,
+  TestBaselineSchema,
   type Config,
   type Item,
   type Prd,
   type Index,
   type BatchProgress,
+  type TestBaseline,
 } from "../schemas";
 import { getConfigPath, getIndexPath, getBatchProgressPath } from "./paths";
 import { safeWriteJson } from "./atomic";
@@ -104,6 +106,27 @@ export async function writePrd(itemDir: string, prd: Prd): Promise<void> {
   await writeJsonPretty(prdPath, prd, { useLock: true });
 }
 
+export async function readTestBaseline(
+  itemDir: string,
+): Promise<TestBaseline | null> {
+  try {
+    const baselinePath = path.join(itemDir, "test-baseline.json");
+    return await readJsonWithSchema(baselinePath, TestBaselineSchema);
+  } catch (err) {
+    if (err instanceof FileNotFoundError) {
+      return null;
+    }
+    throw err;
+  }
+}
+
+export async function writeTestBaseline(
+  itemDir: string,
+  baseline: TestBaseline,
+): Promise<void> {
+  await writeJsonPretty(path.join(itemDir, "test-baseline.json"), baseline);
+}
+
 export async function readIndex(root: string): Promise<Index | null> {
   try {
     return await readJsonWithSchema(getIndexPath(root), IndexSchema);
@@ -89,10 +89,34 @@ export function getPlanPath(root: string, id: string): string {
   return path.join(getItemDir(root, id), "plan.md");
 }
 
+export function getReportPath(root: string, id: string): string {
+  return path.join(getItemDir(root, id), "report.md");
+}
+
+export function getTestBaselinePath(root: string, id: string): string {
+  return path.join(getItemDir(root, id), "test-baseline.json");
+}
+
 export function getProgressLogPath(root: string, id: string): string {
   return path.join(getItemDir(root, id), "progress.log");
 }
 
+export function getHistoryPath(root: string, id: string): string {
+  return path.join(getItemDir(root, id), "history.jsonl");
+}
+
+export function getRevisionsDir(root: string, id: string): string {
+  return path.join(getItemDir(root, id), "revisions");
+}
+
+export function getTranscriptsDir(root: string, id: string): string {
+  return path.join(getItemDir(root, id), "transcripts");
+}
+
+export function getItemArchiveDir(root: string, id: string): string {
+  return path.join(getItemDir(root, id), "archive");
+}
+
 export function getPromptPath(root: string, id: string): string {
   return path.join(getItemDir(root, id), "prompt.md");
 }
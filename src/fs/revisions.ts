@@ -0,0 +1,132 @@
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import type { PhaseName } from "../schemas";
+import { FileNotFoundError } from "../errors";
+import {
+  getPlanPath,
+  getPrdPath,
+  getResearchPath,
+  getRevisionsDir,
+} from "./paths";
+
+export const ARTIFACT_NAMES = ["research", "plan", "prd"] as const;
+export type ArtifactName = (typeof ARTIFACT_NAMES)[number];
+
+/**
+ * Artifacts each phase (re)generates.
+ */
+export const PHASE_ARTIFACTS: Partial<Record<PhaseName, ArtifactName[]>> = {
+  research: ["research"],
+  plan: ["plan", "prd"],
+};
+
+export function getArtifactPath(
+  root: string,
+  id: string,
+  artifact: ArtifactName,
+): string {
+  switch (artifact) {
+    case "research":
+      return getResearchPath(root, id);
+    case "plan":
+      return getPlanPath(root, id);
+    case "prd":
+      return getPrdPath(root, id);
+  }
+}
+
+function getRevisionPath(
+  root: string,
+  id: string,
+  artifact: ArtifactName,
+  revision: number,
+): string {
+  const ext = path.extname(getArtifactPath(root, id, artifact));
+  return path.join(getRevisionsDir(root, id), artifact, `${revision}${ext}`);
+}
+
+/**
+ * Revision numbers recorded for an artifact, oldest first.
+ */
+export async function listRevisions(
+  root: string,
+  id: string,
+  artifact: ArtifactName,
+): Promise<number[]> {
+  let entries: string[];
+  try {
+    entries = await fs.readdir(path.join(getRevisionsDir(root, id), artifact));
+  } catch (err) {
+    if ((err as NodeJS.ErrnoException).code === "ENOENT") {
+      return [];
+    }
+    throw err;
+  }
+  return entries
+    .map((name) => /^(\d+)\.\w+$/.exec(name))
+    .filter((match): match is RegExpExecArray => match !== null)
+    .map((match) => Number(match[1]))
+    .sort((a, b) => a - b);
+}
+
+/**
+ * @throws FileNotFoundError if the revision does not exist
+ */
+export async function readRevision(
+  root: string,
+  id: string,
+  artifact: ArtifactName,
+  revision: number,
+): Promise<string> {
+  try {
+    return await fs.readFile(
+      getRevisionPath(root, id, artifact, revision),
+      "utf-8",
+    );
+  } catch (err) {
+    if ((err as NodeJS.ErrnoException).code === "ENOENT") {
+      throw new FileNotFoundError(
+        `Revision ${revision} of ${artifact} not found for ${id}`,
+      );
+    }
+    throw err;
+  }
+}
+
+/**
+ * Snapshot the artifact's current content as the next numbered revision
+ * (revisions/<artifact>/<n>.<ext>). Nothing is written if the artifact
+ * does not exist or is unchanged since the latest revision.
+ *
+ * @returns The new revision number, or null if nothing was recorded
+ */
+export async function recordRevision(
+  root: string,
+  id: string,
+  artifact: ArtifactName,
+): Promise<number | null> {
+  let content: string;
+  try {
+    content = await fs.readFile(getArtifactPath(root, id, artifact), "utf-8");
+  } catch (err) {
+    if ((err as NodeJS.ErrnoException).code === "ENOENT") {
+      return null;
+    }
+    throw err;
+  }
+
+  const revisions = await listRevisions(root, id, artifact);
+  const latest = revisions.at(-1);
+  if (
+    latest !== undefined &&
+    (await readRevision(root, id, artifact, latest)) === content
+  ) {
+    return null;
+  }
+
+  const next = (latest ?? 0) + 1;
+  const revisionPath = getRevisionPath(root, id, artifact, next);
+  await fs.mkdir(path.dirname(revisionPath), { recursive: true });
+  await fs.writeFile(revisionPath, content, "utf-8");
+  return next;
+}
@@ -0,0 +1,156 @@
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import { PhaseNameSchema, type PhaseName } from "../schemas";
+import type { AgentEvent } from "../tui/agentEvents";
+import { getTranscriptsDir } from "./paths";
+
+/**
+ * One line of a transcript: an agent event and when it happened. Tool
+ * results and errors also carry how long the tool ran.
+ */
+export interface TranscriptEntry {
+  ts: string;
+  event: AgentEvent;
+  duration_ms?: number;
+}
+
+export interface TranscriptFile {
+  phase: PhaseName;
+  run: number;
+  path: string;
+}
+
+export interface TranscriptWriter {
+  path: string;
+  write(event: AgentEvent): void;
+  /** Wait for pending writes */
+  close(): Promise<void>;
+}
+
+const TRANSCRIPT_FILE = /^([a-z]+)-(\d+)\.jsonl$/;
+
+/**
+ * An item's transcripts (transcripts/<phase>-<n>.jsonl), in workflow phase
+ * order and then by run.
+ */
+export async function listTranscripts(
+  root: string,
+  id: string,
+  phase?: PhaseName,
+): Promise<TranscriptFile[]> {
+  const dir = getTranscriptsDir(root, id);
+  let entries: string[];
+  try {
+    entries = await fs.readdir(dir);
+  } catch (err) {
+    if ((err as NodeJS.ErrnoException).code === "ENOENT") {
+      return [];
+    }
+    throw err;
+  }
+
+  const phases = PhaseNameSchema.options;
+  return entries
+    .map((name) => TRANSCRIPT_FILE.exec(name))
+    .filter((match): match is RegExpExecArray => match !== null)
+    .filter((match) => phases.includes(match[1] as PhaseName))
+    .map((match) => ({
+      phase: match[1] as PhaseName,
+      run: Number(match[2]),
+      path: path.join(dir, match[0]),
+    }))
+    .filter((file) => !phase || file.phase === phase)
+    .sort(
+      (a, b) =>
+        phases.indexOf(a.phase) - phases.indexOf(b.phase) || a.run - b.run,
+    );
+}
+
+/**
+ * Read a transcript, oldest entry first. Malformed lines are skipped.
+ */
+export async function readTranscript(
+  filePath: string,
+): Promise<TranscriptEntry[]> {
+  const content = await fs.readFile(filePath, "utf-8");
+  const entries: TranscriptEntry[] = [];
+  for (const line of content.split("\n")) {
+    if (!line.trim()) continue;
+    try {
+      const entry = JSON.parse(line);
+      if (typeof entry?.ts === "string" && entry.event?.type) {
+        entries.push(entry);
+      }
+    } catch {
+      // Skip malformed lines (e.g. a partial write from a crash)
+    }
+  }
+  return entries;
+}
+
+/**
+ * Start the next transcript for a phase run. Events are appended in the
+ * order they are written; tool results and errors get the time since their
+ * tool started as `duration_ms`. The first write error is passed to
+ * `onError` and stops further writes.
+ */
+export async function createTranscriptWriter(
+  root: string,
+  id: string,
+  phase: PhaseName,
+  onError: (err: unknown) => void,
+): Promise<TranscriptWriter> {
+  const existing = await listTranscripts(root, id, phase).catch((err) => {
+    onError(err);
+    return [];
+  });
+  const run = (existing.at(-1)?.run ?? 0) + 1;
+  const dir = getTranscriptsDir(root, id);
+  const filePath = path.join(dir, `${phase}-${run}.jsonl`);
+
+  const toolStarts = new Map<string, number>();
+  let failed = false;
+  let pending = fs.mkdir(dir, { recursive: true }).then(
+    () => undefined,
+    (err) => {
+      failed = true;
+      onError(err);
+    },
+  );
+
+  return {
+    path: filePath,
+    write(event) {
+      const now = Date.now();
+      const entry: TranscriptEntry = {
+        ts: new Date(now).toISOString(),
+        event,
+      };
+      if (event.type === "tool_started") {
+        toolStarts.set(event.toolUseId, now);
+      } else if (
+        event.type === "tool_result" ||
+        event.type === "tool_error"
+      ) {
+        const started = toolStarts.get(event.toolUseId);
+        if (started !== undefined) {
+          entry.duration_ms = now - started;
+          toolStarts.delete(event.toolUseId);
+        }
+      }
+      const line = JSON.stringify(entry) + "\n";
+      pending = pending.then(async () => {
+        if (failed) return;
+        try {
+          await fs.appendFile(filePath, line, "utf-8");
+        } catch (err) {
+          failed = true;
+          onError(err);
+        }
+      });
+    },
+    close() {
+      return pending;
+    },
+  };
+}
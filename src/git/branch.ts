@@ -336,3 +336,121 @@ export async function getBranchSyncStatus(options: {
   if (ahead > 0) return "ahead";
   return "synced";
 }
+
+/**
+ * Diff of the working tree against the point where the current branch left
+ * `baseBranch`, i.e. everything the branch changes. Untracked files are not
+ * included. Returns an empty string if there is no common ancestor.
+ */
+export async function getBranchDiff(
+  baseBranch: string,
+  options: { cwd: string; logger: Logger; dryRun?: boolean },
+): Promise<string> {
+  const mergeBase = await runGitCommand(
+    ["merge-base", baseBranch, "HEAD"],
+    options,
+  );
+  if (mergeBase.exitCode !== 0) {
+    options.logger.warn(`No common ancestor of ${baseBranch} and HEAD`);
+    return "";
+  }
+
+  const result = await runGitCommand(["diff", mergeBase.stdout], options);
+  return result.stdout;
+}
+
+export interface MergeResult {
+  merged: boolean;
+  /** Files left conflicted by a failed merge (the merge is aborted) */
+  conflictedFiles: string[];
+}
+
+/**
+ * Create a linked worktree at `worktreePath` on a new branch started from
+ * `startPoint`.
+ */
+export async function addWorktree(
+  worktreePath: string,
+  branchName: string,
+  startPoint: string,
+  options: { cwd: string; logger: Logger; dryRun?: boolean },
+): Promise<void> {
+  const { logger, dryRun = false } = options;
+
+  if (dryRun) {
+    logger.info(
+      `[dry-run] Would create worktree ${worktreePath} on ${branchName}`,
+    );
+    return;
+  }
+
+  const result = await runGitCommand(
+    ["worktree", "add", "-b", branchName, worktreePath, startPoint],
+    options,
+  );
+  if (result.exitCode !== 0) {
+    throw new BranchError(
+      branchName,
+      "create",
+      `Failed to create worktree for ${branchName}: ${result.stderr ?? ""}`,
+    );
+  }
+}
+
+export async function removeWorktree(
+  worktreePath: string,
+  options: { cwd: string; logger: Logger; dryRun?: boolean },
+): Promise<void> {
+  const { logger, dryRun = false } = options;
+
+  if (dryRun) {
+    logger.info(`[dry-run] Would remove worktree ${worktreePath}`);
+    return;
+  }
+
+  const result = await runGitCommand(
+    ["worktree", "remove", "--force", worktreePath],
+    options,
+  );
+  if (result.exitCode !== 0) {
+    logger.warn(`Failed to remove worktree ${worktreePath}`);
+  }
+}
+
+/**
+ * Merge `sourceBranch` into the branch checked out at `cwd` with a merge
+ * commit. On conflict the merge is aborted and the conflicted files returned.
+ */
+export async function mergeBranch(
+  sourceBranch: string,
+  commitMessage: string,
+  options: { cwd: string; logger: Logger; dryRun?: boolean },
+): Promise<MergeResult> {
+  const { logger, dryRun = false } = options;
+
+  if (dryRun) {
+    logger.info(`[dry-run] Would merge ${sourceBranch}`);
+    return { merged: true, conflictedFiles: [] };
+  }
+
+  const result = await runGitCommand(
+    ["merge", "--no-ff", "-m", commitMessage, sourceBranch],
+    options,
+  );
+  if (result.exitCode === 0) {
+    return { merged: true, conflictedFiles: [] };
+  }
+
+  const conflicts = await runGitCommand(
+    ["diff", "--name-only", "--diff-filter=U"],
+    options,
+  );
+  await runGitCommand(["merge", "--abort"], options);
+  return {
+    merged: false,
+    conflictedFiles: conflicts.stdout
+      .split("\n")
+      .map((line) => line.trim())
+      .filter(Boolean),
+  };
+}
@@ -9,13 +9,20 @@ export type {
   QualityCheckOptions,
   QualityCheckResult,
   SecretScanResult,
+  TestCommandResult,
 } from "./quality";
 export {
   runPrePushQualityGates,
   runQualityChecks,
   runSecretScan,
+  runTestCommand,
   scanForSecrets,
 } from "./quality";
+export {
+  parseJUnitXml,
+  parseTapOutput,
+  parseTestOutput,
+} from "./testReports";
 
 // Core types
 export interface GitOptions {
@@ -156,7 +163,11 @@ export {
 } from "./validation";
 
 // Re-export branch module
-export type { BranchResult, BranchCleanupResult } from "./branch";
+export type {
+  BranchResult,
+  BranchCleanupResult,
+  MergeResult,
+} from "./branch";
 export {
   getCurrentBranch,
   getBranchSha,
@@ -167,6 +178,10 @@ export {
   pushBranch,
   mergeAndPushToBase,
   getBranchSyncStatus,
+  getBranchDiff,
+  addWorktree,
+  removeWorktree,
+  mergeBranch,
 } from "./branch";
 
 // Re-export PR module
@@ -206,11 +221,15 @@ export type {
   StoryScopeResult,
   ScopeViolation,
   ViolationType,
+  ScopeBaseline,
 } from "./scope";
 export {
   DEFAULT_SCOPE_OPTIONS,
   getDiffStats,
   getWorkingTreeDiffStats,
+  captureScopeBaseline,
+  getStoryDiffStats,
+  resetToScopeBaseline,
   validateStoryScope,
   formatScopeViolations,
   isApproachingThreshold,
@@ -1,7 +1,9 @@
 import { spawn } from "node:child_process";
 import type { Logger } from "../logging";
 import type { PrChecksResolved } from "../config";
+import type { TestOutcome } from "../schemas";
 import { runGitCommand } from "./index";
+import { parseTestOutput } from "./testReports";
 
 export interface QualityCheckOptions {
   cwd: string;
@@ -14,6 +16,11 @@ export interface QualityCheckResult {
   success: boolean;
   errors: string[];
   skipped: string[];
+  /**
+   * Per-test outcomes parsed from TAP or JUnit XML output. A command whose
+   * output is in neither format is reported as one test named after it.
+   */
+  tests?: TestOutcome[];
 }
 
 export interface RunCommandOptions {
@@ -26,7 +33,13 @@ async function runCommand(
   command: string,
   args: string[],
   options: RunCommandOptions,
-): Promise<{ stdout: string; exitCode: number }> {
+): Promise<{
+  stdout: string;
+  stderr?: string;
+  exitCode: number;
+  /** Set when the command could not be started at all */
+  spawnError?: string;
+}> {
   const { cwd, logger, dryRun = false } = options;
 
   if (dryRun) {
@@ -44,13 +57,13 @@ async function runCommand(
         cwd,
         stdio: ["pipe", "pipe", "pipe"],
       });
-    } catch {
-      resolve({ stdout: "", exitCode: 1 });
+    } catch (err) {
+      resolve({ stdout: "", exitCode: 1, spawnError: String(err) });
       return;
     }
 
     if (!proc || typeof proc.on !== "function") {
-      resolve({ stdout: "", exitCode: 1 });
+      resolve({ stdout: "", exitCode: 1, spawnError: "spawn failed" });
       return;
     }
 
@@ -69,18 +82,19 @@ async function runCommand(
       if (code !== 0 && stderr) {
         logger.debug(`Command stderr: ${stderr}`);
       }
-      resolve({ stdout: stdout.trim(), exitCode: code ?? 0 });
+      resolve({ stdout: stdout.trim(), stderr, exitCode: code ?? 0 });
     });
 
     proc.on("error", (err) => {
       logger.debug(`Command error: ${err.message}`);
-      resolve({ stdout: "", exitCode: 1 });
+      resolve({ stdout: "", exitCode: 1, spawnError: err.message });
     });
   });
 }
 
 /**
  * Run the configured quality check commands (tests, lint, typecheck, etc.)
+ * and collect per-test outcomes from their output.
  *
  * @param options - Quality check options including commands to run
  * @returns Result indicating success/failure and any errors
@@ -91,10 +105,16 @@ export async function runQualityChecks(
   const { cwd, logger, dryRun, checks } = options;
   const errors: string[] = [];
   const skipped: string[] = [];
+  const tests: TestOutcome[] = [];
 
   if (checks.commands.length === 0) {
     logger.info("No quality checks configured, skipping");
-    return { success: true, errors, skipped: ["No commands configured"] };
+    return {
+      success: true,
+      errors,
+      skipped: ["No commands configured"],
+      tests,
+    };
   }
 
   logger.info(`Running ${checks.commands.length} quality check(s)`);
@@ -109,6 +129,18 @@ export async function runQualityChecks(
 
     const result = await runCommand(exe, args, { cwd, logger, dryRun });
 
+    const parsed = parseTestOutput(
+      [result.stdout, result.stderr].filter(Boolean).join("\n"),
+    );
+    if (parsed.length > 0) {
+      tests.push(...parsed);
+    } else {
+      tests.push({
+        name: command,
+        status: result.exitCode === 0 ? "passed" : "failed",
+      });
+    }
+
     if (result.exitCode !== 0) {
       const error = `Quality check failed: ${command}`;
       logger.error(error);
@@ -125,6 +157,35 @@ export async function runQualityChecks(
     success: errors.length === 0,
     errors,
     skipped,
+    tests,
+  };
+}
+
+export interface TestCommandResult {
+  passed: boolean;
+  exitCode: number;
+  /** Combined stdout and stderr */
+  output: string;
+  /** Set when the command could not be started, so its result means nothing */
+  error?: string;
+}
+
+/**
+ * Run a single test command (e.g. a bug reproduction test) the same way
+ * quality check commands are run.
+ */
+export async function runTestCommand(
+  command: string,
+  options: RunCommandOptions,
+): Promise<TestCommandResult> {
+  const [exe, ...args] = command.trim().split(/\s+/);
+  options.logger.info(`Running: ${command}`);
+  const result = await runCommand(exe, args, options);
+  return {
+    passed: result.exitCode === 0 && !result.spawnError,
+    exitCode: result.exitCode,
+    output: [result.stdout, result.stderr?.trim()].filter(Boolean).join("\n"),
+    error: result.spawnError,
   };
 }
 
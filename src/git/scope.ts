@@ -5,9 +5,12 @@
  * Provides functions to calculate diff statistics and validate against scope limits.
  */
 
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
 import type { Logger } from "../logging";
 import { runGitCommand, type GitOptions } from "./index";
 import type { StoryScopeConfig } from "../schemas";
+import { GitError } from "../errors";
 
 /**
  * Types of scope violations
@@ -487,3 +490,140 @@ export function configToOptions(config: StoryScopeConfig): StoryScopeOptions {
     warningThreshold: 80, // Default warning threshold
   };
 }
+
+/**
+ * State of the working tree when a story started, for checking and undoing
+ * what the agent changed during the story.
+ */
+export interface ScopeBaseline {
+  /** Commit checked out at the start */
+  head: string;
+  /** Commit of the tracked files as they were, uncommitted changes included */
+  snapshot: string;
+  /** Untracked files present at the start */
+  untracked: string[];
+}
+
+// wreckit's own item files change during every run and are not the story's
+const NOT_WRECKIT = ":(top,exclude).wreckit";
+
+async function listUntrackedFiles(options: GitOptions): Promise<string[]> {
+  const result = await runGitCommand(
+    [
+      "ls-files",
+      "--others",
+      "--exclude-standard",
+      "-z",
+      "--",
+      ".",
+      NOT_WRECKIT,
+    ],
+    options,
+  );
+  if (result.exitCode !== 0) {
+    throw new GitError("Failed to list untracked files");
+  }
+  return result.stdout.split("\0").filter(Boolean);
+}
+
+/**
+ * Parse git diff --numstat output. Binary files count as one line.
+ *
+ * Format: "12\t3\tpath/to/file.ts"
+ */
+export function parseNumstat(stdout: string): FileDiff[] {
+  const fileDiffs: FileDiff[] = [];
+  for (const line of stdout.split("\n")) {
+    const match = line.match(/^(\d+|-)\t(\d+|-)\t(.+)$/);
+    if (!match) continue;
+    const lines =
+      match[1] === "-" ? 1 : parseInt(match[1], 10) + parseInt(match[2], 10);
+    fileDiffs.push({ path: match[3], lines, bytes: lines * 40 });
+  }
+  return fileDiffs;
+}
+
+/**
+ * Record the working tree before a story runs. Uncommitted changes (e.g.
+ * from earlier stories) are captured with `git stash create`, which leaves
+ * the working tree and stash list alone.
+ */
+export async function captureScopeBaseline(
+  options: GitOptions,
+): Promise<ScopeBaseline> {
+  const head = await runGitCommand(["rev-parse", "HEAD"], options);
+  if (head.exitCode !== 0) {
+    throw new GitError("Failed to get SHA for HEAD");
+  }
+  const stash = await runGitCommand(["stash", "create"], options);
+  return {
+    head: head.stdout.trim(),
+    snapshot: stash.stdout.trim() || head.stdout.trim(),
+    untracked: await listUntrackedFiles(options),
+  };
+}
+
+/**
+ * Diff statistics of what changed since the baseline: tracked files,
+ * including any commits made since, and new untracked files at their full
+ * size. wreckit's item files are left out.
+ */
+export async function getStoryDiffStats(
+  baseline: ScopeBaseline,
+  options: GitOptions,
+): Promise<DiffStats> {
+  const numstat = await runGitCommand(
+    ["diff", "--numstat", baseline.snapshot, "--", ".", NOT_WRECKIT],
+    options,
+  );
+  const fileDiffs = parseNumstat(numstat.stdout);
+
+  const before = new Set(baseline.untracked);
+  for (const file of await listUntrackedFiles(options)) {
+    if (before.has(file)) continue;
+    const content = await fs
+      .readFile(path.join(options.cwd, file))
+      .catch(() => null);
+    if (!content) continue;
+    const lines = content.toString("utf-8").split("\n").length;
+    fileDiffs.push({ path: file, lines, bytes: content.length });
+  }
+
+  return {
+    totalLines: fileDiffs.reduce((sum, f) => sum + f.lines, 0),
+    totalFiles: fileDiffs.length,
+    totalBytes: fileDiffs.reduce((sum, f) => sum + f.bytes, 0),
+    fileDiffs,
+  };
+}
+
+/**
+ * Put the working tree back as it was at the baseline: commits made since
+ * are undone, tracked files restored and new untracked files removed.
+ * wreckit's item files and untracked files that already existed are kept.
+ */
+export async function resetToScopeBaseline(
+  baseline: ScopeBaseline,
+  options: GitOptions,
+): Promise<void> {
+  const steps = [
+    ["reset", "-q", baseline.head],
+    ["checkout", baseline.snapshot, "--", ".", NOT_WRECKIT],
+    ["reset", "-q"],
+  ];
+  for (const args of steps) {
+    const result = await runGitCommand(args, options);
+    if (result.exitCode !== 0) {
+      throw new GitError(
+        `Failed to reset to story start (git ${args.join(" ")}): ${result.stderr ?? result.stdout}`,
+      );
+    }
+  }
+
+  const before = new Set(baseline.untracked);
+  for (const file of await listUntrackedFiles(options)) {
+    if (!before.has(file)) {
+      await fs.rm(path.join(options.cwd, file), { force: true });
+    }
+  }
+}
@@ -101,15 +101,21 @@ export function parseGitStatusPorcelain(
 /**
  * Get current git status as structured data
  *
- * @param options - Git options
+ * @param options - Git options; `untrackedFiles: "all"` lists the files
+ *   inside untracked directories instead of the directories themselves
  * @returns Array of file changes
  */
 export async function getGitStatus(options: {
   cwd: string;
   logger: Logger;
   dryRun?: boolean;
+  untrackedFiles?: "normal" | "all";
 }): Promise<GitFileChange[]> {
-  const result = await runGitCommand(["status", "--porcelain"], options);
+  const args = ["status", "--porcelain"];
+  if (options.untrackedFiles === "all") {
+    args.push("--untracked-files=all");
+  }
+  const result = await runGitCommand(args, options);
   return parseGitStatusPorcelain(result.stdout, options.cwd);
 }
 
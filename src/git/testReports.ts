@@ -0,0 +1,88 @@
+import type { TestOutcome } from "../schemas";
+
+/**
+ * Parse TAP (Test Anything Protocol) output into per-test outcomes.
+ *
+ * Output without a plan line (`1..N`) is not treated as TAP. Indented
+ * subtest lines (TAP 13/14, node --test) are included, and tests marked
+ * `# SKIP` or `# TODO` count as skipped.
+ */
+export function parseTapOutput(output: string): TestOutcome[] {
+  const lines = output.split("\n");
+  if (!lines.some((line) => /^\s*\d+\.\.\d+/.test(line))) {
+    return [];
+  }
+
+  const outcomes: TestOutcome[] = [];
+  for (const line of lines) {
+    const match = line.match(/^\s*(not ok|ok)\b(?:\s+\d+)?\s*(?:-\s*)?(.*)$/);
+    if (!match) continue;
+
+    // An unescaped '#' starts a directive such as "# SKIP" or "# time=3ms"
+    const [description, ...directive] = match[2].split(/(?<!\\)#/);
+    const name = description.replace(/\\#/g, "#").trim();
+    if (!name) continue;
+
+    const skipped = /^\s*(SKIP|TODO)\b/i.test(directive.join("#"));
+    const status: TestOutcome["status"] = skipped
+      ? "skipped"
+      : match[1] === "ok"
+        ? "passed"
+        : "failed";
+    outcomes.push({ name, status });
+  }
+  return outcomes;
+}
+
+function decodeXml(value: string): string {
+  return value
+    .replace(/&lt;/g, "<")
+    .replace(/&gt;/g, ">")
+    .replace(/&quot;/g, '"')
+    .replace(/&apos;/g, "'")
+    .replace(/&amp;/g, "&");
+}
+
+function getAttribute(attributes: string, name: string): string | undefined {
+  const match = attributes.match(new RegExp(`\\b${name}\\s*=\\s*"([^"]*)"`));
+  return match ? decodeXml(match[1]) : undefined;
+}
+
+/**
+ * Parse JUnit XML into per-test outcomes. Test names are prefixed with
+ * their `classname` when one is given.
+ */
+export function parseJUnitXml(xml: string): TestOutcome[] {
+  const outcomes: TestOutcome[] = [];
+  const testcase = /<testcase\b([^>]*?)(?:\/>|>([\s\S]*?)<\/testcase>)/g;
+
+  for (const match of xml.matchAll(testcase)) {
+    const attributes = match[1];
+    const body = match[2] ?? "";
+    const name = getAttribute(attributes, "name");
+    if (!name) continue;
+
+    const classname = getAttribute(attributes, "classname");
+    const status: TestOutcome["status"] = /<(failure|error)\b/.test(body)
+      ? "failed"
+      : /<skipped\b/.test(body)
+        ? "skipped"
+        : "passed";
+    outcomes.push({
+      name: classname ? `${classname} > ${name}` : name,
+      status,
+    });
+  }
+  return outcomes;
+}
+
+/**
+ * Parse test reporter output, detecting JUnit XML or TAP. Returns an empty
+ * list when the output is in neither format.
+ */
+export function parseTestOutput(output: string): TestOutcome[] {
+  if (/<testcase\b/.test(output)) {
+    return parseJUnitXml(output);
+  }
+  return parseTapOutput(output);
+}
@@ -9,14 +9,22 @@ import { ideasCommand } from "./commands/ideas";
 import { statusCommand } from "./commands/status";
 import { listCommand } from "./commands/list";
 import { showCommand } from "./commands/show";
+import { logCommand } from "./commands/log";
+import { routingCommand } from "./commands/routing";
+import { transcriptCommand } from "./commands/transcript";
 import { runPhaseCommand } from "./commands/phase";
 import { runCommand } from "./commands/run";
 import { orchestrateAll, orchestrateNext } from "./commands/orchestrator";
 import { doctorCommand } from "./commands/doctor";
 import { initCommand } from "./commands/init";
 import { rollbackCommand } from "./commands/rollback";
+import { reopenCommand } from "./commands/reopen";
+import { parkCommand, resumeCommand } from "./commands/park";
+import { approveCommand, rejectCommand } from "./commands/approve";
+import { diffArtifactCommand } from "./commands/diff-artifact";
 import { strategyCommand } from "./commands/strategy";
 import { executeRoadmapCommand } from "./commands/execute-roadmap";
+import { spikeFollowupsCommand } from "./commands/spike-followups";
 import {
   spriteStartCommand,
   spriteListCommand,
@@ -37,6 +45,7 @@ import {
 import { runOnboardingIfNeeded } from "./onboarding";
 import { resolveId } from "./domain/resolveId";
 import { findRepoRoot, resolveCwd } from "./fs/paths";
+import { startRecording } from "./agent/cassette";
 
 export const program = new Command();
 
@@ -69,6 +78,10 @@ program
   .option(
     "--sandbox",
     "Run in isolated Sprite VM with automatic cleanup (implies --agent sprite)",
+  )
+  .option(
+    "--record <dir>",
+    "Record every agent run to cassettes in <dir> for the replay agent",
   );
 
 program.action(async () => {
@@ -117,6 +130,12 @@ program.action(async () => {
       if (result.remaining.length > 0) {
         logger.info(`Remaining: ${result.remaining.length} items`);
       }
+      if (result.awaitingApproval.length > 0) {
+        logger.info(
+          `Awaiting approval: ${result.awaitingApproval.length} items (wreckit approve <id> / wreckit reject <id> --comment ...)`,
+        );
+        result.awaitingApproval.forEach((id) => logger.info(`  - ${id}`));
+      }
 
       if (result.failed.length > 0) {
         process.exit(1);
@@ -137,6 +156,14 @@ program
   .command("ideas")
   .description("Ingest ideas from stdin, file, or interactive interview")
   .option("-f, --file <path>", "Read ideas from file instead of stdin")
+  .option(
+    "--pipeline <name>",
+    "Workflow pipeline for new items (default: config default_pipeline)",
+  )
+  .option(
+    "--kind <kind>",
+    "Kind of work: feature, bug, refactor or spike (default: feature)",
+  )
   .action(async (options, cmd) => {
     const globalOpts = cmd.optsWithGlobals();
     await executeCommand(
@@ -144,6 +171,8 @@ program
         await ideasCommand(
           {
             file: options.file,
+            pipeline: options.pipeline,
+            kind: options.kind,
             dryRun: globalOpts.dryRun,
             cwd: resolveCwd(globalOpts.cwd),
             verbose: globalOpts.verbose,
@@ -235,6 +264,126 @@ program
     );
   });
 
+program
+  .command("log <id>")
+  .description("Show an item's state and phase history as a timeline")
+  .option("--json", "Output as JSON")
+  .action(async (id, options, cmd) => {
+    const globalOpts = cmd.optsWithGlobals();
+    await executeCommand(
+      async () => {
+        const cwd = resolveCwd(globalOpts.cwd);
+        const root = findRepoRoot(cwd);
+        const resolvedId = await resolveId(root, id);
+        await logCommand(resolvedId, { json: options.json, cwd }, logger);
+      },
+      logger,
+      {
+        verbose: globalOpts.verbose,
+        quiet: globalOpts.quiet,
+        cwd: resolveCwd(globalOpts.cwd),
+      },
+    );
+  });
+
+program
+  .command("transcript <id>")
+  .description("Show the agent transcripts recorded for an item's phase runs")
+  .option("--phase <phase>", "Only show transcripts of this phase")
+  .option("--markdown", "Export as Markdown")
+  .option("--output <file>", "Write the Markdown export to a file")
+  .option("--json", "Output as JSON")
+  .action(async (id, options, cmd) => {
+    const globalOpts = cmd.optsWithGlobals();
+    await executeCommand(
+      async () => {
+        const cwd = resolveCwd(globalOpts.cwd);
+        const root = findRepoRoot(cwd);
+        const resolvedId = await resolveId(root, id);
+        await transcriptCommand(
+          resolvedId,
+          {
+            phase: options.phase,
+            markdown: options.markdown,
+            output: options.output,
+            json: options.json,
+            cwd,
+          },
+          logger,
+        );
+      },
+      logger,
+      {
+        verbose: globalOpts.verbose,
+        quiet: globalOpts.quiet,
+        cwd: resolveCwd(globalOpts.cwd),
+      },
+    );
+  });
+
+program
+  .command("routing")
+  .description("Show the agent, fallbacks and limits each phase runs with")
+  .option("--json", "Output as JSON")
+  .action(async (options, cmd) => {
+    const globalOpts = cmd.optsWithGlobals();
+    await executeCommand(
+      async () => {
+        await routingCommand(
+          {
+            json: options.json,
+            cwd: resolveCwd(globalOpts.cwd),
+            agentKind: globalOpts.rlm ? "rlm" : globalOpts.agent,
+            sandbox: globalOpts.sandbox,
+          },
+          logger,
+        );
+      },
+      logger,
+      {
+        verbose: globalOpts.verbose,
+        quiet: globalOpts.quiet,
+        cwd: resolveCwd(globalOpts.cwd),
+      },
+    );
+  });
+
+program
+  .command("diff-artifact <id> <artifact>")
+  .description(
+    "Compare two revisions of an item's research, plan or prd artifact",
+  )
+  .option("--from <n>", "Older revision (default: the one before --to)")
+  .option("--to <n>", "Newer revision (default: latest)")
+  .option("--json", "Output as JSON")
+  .action(async (id, artifact, options, cmd) => {
+    const globalOpts = cmd.optsWithGlobals();
+    await executeCommand(
+      async () => {
+        const cwd = resolveCwd(globalOpts.cwd);
+        const root = findRepoRoot(cwd);
+        const resolvedId = await resolveId(root, id);
+        await diffArtifactCommand(
+          resolvedId,
+          artifact,
+          {
+            from: options.from ? parseInt(options.from, 10) : undefined,
+            to: options.to ? parseInt(options.to, 10) : undefined,
+            json: options.json,
+            cwd,
+          },
+          logger,
+        );
+      },
+      logger,
+      {
+        verbose: globalOpts.verbose,
+        quiet: globalOpts.quiet,
+        cwd: resolveCwd(globalOpts.cwd),
+      },
+    );
+  });
+
 program
   .command("research <id>")
   .description("Run research phase: idea → researched")
@@ -268,6 +417,39 @@ program
     );
   });
 
+program
+  .command("reproduce <id>")
+  .description("Run reproduce phase for a bug: researched → reproduced")
+  .option("--force", "Write a new reproduction even if one is recorded")
+  .action(async (id, options, cmd) => {
+    const globalOpts = cmd.optsWithGlobals();
+    await executeCommand(
+      async () => {
+        const cwd = resolveCwd(globalOpts.cwd);
+        const root = findRepoRoot(cwd);
+        const resolvedId = await resolveId(root, id);
+        await runPhaseCommand(
+          "reproduce",
+          resolvedId,
+          {
+            force: options.force,
+            dryRun: globalOpts.dryRun,
+            cwd,
+            sandbox: globalOpts.sandbox,
+          },
+          logger,
+        );
+      },
+      logger,
+      {
+        verbose: globalOpts.verbose,
+        quiet: globalOpts.quiet,
+        dryRun: globalOpts.dryRun,
+        cwd: resolveCwd(globalOpts.cwd),
+      },
+    );
+  });
+
 program
   .command("plan <id>")
   .description("Run plan phase: researched → planned")
@@ -301,6 +483,39 @@ program
     );
   });
 
+program
+  .command("tests <id>")
+  .description("Run tests phase: planned → tests_written (write failing tests)")
+  .option("--force", "Write new story tests even if they are recorded")
+  .action(async (id, options, cmd) => {
+    const globalOpts = cmd.optsWithGlobals();
+    await executeCommand(
+      async () => {
+        const cwd = resolveCwd(globalOpts.cwd);
+        const root = findRepoRoot(cwd);
+        const resolvedId = await resolveId(root, id);
+        await runPhaseCommand(
+          "tests",
+          resolvedId,
+          {
+            force: options.force,
+            dryRun: globalOpts.dryRun,
+            cwd,
+            sandbox: globalOpts.sandbox,
+          },
+          logger,
+        );
+      },
+      logger,
+      {
+        verbose: globalOpts.verbose,
+        quiet: globalOpts.quiet,
+        dryRun: globalOpts.dryRun,
+        cwd: resolveCwd(globalOpts.cwd),
+      },
+    );
+  });
+
 program
   .command("implement <id>")
   .description("Run implement phase: planned → implementing")
@@ -427,6 +642,39 @@ program
     );
   });
 
+program
+  .command("document <id>")
+  .description("Run document phase: critique → documented (docs and changelog)")
+  .option("--force", "Update the docs again even if already documented")
+  .action(async (id, options, cmd) => {
+    const globalOpts = cmd.optsWithGlobals();
+    await executeCommand(
+      async () => {
+        const cwd = resolveCwd(globalOpts.cwd);
+        const root = findRepoRoot(cwd);
+        const resolvedId = await resolveId(root, id);
+        await runPhaseCommand(
+          "document",
+          resolvedId,
+          {
+            force: options.force,
+            dryRun: globalOpts.dryRun,
+            cwd,
+            sandbox: globalOpts.sandbox,
+          },
+          logger,
+        );
+      },
+      logger,
+      {
+        verbose: globalOpts.verbose,
+        quiet: globalOpts.quiet,
+        dryRun: globalOpts.dryRun,
+        cwd: resolveCwd(globalOpts.cwd),
+      },
+    );
+  });
+
 program
   .command("rollback <id>")
   .description("Rollback a direct-merge item to its pre-merge state")
@@ -461,6 +709,238 @@ program
     );
   });
 
+program
+  .command("reopen <id>")
+  .description(
+    "Send an item back to an earlier state, archiving superseded artifacts",
+  )
+  .requiredOption("--to <state>", "Target state (e.g. researched, planned)")
+  .requiredOption("--reason <text>", "Why the item is being reopened")
+  .action(async (id, options, cmd) => {
+    const globalOpts = cmd.optsWithGlobals();
+    await executeCommand(
+      async () => {
+        const cwd = resolveCwd(globalOpts.cwd);
+        const root = findRepoRoot(cwd);
+        const resolvedId = await resolveId(root, id);
+        await reopenCommand(
+          resolvedId,
+          {
+            to: options.to,
+            reason: options.reason,
+            dryRun: globalOpts.dryRun,
+            cwd,
+          },
+          logger,
+        );
+      },
+      logger,
+      {
+        verbose: globalOpts.verbose,
+        quiet: globalOpts.quiet,
+        dryRun: globalOpts.dryRun,
+        cwd: resolveCwd(globalOpts.cwd),
+      },
+    );
+  });
+
+program
+  .command("block <id>")
+  .description("Mark an item blocked on an external decision")
+  .requiredOption("--reason <text>", "Why the item is blocked")
+  .option(
+    "--until <condition>",
+    "Condition under which the item can continue",
+  )
+  .action(async (id, options, cmd) => {
+    const globalOpts = cmd.optsWithGlobals();
+    await executeCommand(
+      async () => {
+        const cwd = resolveCwd(globalOpts.cwd);
+        const root = findRepoRoot(cwd);
+        const resolvedId = await resolveId(root, id);
+        await parkCommand(
+          resolvedId,
+          "blocked",
+          {
+            reason: options.reason,
+            until: options.until,
+            dryRun: globalOpts.dryRun,
+            cwd,
+          },
+          logger,
+        );
+      },
+      logger,
+      {
+        verbose: globalOpts.verbose,
+        quiet: globalOpts.quiet,
+        dryRun: globalOpts.dryRun,
+        cwd: resolveCwd(globalOpts.cwd),
+      },
+    );
+  });
+
+program
+  .command("pause <id>")
+  .description("Pause an item so the orchestrator skips it")
+  .option("--reason <text>", "Why the item is paused")
+  .action(async (id, options, cmd) => {
+    const globalOpts = cmd.optsWithGlobals();
+    await executeCommand(
+      async () => {
+        const cwd = resolveCwd(globalOpts.cwd);
+        const root = findRepoRoot(cwd);
+        const resolvedId = await resolveId(root, id);
+        await parkCommand(
+          resolvedId,
+          "paused",
+          {
+            reason: options.reason,
+            dryRun: globalOpts.dryRun,
+            cwd,
+          },
+          logger,
+        );
+      },
+      logger,
+      {
+        verbose: globalOpts.verbose,
+        quiet: globalOpts.quiet,
+        dryRun: globalOpts.dryRun,
+        cwd: resolveCwd(globalOpts.cwd),
+      },
+    );
+  });
+
+program
+  .command("wontfix <id>")
+  .description("Abandon an item without deleting it")
+  .requiredOption("--reason <text>", "Why the item is not being done")
+  .action(async (id, options, cmd) => {
+    const globalOpts = cmd.optsWithGlobals();
+    await executeCommand(
+      async () => {
+        const cwd = resolveCwd(globalOpts.cwd);
+        const root = findRepoRoot(cwd);
+        const resolvedId = await resolveId(root, id);
+        await parkCommand(
+          resolvedId,
+          "wontfix",
+          {
+            reason: options.reason,
+            dryRun: globalOpts.dryRun,
+            cwd,
+          },
+          logger,
+        );
+      },
+      logger,
+      {
+        verbose: globalOpts.verbose,
+        quiet: globalOpts.quiet,
+        dryRun: globalOpts.dryRun,
+        cwd: resolveCwd(globalOpts.cwd),
+      },
+    );
+  });
+
+program
+  .command("resume <id>")
+  .description(
+    "Return a blocked, paused or wontfix item to its previous state",
+  )
+  .action(async (id, _options, cmd) => {
+    const globalOpts = cmd.optsWithGlobals();
+    await executeCommand(
+      async () => {
+        const cwd = resolveCwd(globalOpts.cwd);
+        const root = findRepoRoot(cwd);
+        const resolvedId = await resolveId(root, id);
+        await resumeCommand(
+          resolvedId,
+          { dryRun: globalOpts.dryRun, cwd },
+          logger,
+        );
+      },
+      logger,
+      {
+        verbose: globalOpts.verbose,
+        quiet: globalOpts.quiet,
+        dryRun: globalOpts.dryRun,
+        cwd: resolveCwd(globalOpts.cwd),
+      },
+    );
+  });
+
+program
+  .command("approve <id>")
+  .description("Approve an item's gated phase output so it can continue")
+  .option("--comment <text>", "Optional note recorded with the approval")
+  .option("--by <name>", "Reviewer name (defaults to the OS user)")
+  .action(async (id, options, cmd) => {
+    const globalOpts = cmd.optsWithGlobals();
+    await executeCommand(
+      async () => {
+        const cwd = resolveCwd(globalOpts.cwd);
+        const root = findRepoRoot(cwd);
+        const resolvedId = await resolveId(root, id);
+        await approveCommand(
+          resolvedId,
+          {
+            comment: options.comment,
+            by: options.by,
+            dryRun: globalOpts.dryRun,
+            cwd,
+          },
+          logger,
+        );
+      },
+      logger,
+      {
+        verbose: globalOpts.verbose,
+        quiet: globalOpts.quiet,
+        dryRun: globalOpts.dryRun,
+        cwd: resolveCwd(globalOpts.cwd),
+      },
+    );
+  });
+
+program
+  .command("reject <id>")
+  .description(
+    "Reject an item's gated phase output and send it back with feedback",
+  )
+  .requiredOption("--comment <text>", "What must change before approval")
+  .option("--by <name>", "Reviewer name (defaults to the OS user)")
+  .action(async (id, options, cmd) => {
+    const globalOpts = cmd.optsWithGlobals();
+    await executeCommand(
+      async () => {
+        const cwd = resolveCwd(globalOpts.cwd);
+        const root = findRepoRoot(cwd);
+        const resolvedId = await resolveId(root, id);
+        await rejectCommand(
+          resolvedId,
+          {
+            comment: options.comment,
+            by: options.by,
+            dryRun: globalOpts.dryRun,
+            cwd,
+          },
+          logger,
+        );
+      },
+      logger,
+      {
+        verbose: globalOpts.verbose,
+        quiet: globalOpts.quiet,
+        dryRun: globalOpts.dryRun,
+        cwd: resolveCwd(globalOpts.cwd),
+      },
+    );
+  });
+
 // ============================================================================
 // Sprite Commands (Item 073)
 // ============================================================================
@@ -856,6 +1336,32 @@ program
     );
   });
 
+program
+  .command("spike-followups <id>")
+  .description("Create items for the follow-up ideas in a spike's report")
+  .action(async (id, _options, cmd) => {
+    const globalOpts = cmd.optsWithGlobals();
+    await executeCommand(
+      async () => {
+        const cwd = resolveCwd(globalOpts.cwd);
+        const root = findRepoRoot(cwd);
+        const resolvedId = await resolveId(root, id);
+        await spikeFollowupsCommand(
+          resolvedId,
+          { dryRun: globalOpts.dryRun, cwd },
+          logger,
+        );
+      },
+      logger,
+      {
+        verbose: globalOpts.verbose,
+        quiet: globalOpts.quiet,
+        dryRun: globalOpts.dryRun,
+        cwd: resolveCwd(globalOpts.cwd),
+      },
+    );
+  });
+
 program
   .command("learn [patterns...]")
   .description(
@@ -1149,13 +1655,16 @@ async function main(): Promise<void> {
     process.exit(1);
   });
 
-  program.hook("preAction", (thisCommand) => {
+  program.hook("preAction", async (thisCommand) => {
     const opts = thisCommand.opts();
     initLogger({
       verbose: opts.verbose,
       quiet: opts.quiet,
       debug: opts.debug,
     });
+    if (opts.record) {
+      await startRecording(path.resolve(resolveCwd(opts.cwd), opts.record));
+    }
   });
 
   try {
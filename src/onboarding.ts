@@ -8,6 +8,7 @@ import { initCommand, NotGitRepoError } from "./commands/init";
 import { scanItems } from "./domain/indexing";
 import { runIdeaInterview, runSimpleInterview } from "./domain/ideas-interview";
 import { persistItems } from "./domain/ideas";
+import { loadConfig } from "./config";
 
 export interface OnboardingResult {
   proceed: boolean;
@@ -86,7 +87,9 @@ async function promptFirstIdea(logger: Logger, root: string): Promise<boolean> {
   }
 
   // Persist the ideas
-  const result = await persistItems(root, ideas);
+  const result = await persistItems(root, ideas, {
+    config: await loadConfig(root),
+  });
 
   if (result.created.length > 0) {
     outro(
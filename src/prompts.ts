@@ -2,11 +2,15 @@ import * as fs from "node:fs/promises";
 import * as path from "node:path";
 import { fileURLToPath } from "node:url";
 import { getPromptsDir, getWreckitDir } from "./fs/paths";
+import type { ItemKind } from "./schemas";
 
 export type PromptName =
   | "research"
+  | "reproduce"
   | "plan"
+  | "tests"
   | "implement"
+  | "document"
   | "ideas"
   | "pr"
   | "strategy"
@@ -33,39 +37,100 @@ export interface PromptVariables {
   skill_context?: string;
   // Add scope limits context (Item 084)
   scope_limits?: string;
+  // Story the implement loop selected (dependencies done)
+  current_story?: string;
+  // Set for story agents in parallel worktrees, which leave bookkeeping alone
+  parallel_worktree?: boolean;
+  // Rejection comments on this phase's previous output (approval gates)
+  review_feedback?: string;
+  // Item kind and, for bugs, the recorded reproduction test
+  kind?: string;
+  reproduction_command?: string;
+  reproduction_tests?: string;
+  // Failing tests written by the tests phase, to be turned green
+  tests_command?: string;
+  story_tests?: string;
+  // Branch diff and documentation globs for the document phase
+  branch_diff?: string;
+  doc_paths?: string;
 }
 
-function getPromptTemplatePath(root: string, name: PromptName): string {
+/**
+ * Custom template names (e.g. from a pipeline stage) resolve only from
+ * .wreckit/prompts/; bundled defaults exist for PromptName values.
+ */
+export type PromptTemplateName = PromptName | (string & {});
+
+function getPromptTemplatePath(
+  root: string,
+  name: PromptTemplateName,
+): string {
   return path.join(getPromptsDir(root), `${name}.md`);
 }
 
-function getBundledPromptPath(name: PromptName): string {
+function getBundledPromptPath(name: PromptTemplateName): string {
   const __dirname = path.dirname(fileURLToPath(import.meta.url));
   return path.join(__dirname, "prompts", `${name}.md`);
 }
 
-export async function getDefaultTemplate(name: PromptName): Promise<string> {
+export async function getDefaultTemplate(
+  name: PromptTemplateName,
+): Promise<string> {
   const bundledPath = getBundledPromptPath(name);
   return fs.readFile(bundledPath, "utf-8");
 }
 
-export async function loadPromptTemplate(
-  root: string,
-  name: PromptName,
-): Promise<string> {
-  const promptPath = getPromptTemplatePath(root, name);
+function isMissing(err: unknown): boolean {
+  return (err as NodeJS.ErrnoException).code === "ENOENT";
+}
 
+/**
+ * The project's own template from `.wreckit/prompts/`, or null.
+ */
+async function loadProjectTemplate(
+  root: string,
+  name: PromptTemplateName,
+): Promise<string | null> {
   try {
-    const content = await fs.readFile(promptPath, "utf-8");
-    return content;
+    return await fs.readFile(getPromptTemplatePath(root, name), "utf-8");
   } catch (err) {
-    if ((err as NodeJS.ErrnoException).code === "ENOENT") {
-      return getDefaultTemplate(name);
+    if (isMissing(err)) {
+      return null;
     }
     throw err;
   }
 }
 
+/**
+ * Load a prompt template. Non-feature items prefer a kind-specific variant
+ * such as `implement-bug.md`, but a project's own templates win over the
+ * bundled ones: project variant, project base, bundled variant, then
+ * bundled base.
+ */
+export async function loadPromptTemplate(
+  root: string,
+  name: PromptTemplateName,
+  kind?: ItemKind,
+): Promise<string> {
+  const variant = kind && kind !== "feature" ? `${name}-${kind}` : null;
+  for (const candidate of variant ? [variant, name] : [name]) {
+    const custom = await loadProjectTemplate(root, candidate);
+    if (custom !== null) {
+      return custom;
+    }
+  }
+  if (variant) {
+    try {
+      return await getDefaultTemplate(variant);
+    } catch (err) {
+      if (!isMissing(err)) {
+        throw err;
+      }
+    }
+  }
+  return getDefaultTemplate(name);
+}
+
 export function renderPrompt(
   template: string,
   variables: PromptVariables,
@@ -106,6 +171,15 @@ export function renderPrompt(
     progress: variables.progress,
     skill_context: variables.skill_context, // Add skill context
     scope_limits: variables.scope_limits, // Add scope limits
+    current_story: variables.current_story,
+    review_feedback: variables.review_feedback,
+    kind: variables.kind,
+    reproduction_command: variables.reproduction_command,
+    reproduction_tests: variables.reproduction_tests,
+    tests_command: variables.tests_command,
+    story_tests: variables.story_tests,
+    branch_diff: variables.branch_diff,
+    doc_paths: variables.doc_paths,
   };
 
   for (const [key, value] of Object.entries(varMap)) {
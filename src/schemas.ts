@@ -3,15 +3,34 @@ import { z } from "zod";
 export const ItemStateSchema = z.enum([
   "idea",
   "researched",
+  "reproduced",
   "planned",
+  "tests_written",
   "implementing",
   "critique",
+  "documented",
   "in_pr",
   "done",
+  // Parked states: outside the pipeline, skipped by the orchestrator
+  "blocked",
+  "paused",
+  "wontfix",
 ]);
 
 export const StoryStatusSchema = z.enum(["pending", "done"]);
 
+export const PhaseNameSchema = z.enum([
+  "research",
+  "reproduce",
+  "plan",
+  "tests",
+  "implement",
+  "critique",
+  "document",
+  "pr",
+  "complete",
+]);
+
 export const AgentModeSchema = z.enum(["process", "sdk"]);
 
 export const MergeModeSchema = z.enum(["pr", "direct"]);
@@ -123,6 +142,37 @@ export const SpriteAgentSchema = z.object({
     .describe("Default timeout in seconds for VM operations"),
 });
 
+export const OpenAiCompatAgentSchema = z.object({
+  kind: z.literal("openai_compat"),
+  base_url: z
+    .string()
+    .default("http://localhost:11434/v1")
+    .describe("Base URL of the API, up to and including /v1"),
+  model: z.string().describe("Model name as the server knows it"),
+  api_key_env: z
+    .string()
+    .optional()
+    .describe("Environment variable holding the API key, if one is needed"),
+  max_tokens: z.number().default(4096),
+  max_iterations: z
+    .number()
+    .int()
+    .positive()
+    .default(100)
+    .describe("Maximum chat requests per run"),
+});
+
+export const ReplayAgentSchema = z.object({
+  kind: z.literal("replay"),
+  cassette_dir: z
+    .string()
+    .describe("Directory of cassettes recorded with --record"),
+  strict: z
+    .boolean()
+    .default(false)
+    .describe("Fail instead of replaying a cassette whose prompt differs"),
+});
+
 export const AgentConfigUnionSchema = z.discriminatedUnion("kind", [
   ProcessAgentSchema,
   ClaudeSdkAgentSchema,
@@ -131,6 +181,17 @@ export const AgentConfigUnionSchema = z.discriminatedUnion("kind", [
   OpenCodeSdkAgentSchema,
   RlmSdkAgentSchema,
   SpriteAgentSchema,
+  OpenAiCompatAgentSchema,
+  ReplayAgentSchema,
+]);
+
+// Agents tried in order; later ones run when an earlier one fails transiently
+export const AgentChainSchema = z.array(AgentConfigUnionSchema).min(1);
+
+// A single agent or a fallback chain, as accepted for one phase
+export const PhaseAgentSchema = z.union([
+  AgentConfigUnionSchema,
+  AgentChainSchema,
 ]);
 
 // Legacy agent config (mode-based) - for backwards compatibility
@@ -274,6 +335,257 @@ export const StoryScopeConfigSchema = z
       .array(z.string())
       .default(["*.lock", "package-lock.json", "yarn.lock", "*.log"])
       .describe("Patterns to exclude from scope checks"),
+    live_check: z
+      .boolean()
+      .default(true)
+      .describe("Check scope while the agent runs and abort when exceeded"),
+    live_check_interval_seconds: z
+      .number()
+      .positive()
+      .default(30)
+      .describe("Seconds between scope checks while the agent runs"),
+    reset_on_violation: z
+      .boolean()
+      .default(false)
+      .describe(
+        "Reset the working tree to the story's start when the agent is aborted",
+      ),
+  })
+  .strict();
+
+/**
+ * Parallel story implementation: independent PRD stories run in separate
+ * git worktrees and are merged back into the item branch.
+ */
+export const ParallelStoriesConfigSchema = z
+  .object({
+    enabled: z
+      .boolean()
+      .default(false)
+      .describe("Implement independent stories in parallel worktrees"),
+    max_workers: z
+      .number()
+      .int()
+      .positive()
+      .default(2)
+      .describe("Maximum number of stories implemented at once"),
+  })
+  .strict();
+
+/**
+ * Optional document phase: which repo paths count as documentation.
+ */
+export const DocumentConfigSchema = z
+  .object({
+    paths: z
+      .array(z.string())
+      .default([
+        "**/*.md",
+        "**/*.mdx",
+        "**/*.rst",
+        "**/README*",
+        "**/CHANGELOG*",
+        "docs/**",
+      ])
+      .describe("Globs of the paths the document phase may change"),
+  })
+  .strict();
+
+/**
+ * Price of a model in USD per million tokens. Cache prices default to the
+ * input price.
+ */
+export const ModelPriceSchema = z
+  .object({
+    input: z.number().nonnegative(),
+    output: z.number().nonnegative(),
+    cache_read: z.number().nonnegative().optional(),
+    cache_write: z.number().nonnegative().optional(),
+  })
+  .strict();
+
+/**
+ * Spend caps in USD. An agent run that reaches a cap is aborted with
+ * BUDGET_EXCEEDED.
+ */
+export const BudgetConfigSchema = z
+  .object({
+    per_item_usd: z
+      .number()
+      .positive()
+      .optional()
+      .describe("Cap on the cost of all of an item's phases"),
+    per_phase_usd: z
+      .number()
+      .positive()
+      .optional()
+      .describe("Cap on the cost of one phase of an item"),
+    per_session_usd: z
+      .number()
+      .positive()
+      .optional()
+      .describe("Cap on the cost of one orchestrator session"),
+  })
+  .strict();
+
+/**
+ * Limits for spotting an agent that is stuck. A stuck agent is nudged once
+ * where the backend takes messages mid-run, then aborted.
+ */
+export const StallDetectionConfigSchema = z
+  .object({
+    enabled: z.boolean().default(true),
+    max_repeated_tool_calls: z
+      .number()
+      .int()
+      .positive()
+      .default(5)
+      .describe("Identical tool calls allowed with no file change between"),
+    max_idle_seconds: z
+      .number()
+      .positive()
+      .default(900)
+      .describe("Time without file changes in phases that edit the repo"),
+    max_repeated_errors: z
+      .number()
+      .int()
+      .positive()
+      .default(5)
+      .describe("Times the same error message may come back"),
+  })
+  .strict();
+
+/**
+ * Where an agent's file-writing tools may write in a phase. Globs are
+ * relative to the repository root; `<id>` stands for the item id.
+ */
+export const PathPolicySchema = z
+  .object({
+    allow: z
+      .array(z.string())
+      .optional()
+      .describe("Only paths matching one of these globs may be written"),
+    deny: z
+      .array(z.string())
+      .default([])
+      .describe("Paths matching one of these globs may never be written"),
+  })
+  .strict();
+
+export const BashActionSchema = z.enum(["allow", "deny", "approve"]);
+
+const BashRuleFields = {
+  action: BashActionSchema,
+  reason: z.string().optional().describe("Told to the agent when it applies"),
+};
+
+function isValidRegExp(pattern: string): boolean {
+  try {
+    new RegExp(pattern);
+    return true;
+  } catch {
+    return false;
+  }
+}
+
+/**
+ * A rule for commands run through the Bash tool, matched either by command
+ * and arguments (e.g. "git push --force") or by a regular expression over
+ * the whole command line.
+ */
+export const BashRuleSchema = z.union([
+  z
+    .object({
+      command: z
+        .string()
+        .describe("Command and arguments a matching invocation contains"),
+      ...BashRuleFields,
+    })
+    .strict(),
+  z
+    .object({
+      pattern: z
+        .string()
+        .refine(isValidRegExp, {
+          error: (issue) => `Invalid regular expression: ${issue.input}`,
+        })
+        .describe("Regular expression matched against the command line"),
+      ...BashRuleFields,
+    })
+    .strict(),
+]);
+
+/**
+ * What the Bash tool may run. The most restrictive matching rule wins;
+ * commands no rule matches get `default_action`.
+ */
+export const BashPolicyConfigSchema = z
+  .object({
+    enabled: z.boolean().default(true),
+    default_action: BashActionSchema.default("allow"),
+    rules: z.array(BashRuleSchema).default([]),
+    default_rules: z
+      .boolean()
+      .default(true)
+      .describe("Also apply the built-in rules, see src/agent/bashPolicy.ts"),
+    abort_on_violation: z
+      .boolean()
+      .default(false)
+      .describe("Abort the agent run when a command is denied"),
+  })
+  .strict();
+
+// ============================================================
+// Workflow Pipeline Configuration Schema
+// ============================================================
+
+/**
+ * A single stage of a workflow pipeline.
+ * The first stage is the entry state and has no phase; every later stage
+ * names the phase runner that moves an item into its state.
+ */
+export const PipelineStageSchema = z
+  .object({
+    state: ItemStateSchema.describe("Workflow state reached by this stage"),
+    phase: PhaseNameSchema.optional().describe(
+      "Phase runner that moves an item into this state",
+    ),
+    prompt: z
+      .string()
+      .optional()
+      .describe("Prompt template name (defaults to the phase name)"),
+    allowed_tools: z
+      .array(z.string())
+      .optional()
+      .describe("Tool allowlist for this stage (defaults to the phase allowlist)"),
+  })
+  .strict();
+
+/**
+ * An ordered workflow pipeline.
+ */
+export const PipelineSchema = z
+  .object({
+    description: z.string().optional(),
+    stages: z.array(PipelineStageSchema).min(2),
+  })
+  .strict();
+
+/**
+ * Approval gate after a phase: "human" stops the item until
+ * `wreckit approve` / `wreckit reject`, "auto" continues (default).
+ */
+export const GateModeSchema = z.enum(["human", "auto"]);
+
+/**
+ * Per-phase overrides for the global agent, timeout and iteration limits.
+ * For research and plan, max_iterations caps validation attempts (default 3).
+ */
+export const PhaseSettingsSchema = z
+  .object({
+    timeout_seconds: z.number().positive().optional(),
+    max_iterations: z.number().int().positive().optional(),
+    agent: PhaseAgentSchema.optional(),
   })
   .strict();
 
@@ -283,7 +595,11 @@ export const ConfigSchema = z.object({
   branch_prefix: z.string().default("wreckit/"),
   merge_mode: MergeModeSchema.default("pr"),
   // Accept either legacy mode-based format or new kind-based union format
-  agent: z.union([LegacyAgentConfigSchema, AgentConfigUnionSchema]),
+  agent: z.union([
+    LegacyAgentConfigSchema,
+    AgentConfigUnionSchema,
+    AgentChainSchema,
+  ]),
   max_iterations: z.number().default(100),
   timeout_seconds: z.number().default(3600),
   pr_checks: PrChecksSchema.optional(),
@@ -294,10 +610,202 @@ export const ConfigSchema = z.object({
   doctor: DoctorConfigSchema.optional(),
   // Add optional story scope configuration (Item 084)
   story_scope: StoryScopeConfigSchema.optional(),
+  // Named workflow pipelines and the one new items are created under
+  pipelines: z.record(z.string(), PipelineSchema).optional(),
+  default_pipeline: z.string().optional(),
+  // Approval gates keyed by the phase whose output needs review
+  gates: z.partialRecord(PhaseNameSchema, GateModeSchema).optional(),
+  // Per-phase agent, timeout and iteration overrides
+  phases: z.partialRecord(PhaseNameSchema, PhaseSettingsSchema).optional(),
+  // Agent routing by phase, e.g. a cheap model for research and pr
+  phase_agents: z.partialRecord(PhaseNameSchema, PhaseAgentSchema).optional(),
+  // Opt-in parallel implementation of independent stories
+  parallel_stories: ParallelStoriesConfigSchema.optional(),
+  // Documentation globs for the optional document phase
+  document: DocumentConfigSchema.optional(),
+  // Model prices used to turn token usage into costs
+  prices: z.record(z.string(), ModelPriceSchema).optional(),
+  // Spend caps, priced with `prices`
+  budget: BudgetConfigSchema.optional(),
+  // Stuck agent detection; off unless configured
+  stall_detection: StallDetectionConfigSchema.optional(),
+  // Where file-writing tools may write, per phase
+  path_policies: z.partialRecord(PhaseNameSchema, PathPolicySchema).optional(),
+  // What the Bash tool may run
+  bash_policy: BashPolicyConfigSchema.optional(),
 });
 
 export const PriorityHintSchema = z.enum(["low", "medium", "high", "critical"]);
 
+/**
+ * What kind of work an item is. Kinds pick their own prompts and, for bugs,
+ * a pipeline that reproduces the bug before fixing it.
+ */
+export const ItemKindSchema = z.enum(["feature", "bug", "refactor", "spike"]);
+
+/**
+ * One run of a reproduction test command.
+ */
+export const TestRunSchema = z.object({
+  at: z.string(),
+  exit_code: z.number(),
+  passed: z.boolean(),
+});
+
+/**
+ * A failing test written by the reproduce phase. The fix is only accepted
+ * once the same command has failed and then passed.
+ */
+export const ReproductionSchema = z.object({
+  command: z.string(),
+  test_files: z.array(z.string()),
+  description: z.string().optional(),
+  failing_run: TestRunSchema.nullable(),
+  passing_run: TestRunSchema.nullable(),
+});
+
+/**
+ * Tests written for one story by the tests phase.
+ */
+export const StoryTestEntrySchema = z.object({
+  story_id: z.string(),
+  test_files: z.array(z.string()),
+  tests: z.array(z.string()),
+});
+
+/**
+ * Failing tests written for a PRD's stories before implementation. The
+ * implement phase is told to make them pass.
+ */
+export const StoryTestsSchema = z.object({
+  command: z.string(),
+  stories: z.array(StoryTestEntrySchema),
+  failing_run: TestRunSchema.nullable(),
+});
+
+/**
+ * Documentation updated by the document phase.
+ */
+export const DocumentationSchema = z.object({
+  changed_files: z.array(z.string()),
+  documented_at: z.string(),
+});
+
+/**
+ * Tokens used by agent runs. Input tokens exclude cache reads and writes.
+ */
+export const TokenUsageSchema = z.object({
+  input_tokens: z.number(),
+  output_tokens: z.number(),
+  cache_read_tokens: z.number(),
+  cache_write_tokens: z.number(),
+});
+
+/**
+ * Tokens used by one agent run and the model that used them.
+ */
+export const AgentUsageSchema = TokenUsageSchema.extend({
+  model: z.string().nullable(),
+});
+
+/**
+ * Accumulated usage of a phase or session. `cost_usd` only covers runs
+ * whose model has a price in the config's `prices`.
+ */
+export const UsageTotalsSchema = TokenUsageSchema.extend({
+  cost_usd: z.number(),
+  runs: z.number(),
+  models: z.array(z.string()),
+});
+
+// ============================================================
+// Agent Session Cassettes
+// ============================================================
+
+/**
+ * One step of a recorded agent run, in the order it happened.
+ */
+export const CassetteEntrySchema = z.discriminatedUnion("type", [
+  z.object({
+    type: z.literal("event"),
+    event: z.looseObject({ type: z.string() }),
+  }),
+  z.object({ type: z.literal("stdout"), chunk: z.string() }),
+  z.object({ type: z.literal("stderr"), chunk: z.string() }),
+]);
+
+/**
+ * A file the agent created, changed or deleted (content null).
+ */
+export const CassetteFileChangeSchema = z.object({
+  path: z.string().describe("Path relative to the repository root"),
+  content: z.string().nullable(),
+  encoding: z.enum(["utf8", "base64"]).default("utf8"),
+});
+
+/**
+ * A recorded agent run that the `replay` agent kind plays back.
+ */
+export const CassetteSchema = z.object({
+  schema_version: z.literal(1),
+  recorded_at: z.string(),
+  agent_kind: z.string(),
+  cwd: z.string().describe("Working directory relative to the repository"),
+  prompt: z.string(),
+  timeline: z.array(CassetteEntrySchema),
+  file_changes: z.array(CassetteFileChangeSchema),
+  result: z.object({
+    success: z.boolean(),
+    output: z.string(),
+    timedOut: z.boolean(),
+    exitCode: z.number().nullable(),
+    completionDetected: z.boolean(),
+    usage: AgentUsageSchema.optional(),
+  }),
+});
+
+/**
+ * Outcome of a single test, parsed from a test reporter's output.
+ */
+export const TestOutcomeSchema = z.object({
+  name: z.string(),
+  status: z.enum(["passed", "failed", "skipped"]),
+});
+
+/**
+ * Per-test results recorded before a refactor starts. Stories may not turn
+ * any test that passed here into a failing one.
+ */
+export const TestBaselineSchema = z.object({
+  schema_version: z.literal(1),
+  recorded_at: z.string(),
+  commands: z.array(z.string()),
+  tests: z.array(TestOutcomeSchema),
+});
+
+/**
+ * A recorded backward transition (reopen/regress) of an item.
+ */
+export const RegressionSchema = z.object({
+  from: ItemStateSchema,
+  to: ItemStateSchema,
+  reason: z.string(),
+  actor: z.string(),
+  at: z.string(),
+  archive_dir: z.string().nullable().optional(),
+});
+
+/**
+ * A recorded approval decision on a gated phase.
+ */
+export const ApprovalSchema = z.object({
+  phase: PhaseNameSchema,
+  decision: z.enum(["approved", "rejected"]),
+  by: z.string(),
+  at: z.string(),
+  comment: z.string().nullable().optional(),
+});
+
 export const ItemSchema = z.object({
   schema_version: z.number(),
   id: z.string(),
@@ -334,6 +842,63 @@ export const ItemSchema = z.object({
   // Dependency management and campaign grouping (Item 022)
   depends_on: z.array(z.string()).optional(),
   campaign: z.string().optional(),
+
+  // Workflow pipeline the item was created under (defaults to "default")
+  pipeline: z.string().optional(),
+
+  // Backward transitions with their reasons, oldest first
+  regressions: z.array(RegressionSchema).optional(),
+
+  // Parking metadata for blocked/paused/wontfix items
+  parked_from: ItemStateSchema.nullable().optional(),
+  parked_at: z.string().nullable().optional(),
+  parked_reason: z.string().nullable().optional(),
+  unblock_condition: z.string().nullable().optional(),
+
+  // Approval gates: the gated phase awaiting review, and past decisions
+  pending_approval: PhaseNameSchema.nullable().optional(),
+  approvals: z.array(ApprovalSchema).optional(),
+
+  // Kind of work (defaults to "feature") and the bug reproduction test
+  kind: ItemKindSchema.optional(),
+  reproduction: ReproductionSchema.nullable().optional(),
+
+  // Failing tests written by the optional tests phase
+  story_tests: StoryTestsSchema.nullable().optional(),
+  // Documentation files changed by the optional document phase
+  documentation: DocumentationSchema.nullable().optional(),
+  // Token usage and cost of the agent runs of each phase
+  usage: z.partialRecord(PhaseNameSchema, UsageTotalsSchema).optional(),
+});
+
+/**
+ * One line of an item's append-only history.jsonl audit trail.
+ */
+export const HistoryEventSchema = z.enum([
+  "transition",
+  "phase_started",
+  "phase_succeeded",
+  "phase_failed",
+  "agent_fallback",
+  "approval_requested",
+  "approved",
+  "rejected",
+]);
+
+export const HistoryEntrySchema = z.object({
+  ts: z.string(),
+  event: HistoryEventSchema,
+  /** Agent kind for phase runs, "human" for CLI actions, or the triggering phase */
+  actor: z.string(),
+  phase: z.string().optional(),
+  from: ItemStateSchema.optional(),
+  to: ItemStateSchema.optional(),
+  reason: z.string().optional(),
+  duration_ms: z.number().optional(),
+  error: z.string().optional(),
+  error_code: z.string().optional(),
+  /** Agent that ran the phase, or was fallen back to, e.g. "codex_sdk/codex-1" */
+  agent: z.string().optional(),
 });
 
 export const StorySchema = z.object({
@@ -343,6 +908,8 @@ export const StorySchema = z.object({
   priority: z.number(),
   status: StoryStatusSchema,
   notes: z.string(),
+  // IDs of stories in the same PRD that must be done first
+  depends_on: z.array(z.string()).optional(),
 });
 
 export const PrdSchema = z.object({
@@ -358,6 +925,7 @@ export const IndexItemSchema = z.object({
   title: z.string(),
   // Dependency management for efficient orchestration (Item 022)
   depends_on: z.array(z.string()).optional(),
+  pending_approval: PhaseNameSchema.nullable().optional(),
 });
 
 export const IndexSchema = z.object({
@@ -392,15 +960,38 @@ export const BatchProgressSchema = z.object({
     .string()
     .nullable()
     .describe("ISO timestamp of last healing event"),
+
+  // Token usage and cost of the agent runs this session
+  usage: UsageTotalsSchema.optional(),
 });
 
 export type WorkflowState = z.infer<typeof ItemStateSchema>;
+export type PhaseName = z.infer<typeof PhaseNameSchema>;
 export type StoryStatus = z.infer<typeof StoryStatusSchema>;
 export type MergeMode = z.infer<typeof MergeModeSchema>;
 export type Config = z.infer<typeof ConfigSchema>;
 export type LegacyAgentConfig = z.infer<typeof LegacyAgentConfigSchema>;
 export type Item = z.infer<typeof ItemSchema>;
 export type PriorityHint = z.infer<typeof PriorityHintSchema>;
+export type Regression = z.infer<typeof RegressionSchema>;
+export type Approval = z.infer<typeof ApprovalSchema>;
+export type GateMode = z.infer<typeof GateModeSchema>;
+export type PhaseSettings = z.infer<typeof PhaseSettingsSchema>;
+export type ItemKind = z.infer<typeof ItemKindSchema>;
+export type TestRun = z.infer<typeof TestRunSchema>;
+export type Reproduction = z.infer<typeof ReproductionSchema>;
+export type StoryTests = z.infer<typeof StoryTestsSchema>;
+export type Documentation = z.infer<typeof DocumentationSchema>;
+export type TokenUsage = z.infer<typeof TokenUsageSchema>;
+export type AgentUsage = z.infer<typeof AgentUsageSchema>;
+export type UsageTotals = z.infer<typeof UsageTotalsSchema>;
+export type CassetteEntry = z.infer<typeof CassetteEntrySchema>;
+export type CassetteFileChange = z.infer<typeof CassetteFileChangeSchema>;
+export type Cassette = z.infer<typeof CassetteSchema>;
+export type TestOutcome = z.infer<typeof TestOutcomeSchema>;
+export type TestBaseline = z.infer<typeof TestBaselineSchema>;
+export type HistoryEvent = z.infer<typeof HistoryEventSchema>;
+export type HistoryEntry = z.infer<typeof HistoryEntrySchema>;
 export type Story = z.infer<typeof StorySchema>;
 export type Prd = z.infer<typeof PrdSchema>;
 export type IndexItem = z.infer<typeof IndexItemSchema>;
@@ -413,7 +1004,13 @@ export type CodexSdkAgentConfig = z.infer<typeof CodexSdkAgentSchema>;
 export type OpenCodeSdkAgentConfig = z.infer<typeof OpenCodeSdkAgentSchema>;
 export type RlmSdkAgentConfig = z.infer<typeof RlmSdkAgentSchema>;
 export type SpriteAgentConfig = z.infer<typeof SpriteAgentSchema>;
+export type OpenAiCompatAgentConfig = z.infer<
+  typeof OpenAiCompatAgentSchema
+>;
+export type ReplayAgentConfig = z.infer<typeof ReplayAgentSchema>;
 export type AgentConfigUnion = z.infer<typeof AgentConfigUnionSchema>;
+export type AgentChain = z.infer<typeof AgentChainSchema>;
+export type PhaseAgent = z.infer<typeof PhaseAgentSchema>;
 export type BatchProgress = z.infer<typeof BatchProgressSchema>;
 
 // Type exports for skill configuration (Item 033)
@@ -430,6 +1027,21 @@ export type DoctorConfig = z.infer<typeof DoctorConfigSchema>;
 
 // Type exports for story scope configuration (Item 084)
 export type StoryScopeConfig = z.infer<typeof StoryScopeConfigSchema>;
+export type ParallelStoriesConfig = z.infer<
+  typeof ParallelStoriesConfigSchema
+>;
+export type DocumentConfig = z.infer<typeof DocumentConfigSchema>;
+export type ModelPrice = z.infer<typeof ModelPriceSchema>;
+export type BudgetConfig = z.infer<typeof BudgetConfigSchema>;
+export type StallDetectionConfig = z.infer<typeof StallDetectionConfigSchema>;
+export type PathPolicy = z.infer<typeof PathPolicySchema>;
+export type BashAction = z.infer<typeof BashActionSchema>;
+export type BashRule = z.infer<typeof BashRuleSchema>;
+export type BashPolicyConfig = z.infer<typeof BashPolicyConfigSchema>;
+
+// Type exports for workflow pipeline configuration
+export type PipelineStageConfig = z.infer<typeof PipelineStageSchema>;
+export type PipelineConfig = z.infer<typeof PipelineSchema>;
 
 // Backup manifest schemas for doctor --fix
 export const BackupFileEntrySchema = z.object({
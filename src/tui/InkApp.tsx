@@ -49,7 +49,7 @@ export function InkApp({
 
   const handleScroll = useCallback(
     (direction: "up" | "down" | "pageUp" | "pageDown" | "top" | "bottom") => {
-      const logsHeight = height - 10;
+      const logsHeight = height - 11;
       const maxOffset = Math.max(0, state.logs.length - logsHeight);
 
       setScrollOffset((prev) => {
@@ -102,7 +102,7 @@ export function InkApp({
     }
   });
 
-  const headerHeight = 5;
+  const headerHeight = 6;
   const footerHeight = 4;
   const mainHeight = Math.max(1, height - headerHeight - footerHeight);
 
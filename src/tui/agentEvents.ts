@@ -1,3 +1,5 @@
+import type { AgentUsage } from "../schemas";
+
 export type AgentEvent =
   | { type: "assistant_text"; text: string }
   | {
@@ -9,4 +11,28 @@ export type AgentEvent =
   | { type: "tool_result"; toolUseId: string; result: unknown }
   | { type: "tool_error"; toolUseId: string; error: string }
   | { type: "run_result"; subtype?: string }
+  | { type: "usage"; usage: AgentUsage }
+  // Usage of the current run so far, from runners that see every request
+  | { type: "usage_progress"; usage: AgentUsage }
+  // The agent of a fallback chain that runs next
+  | { type: "agent_started"; agent: string }
+  | { type: "agent_fallback"; from: string; to: string; reason: string }
+  // A stuck agent was spotted; it is nudged once, then aborted
+  | {
+      type: "stall_detected";
+      reason: string;
+      message: string;
+      action: "nudge" | "abort";
+    }
+  // The story's diff crossed a story_scope limit and the agent was aborted
+  | { type: "scope_exceeded"; violations: string[] }
+  // A file write was refused by the phase's path policy
+  | { type: "path_denied"; tool: string; path: string; reason: string }
+  // A Bash command was refused by the bash_policy
+  | {
+      type: "command_denied";
+      command: string;
+      action: "deny" | "approve";
+      reason: string;
+    }
   | { type: "error"; message: string };
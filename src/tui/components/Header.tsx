@@ -1,6 +1,7 @@
 import React from "react";
 import { Box, Text } from "ink";
-import type { TuiState } from "../dashboard";
+import { formatPhaseText, type TuiState } from "../dashboard";
+import { formatUsage } from "../../domain/usage";
 
 interface HeaderProps {
   state: TuiState;
@@ -12,14 +13,16 @@ export function Header({ state, width }: HeaderProps): React.ReactElement {
     ? `Running: ${state.currentItem}`
     : "Waiting...";
 
-  const phaseText = state.currentPhase
-    ? `Phase: ${state.currentPhase} (iteration ${state.currentIteration}/${state.maxIterations})`
-    : "Phase: idle";
+  const phaseText = formatPhaseText(state);
 
   const storyText = state.currentStory
     ? `Story: ${state.currentStory.id} - ${state.currentStory.title}`
     : "Story: none";
 
+  const usageText = state.usage
+    ? `Usage: ${formatUsage(state.usage)}`
+    : "Usage: none";
+
   return (
     <Box flexDirection="column" width={width}>
       <Box>
@@ -48,6 +51,13 @@ export function Header({ state, width }: HeaderProps): React.ReactElement {
           {" ".repeat(Math.max(0, width - 4 - storyText.length))} │
         </Text>
       </Box>
+      <Box>
+        <Text color="cyan">│ </Text>
+        <Text dimColor>{truncate(usageText, width - 4)}</Text>
+        <Text color="cyan">
+          {" ".repeat(Math.max(0, width - 4 - usageText.length))} │
+        </Text>
+      </Box>
       <Box>
         <Text color="cyan">├{"─".repeat(width - 2)}┤</Text>
       </Box>
@@ -1,4 +1,5 @@
-import type { IndexItem } from "../schemas";
+import type { IndexItem, UsageTotals } from "../schemas";
+import { formatUsage } from "../domain/usage";
 
 export interface ToolExecution {
   toolUseId: string;
@@ -33,6 +34,10 @@ export interface TuiState {
   logs: string[];
   showLogs: boolean;
   activityByItem: Record<string, AgentActivityForItem>;
+  /** Token usage and cost of the session so far */
+  usage: UsageTotals | null;
+  /** Agent running the current phase, after any fallback */
+  currentAgent: string | null;
 }
 
 export function createTuiState(items: IndexItem[]): TuiState {
@@ -56,6 +61,8 @@ export function createTuiState(items: IndexItem[]): TuiState {
     activityByItem: Object.fromEntries(
       items.map((item) => [item.id, { thoughts: [], tools: [] }]),
     ),
+    usage: null,
+    currentAgent: null,
   };
 }
 
@@ -89,9 +96,18 @@ export function getStateIcon(state: string): string {
     case "implementing":
     case "in_pr":
       return "→";
+    case "blocked":
+      return "!";
+    case "paused":
+      return "‖";
+    case "wontfix":
+      return "✗";
     case "idea":
     case "researched":
+    case "reproduced":
     case "planned":
+    case "tests_written":
+    case "documented":
     default:
       return "○";
   }
@@ -112,6 +128,17 @@ function renderHorizontalLine(
   return left + "─".repeat(width - 2) + right;
 }
 
+/**
+ * The header's phase line, naming the agent once one has started.
+ */
+export function formatPhaseText(state: TuiState): string {
+  if (!state.currentPhase) {
+    return "Phase: idle";
+  }
+  const agent = state.currentAgent ? ` via ${state.currentAgent}` : "";
+  return `Phase: ${state.currentPhase} (iteration ${state.currentIteration}/${state.maxIterations})${agent}`;
+}
+
 export function renderDashboard(state: TuiState, width = 80): string {
   const innerWidth = width - 4;
   const lines: string[] = [];
@@ -123,9 +150,7 @@ export function renderDashboard(state: TuiState, width = 80): string {
     : "Waiting...";
   lines.push("│ " + padToWidth(currentItemText, innerWidth) + " │");
 
-  const phaseText = state.currentPhase
-    ? `Phase: ${state.currentPhase} (iteration ${state.currentIteration}/${state.maxIterations})`
-    : "Phase: idle";
+  const phaseText = formatPhaseText(state);
   lines.push("│ " + padToWidth(phaseText, innerWidth) + " │");
 
   const storyText = state.currentStory
@@ -133,6 +158,11 @@ export function renderDashboard(state: TuiState, width = 80): string {
     : "Story: none";
   lines.push("│ " + padToWidth(storyText, innerWidth) + " │");
 
+  const usageText = state.usage
+    ? `Usage: ${formatUsage(state.usage)}`
+    : "Usage: none";
+  lines.push("│ " + padToWidth(usageText, innerWidth) + " │");
+
   lines.push("├" + "─".repeat(width - 2) + "┤");
 
   if (!state.showLogs) {
@@ -221,7 +221,45 @@ export class TuiRunner {
         );
         break;
       }
+      case "agent_fallback": {
+        const fallbackMessage = `[FALLBACK] ${event.from} ${event.reason}, switching to ${event.to}`;
+        activity.thoughts = [...activity.thoughts, fallbackMessage].slice(
+          -MAX_THOUGHTS,
+        );
+        break;
+      }
+      case "stall_detected": {
+        const stallMessage = `[STALL] ${event.message} (${event.action === "nudge" ? "nudging agent" : "aborting agent"})`;
+        activity.thoughts = [...activity.thoughts, stallMessage].slice(
+          -MAX_THOUGHTS,
+        );
+        break;
+      }
+      case "scope_exceeded": {
+        const scopeMessage = `[SCOPE] ${event.violations.join("; ")} (aborting agent)`;
+        activity.thoughts = [...activity.thoughts, scopeMessage].slice(
+          -MAX_THOUGHTS,
+        );
+        break;
+      }
+      case "path_denied": {
+        const deniedMessage = `[DENIED] ${event.tool}: ${event.reason}`;
+        activity.thoughts = [...activity.thoughts, deniedMessage].slice(
+          -MAX_THOUGHTS,
+        );
+        break;
+      }
+      case "command_denied": {
+        const blockedMessage = `[BLOCKED] ${event.command}: ${event.reason}`;
+        activity.thoughts = [...activity.thoughts, blockedMessage].slice(
+          -MAX_THOUGHTS,
+        );
+        break;
+      }
+      case "agent_started":
       case "run_result":
+      case "usage":
+      case "usage_progress":
         break;
     }
 
@@ -230,6 +268,7 @@ export class TuiRunner {
         ...this.state.activityByItem,
         [itemId]: activity,
       },
+      ...(event.type === "agent_started" && { currentAgent: event.agent }),
     });
     this.notify();
   }
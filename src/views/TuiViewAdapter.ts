@@ -1,6 +1,6 @@
 import type { ViewAdapter, ItemSnapshot, StorySnapshot } from "./ViewAdapter";
 import type { AgentEvent } from "../tui/agentEvents";
-import type { WorkflowState } from "../schemas";
+import type { UsageTotals, WorkflowState } from "../schemas";
 import { TuiRunner, type TuiOptions } from "../tui/runner";
 
 export class TuiViewAdapter implements ViewAdapter {
@@ -44,6 +44,10 @@ export class TuiViewAdapter implements ViewAdapter {
     this.runner.update({ currentStory: story });
   }
 
+  onUsageChanged(usage: UsageTotals): void {
+    this.runner.update({ usage });
+  }
+
   onAgentEvent(itemId: string, event: AgentEvent): void {
     this.runner.appendAgentEvent(itemId, event);
   }
@@ -1,5 +1,5 @@
 import type { AgentEvent } from "../tui/agentEvents";
-import type { WorkflowState } from "../schemas";
+import type { UsageTotals, WorkflowState } from "../schemas";
 
 export interface StorySnapshot {
   id: string;
@@ -12,6 +12,7 @@ export interface ViewAdapter {
   onPhaseChanged(phase: WorkflowState | null): void;
   onIterationChanged(iteration: number, maxIterations: number): void;
   onStoryChanged(story: StorySnapshot | null): void;
+  onUsageChanged(usage: UsageTotals): void;
   onAgentEvent(itemId: string, event: AgentEvent): void;
   onRunComplete(itemId: string, success: boolean, error?: string): void;
   start(): void;
@@ -2,7 +2,12 @@ import * as fs from "node:fs/promises";
 import type { Logger } from "../logging";
 import type { Item, WorkflowState } from "../schemas";
 import type { PhaseResult, WorkflowOptions } from "./itemWorkflow";
-import { getAgentConfigUnion, runAgentUnion } from "../agent/runner";
+import {
+  getAgentConfigUnion,
+  runAgentUnion,
+  type AgentResult,
+} from "../agent/runner";
+import { getPhaseSettings } from "../config";
 import { loadPromptTemplate, renderPrompt } from "../prompts";
 import {
   getItemDir,
@@ -13,6 +18,9 @@ import {
 } from "../fs/paths";
 import { readItem, writeItem } from "../fs/json";
 import { getGitStatus, type GitFileChange } from "../git";
+import { getItemPipeline, getStageForPhase } from "../domain/pipeline";
+import { regressItem } from "./regress";
+import { formatReviewFeedback } from "../domain/gates";
 
 interface CritiqueResult {
   status: "approved" | "rejected";
@@ -60,6 +68,27 @@ function parseCritiqueJson(output: string, logger: Logger): CritiqueResult | nul
   }
 }
 
+/**
+ * The critic's decision from one run, or why it did not give one.
+ */
+function readCritique(
+  result: AgentResult,
+  logger: Logger,
+): CritiqueResult | { error: string } {
+  if (!result.success) {
+    return {
+      error: result.timedOut
+        ? "Critic timed out (complexity too high)"
+        : `Critic failed: ${result.output.slice(0, 100)}...`,
+    };
+  }
+  return (
+    parseCritiqueJson(result.output, logger) ?? {
+      error: "Critic failed to output valid JSON decision",
+    }
+  );
+}
+
 export async function runPhaseCritique(
   itemId: string,
   options: WorkflowOptions,
@@ -94,7 +123,12 @@ export async function runPhaseCritique(
     return { success: true, item };
   }
 
-  const template = await loadPromptTemplate(root, "critique");
+  const stage = getStageForPhase(getItemPipeline(item, config), "critique");
+  const template = await loadPromptTemplate(
+    root,
+    stage?.prompt ?? "critique",
+    item.kind,
+  );
 
   // Load context for variables
   const plan = await fs
@@ -116,31 +150,39 @@ export async function runPhaseCritique(
     base_branch: config.base_branch,
     completion_signal: "JSON_OUTPUT",
     sdk_mode: true,
+    review_feedback: formatReviewFeedback(item, "critique"),
   };
 
   const prompt = renderPrompt(template, variables);
-  const agentConfig = getAgentConfigUnion(config);
-
-  const result = await runAgentUnion({
-    itemId: itemId,
-    config: agentConfig,
-    cwd: root, // Critic runs at root to see everything
-    prompt,
-    logger,
-    dryRun,
-    mockAgent,
-    timeoutSeconds: config.timeout_seconds,
-    onStdoutChunk: onAgentOutput,
-    onStderrChunk: onAgentOutput,
-    onAgentEvent,
-    allowedTools: [
-      "read_file",
-      "run_shell_command",
-      "glob",
-      "search_file_content",
-      "list_directory",
-    ], // Read-only tools
-  });
+  const agentConfig = getAgentConfigUnion(config, "critique");
+
+  const runCritic = () =>
+    runAgentUnion({
+      itemId: itemId,
+      config: agentConfig,
+      cwd: root, // Critic runs at root to see everything
+      prompt,
+      logger,
+      dryRun,
+      mockAgent,
+      timeoutSeconds: getPhaseSettings(config, "critique").timeout_seconds,
+      onStdoutChunk: onAgentOutput,
+      onStderrChunk: onAgentOutput,
+      onAgentEvent,
+      budget: options.budget,
+      stall: options.stall,
+      bashPolicy: options.bashPolicy,
+      fallbacks: getPhaseSettings(config, "critique").agent_fallbacks,
+      allowedTools: stage?.allowedTools ?? [
+        "read_file",
+        "run_shell_command",
+        "glob",
+        "search_file_content",
+        "list_directory",
+      ], // Read-only tools
+    });
+
+  const result = await runCritic();
 
   if (dryRun) {
     return { success: true, item };
@@ -152,32 +194,22 @@ export async function runPhaseCritique(
     return { success: true, item };
   }
 
-  // TECHNICAL FAILURE HANDLING (Self-Healing)
-  if (!result.success) {
-    const error = result.timedOut
-      ? "Critic timed out (complexity too high)"
-      : `Critic failed: ${result.output.slice(0, 100)}...`;
-    logger.warn(
-      `Critique technical failure: ${error}. Regressing to 'planned' for simplification.`,
-    );
-
-    // Regress to planned to force re-implementation/simplification
-    item = { ...item, state: "planned", last_error: error };
-    await writeItem(itemDir, item);
-    // Return SUCCESS so the loop continues to 'implement' phase instead of crashing
-    return { success: true, item };
+  // A crashed, timed-out or unparseable critic says nothing about the
+  // implementation: retry it once, then fail with the stories left intact.
+  // Only a real rejection sends the item back to planning.
+  let outcome = readCritique(result, logger);
+  if ("error" in outcome) {
+    logger.warn(`${outcome.error}; retrying critique`);
+    outcome = readCritique(await runCritic(), logger);
   }
-
-  const critique = parseCritiqueJson(result.output, logger);
-
-  if (!critique) {
-    const error = "Critic failed to output valid JSON decision";
+  if ("error" in outcome) {
+    const error = outcome.error;
     logger.error(error);
-    // Regress to planned on parsing failure too
-    item = { ...item, state: "planned", last_error: error };
+    item = { ...item, last_error: error };
     await writeItem(itemDir, item);
-    return { success: true, item };
+    return { success: false, item, error };
   }
+  const critique = outcome;
 
   // Log critique
   const progressPath = getProgressLogPath(root, item.id);
@@ -188,13 +220,17 @@ export async function runPhaseCritique(
   if (critique.status === "rejected") {
     logger.warn(`Critic REJECTED implementation: ${critique.reason}`);
 
-    // REGRESSION LOOP: Move back to planned
-    item = {
-      ...item,
-      state: "planned",
-      last_error: `Critique Failed: ${critique.reason}`,
-    };
-    await writeItem(itemDir, item);
+    // REGRESSION LOOP: Move back to planned. Stories are reset so the
+    // next implement run actually revisits the work.
+    ({ item } = await regressItem(item.id, {
+      root,
+      config,
+      logger,
+      to: "planned",
+      reason: critique.reason,
+      actor: "critique",
+      lastError: `Critique Failed: ${critique.reason}`,
+    }));
     return { success: true, item };
   }
 
@@ -3,10 +3,21 @@ export {
   type PhaseResult,
   buildValidationContext,
   runPhaseResearch,
+  runPhaseReproduce,
   runPhasePlan,
+  runPhaseTests,
   runPhaseImplement,
   runPhaseCritique,
+  runPhaseDocument,
   runPhasePr,
   runPhaseComplete,
   getNextPhase,
 } from "./itemWorkflow";
+
+export {
+  type RegressOptions,
+  type RegressResult,
+  STATE_ARTIFACTS,
+  getSupersededStates,
+  regressItem,
+} from "./regress";
@@ -1,8 +1,21 @@
 import * as fs from "node:fs/promises";
 import * as path from "node:path";
-import type { Item, Prd, WorkflowState, StoryStatus } from "../schemas";
+import type {
+  AgentUsage,
+  Item,
+  Prd,
+  Story,
+  WorkflowState,
+  StoryStatus,
+  PhaseName,
+  Reproduction,
+  StoryTests,
+  TestBaseline,
+  PathPolicy,
+  BashPolicyConfig,
+} from "../schemas";
 import { PrdSchema } from "../schemas";
-import type { ConfigResolved } from "../config";
+import { getPhaseSettings, type ConfigResolved } from "../config";
 import type { Logger } from "../logging";
 import type { AgentEvent } from "../tui/agentEvents";
 import type {
@@ -13,33 +26,91 @@ import {
   validateTransition,
   allStoriesDone,
   hasPendingStories,
+  getNextStory,
   validateResearchQuality,
   validatePlanQuality,
   validateStoryQuality,
 } from "../domain/validation";
 import {
+  ErrorCodes,
   WreckitError,
   FileNotFoundError,
   InvalidJsonError,
   SchemaValidationError,
+  BehaviorRegressionError,
+  BudgetExceededError,
 } from "../errors";
-import { getNextState } from "../domain/states";
+import { getNextState, getStateIndex } from "../domain/states";
+import { createAdvanceEntries } from "../domain/transitions";
+import { requiresApproval, formatReviewFeedback } from "../domain/gates";
+import {
+  recordTestRun,
+  requiresReproduction,
+} from "../domain/reproduction";
+import { requiresReport, validateSpikeReport } from "../domain/spike";
+import {
+  DEFAULT_DOCUMENTATION_PATHS,
+  findNonDocumentationPaths,
+} from "../domain/documentation";
+import { addRunUsage, computeCost, getItemUsage } from "../domain/usage";
+import {
+  compareWithBaseline,
+  requiresBehaviorCheck,
+} from "../domain/behavior";
+import {
+  checkTestsFailBeforeImplementation,
+  formatStoryTests,
+} from "../domain/storyTests";
+import {
+  DEFAULT_PIPELINE,
+  getItemPipeline,
+  getStageForPhase,
+  getPhaseEntryState,
+  getNextPhaseInPipeline,
+  PHASE_TARGET_STATES,
+  type Pipeline,
+  type PipelineStage,
+} from "../domain/pipeline";
 import {
   getItemDir,
   getResearchPath,
   getPlanPath,
   getPrdPath,
   getProgressLogPath,
+  getReportPath,
 } from "../fs/paths";
 import { pathExists, checkPathAccess } from "../fs/util";
-import { readItem, writeItem, readPrd, writePrd } from "../fs/json";
-import { createWreckitMcpServer } from "../agent/mcp/wreckitMcpServer";
+import {
+  readItem,
+  writeItem,
+  readPrd,
+  writePrd,
+  readTestBaseline,
+  writeTestBaseline,
+} from "../fs/json";
+import { appendHistory } from "../fs/history";
+import { PHASE_ARTIFACTS, recordRevision } from "../fs/revisions";
+import { createTranscriptWriter } from "../fs/transcripts";
+import type { StallLimits } from "../agent/stallDetector";
+import type { LiveScopeLimits } from "../agent/scope";
+import { getPhasePathPolicy } from "../agent/pathPolicy";
+import { getBashPolicy } from "../agent/bashPolicy";
+import type { AgentResult } from "../agent/result";
+import {
+  createWreckitMcpServer,
+  type ReproductionData,
+  type StoryTestsData,
+} from "../agent/mcp/wreckitMcpServer";
 import {
   loadPromptTemplate,
   renderPrompt,
   type PromptVariables,
 } from "../prompts";
-import { runAgentUnion, getAgentConfigUnion } from "../agent/runner";
+import {
+  runAgentUnion,
+  getAgentConfigUnion,
+  type AgentBudget,
+} from "../agent/runner";
 import {
   runAgentWithHealing,
   doctorConfigToHealingConfig,
@@ -66,9 +137,13 @@ import {
   mergeAndPushToBase,
   checkMergeConflicts,
   getGitStatus,
+  getBranchDiff,
   compareGitStatus,
   formatViolations,
   runPrePushQualityGates,
+  parseTestOutput,
+  runQualityChecks,
+  runTestCommand,
   checkPrMergeability,
   validateRemoteUrl,
   runGitCommand,
@@ -76,7 +151,14 @@ import {
   getWorkingTreeDiffStats,
   configToOptions,
   formatScopeViolations,
+  validateStoryScope,
+  captureScopeBaseline,
+  getStoryDiffStats,
+  resetToScopeBaseline,
+  type GitOptions,
+  type ScopeBaseline,
   type PrMergeabilityResult,
+  type TestCommandResult,
   type GitPreflightError,
   type GitFileChange,
   type StatusCompareOptions,
@@ -84,9 +166,19 @@ import {
   type RemoteValidationResult,
   type PrDetails,
 } from "../git";
-import { runPhaseCritique } from "./critique";
+import { runPhaseCritique as critiquePhase } from "./critique";
+import {
+  implementStoriesInParallel,
+  type ParallelStoriesResult,
+  type StoryRunResult,
+} from "./parallelStories";
 
-export { runPhaseCritique };
+export async function runPhaseCritique(
+  itemId: string,
+  options: WorkflowOptions,
+): Promise<PhaseResult> {
+  return recordPhase("critique", itemId, options, critiquePhase);
+}
 
 export interface WorkflowOptions {
   root: string;
@@ -102,6 +194,16 @@ export interface WorkflowOptions {
   onPhaseChanged?: (phase: WorkflowState | null) => void;
   /** Disable automatic self-healing for this workflow (Item 038) */
   noHealing?: boolean;
+  /** Orchestrator session cost so far, for budget.per_session_usd */
+  getSessionCostUsd?: () => number;
+  /** Spend caps for the phase's agent runs; set by recordPhase */
+  budget?: AgentBudget;
+  /** Stuck agent limits for the phase's agent runs; set by recordPhase */
+  stall?: StallLimits;
+  /** Where the phase's agents may write; set by recordPhase */
+  pathPolicy?: PathPolicy;
+  /** What the phase's agents may run through Bash; set by recordPhase */
+  bashPolicy?: BashPolicyConfig;
 }
 
 export interface PhaseResult {
@@ -112,6 +214,8 @@ export interface PhaseResult {
    * @deprecated String errors are deprecated. Use typed WreckitError for better programmatic handling.
    */
   error?: string | WreckitError;
+  /** Agent that ran the phase's last agent run, after any fallback */
+  agent?: string;
 }
 
 async function readFileIfExists(filePath: string): Promise<string | undefined> {
@@ -155,6 +259,9 @@ export async function buildValidationContext(
   const planCheck = await checkPathAccess(planPath);
   if (planCheck.error) throw planCheck.error;
 
+  const reportCheck = await checkPathAccess(getReportPath(root, item.id));
+  if (reportCheck.error) throw reportCheck.error;
+
   const hasResearchMd = researchCheck.exists;
   const hasPlanMd = planCheck.exists;
   const prd = await loadPrdSafe(itemDir);
@@ -167,6 +274,34 @@ export async function buildValidationContext(
     prd,
     hasPr,
     prMerged,
+    kind: item.kind,
+    reproduction: item.reproduction,
+    hasReportMd: reportCheck.exists,
+    storyTests: item.story_tests,
+    documentation: item.documentation,
+  };
+}
+
+interface PhaseStage {
+  pipeline: Pipeline;
+  stage: PipelineStage | undefined;
+  entryState: WorkflowState | null;
+}
+
+/**
+ * Resolve the pipeline stage a phase runner moves an item into.
+ * The stage supplies prompt and tool allowlist overrides.
+ */
+export function resolvePhaseStage(
+  config: ConfigResolved,
+  item: Item,
+  phase: PhaseName,
+): PhaseStage {
+  const pipeline = getItemPipeline(item, config);
+  return {
+    pipeline,
+    stage: getStageForPhase(pipeline, phase),
+    entryState: getPhaseEntryState(pipeline, phase),
   };
 }
 
@@ -183,11 +318,408 @@ async function saveItem(root: string, item: Item): Promise<void> {
   });
 }
 
+/**
+ * Append to the item's history.jsonl without letting audit failures
+ * break the workflow.
+ */
+async function recordHistory(
+  root: string,
+  itemId: string,
+  entry: Parameters<typeof appendHistory>[2],
+  logger: Logger,
+): Promise<void> {
+  try {
+    await appendHistory(root, itemId, entry);
+  } catch (err) {
+    logger.debug(`Failed to write history for ${itemId}: ${err}`);
+  }
+}
+
+/**
+ * Why an agent run failed, for the item's last_error.
+ */
+function describeAgentFailure(result: AgentResult): string {
+  if (result.timedOut) {
+    return "Agent timed out";
+  }
+  if (result.stall) {
+    return `Agent stopped as stuck: ${result.stall.message}`;
+  }
+  if (result.scopeViolation) {
+    return result.scopeViolation;
+  }
+  if (result.policyViolation) {
+    return `Agent ran a command denied by bash_policy: ${result.policyViolation}`;
+  }
+  return `Agent failed with exit code ${result.exitCode}`;
+}
+
+/**
+ * Snapshot the artifacts a phase generates as numbered revisions, so
+ * regenerating them never loses the previous version.
+ */
+async function recordRevisions(
+  root: string,
+  itemId: string,
+  phase: PhaseName,
+  logger: Logger,
+): Promise<void> {
+  for (const artifact of PHASE_ARTIFACTS[phase] ?? []) {
+    try {
+      const revision = await recordRevision(root, itemId, artifact);
+      if (revision !== null) {
+        logger.debug(
+          `Recorded ${artifact} revision ${revision} for ${itemId}`,
+        );
+      }
+    } catch (err) {
+      logger.debug(
+        `Failed to record ${artifact} revision for ${itemId}: ${err}`,
+      );
+    }
+  }
+}
+
+/**
+ * Add the token usage of a phase's agent runs to the item's per-phase totals.
+ */
+function withPhaseUsage(
+  item: Item,
+  phase: PhaseName,
+  runs: AgentUsage[],
+  config: ConfigResolved,
+): Item {
+  const totals = runs.reduce(
+    (sum, usage) => addRunUsage(sum, usage, config.prices),
+    item.usage?.[phase],
+  );
+  if (!totals) {
+    return item;
+  }
+  return { ...item, usage: { ...item.usage, [phase]: totals } };
+}
+
+// Phases meant to change the repo; only these are watched for idle time
+const EDITING_PHASES: PhaseName[] = [
+  "reproduce",
+  "tests",
+  "implement",
+  "document",
+];
+
+/**
+ * Stall detection limits for a phase's agent runs, if enabled.
+ */
+function getStallLimits(
+  config: ConfigResolved,
+  phase: PhaseName,
+): StallLimits | undefined {
+  const settings = config.stall_detection;
+  if (!settings?.enabled) {
+    return undefined;
+  }
+  return {
+    max_repeated_tool_calls: settings.max_repeated_tool_calls,
+    max_repeated_errors: settings.max_repeated_errors,
+    ...(EDITING_PHASES.includes(phase) && {
+      max_idle_seconds: settings.max_idle_seconds,
+    }),
+  };
+}
+
+interface LiveScope {
+  baseline: ScopeBaseline;
+  limits: LiveScopeLimits;
+}
+
+/**
+ * Record where a story starts so its scope can be checked while the agent
+ * runs, if `story_scope.live_check` is on.
+ */
+async function startLiveScope(
+  config: ConfigResolved,
+  storyId: string,
+  gitOptions: GitOptions,
+): Promise<LiveScope | null> {
+  const scopeConfig = config.story_scope;
+  if (!scopeConfig?.enabled || !scopeConfig.live_check) {
+    return null;
+  }
+  const baseline = await captureScopeBaseline(gitOptions);
+  return {
+    baseline,
+    limits: {
+      config: scopeConfig,
+      storyId,
+      getStats: () => getStoryDiffStats(baseline, gitOptions),
+    },
+  };
+}
+
+/**
+ * Undo a story's changes after its agent was aborted for exceeding scope,
+ * if `story_scope.reset_on_violation` is on.
+ */
+async function resetAfterScopeViolation(
+  result: AgentResult,
+  liveScope: LiveScope | null,
+  config: ConfigResolved,
+  gitOptions: GitOptions,
+): Promise<void> {
+  if (
+    !result.scopeViolation ||
+    !liveScope ||
+    !config.story_scope?.reset_on_violation
+  ) {
+    return;
+  }
+  const { storyId } = liveScope.limits;
+  try {
+    await resetToScopeBaseline(liveScope.baseline, gitOptions);
+    gitOptions.logger.warn(
+      `Reset the working tree to the start of story ${storyId}`,
+    );
+  } catch (err) {
+    gitOptions.logger.error(
+      `Failed to reset story ${storyId}: ${err instanceof Error ? err.message : String(err)}`,
+    );
+  }
+}
+
+/**
+ * Run a phase and record its start, outcome, duration and any state change
+ * in the item's history.jsonl. Backward moves are recorded by regressItem.
+ * If the phase is gated for human review, the item is left awaiting approval.
+ * Token usage reported by the phase's agent runs is added to `item.usage`,
+ * and the runs are held to the `budget` caps. Agent events are saved to a
+ * transcript (transcripts/<phase>-<n>.jsonl).
+ */
+async function recordPhase(
+  phase: PhaseName,
+  itemId: string,
+  options: WorkflowOptions,
+  run: (itemId: string, options: WorkflowOptions) => Promise<PhaseResult>,
+): Promise<PhaseResult> {
+  const { root, config, logger, dryRun = false } = options;
+  if (dryRun) {
+    return run(itemId, options);
+  }
+
+  const actor =
+    phase === "complete"
+      ? "system"
+      : getPhaseSettings(config, phase).agent.kind;
+  const before = await loadItem(root, itemId).catch(() => null);
+  if (!before) {
+    return run(itemId, options);
+  }
+
+  // Keep artifacts that predate revision tracking before they are overwritten
+  await recordRevisions(root, itemId, phase, logger);
+  await recordHistory(
+    root,
+    itemId,
+    { event: "phase_started", actor, phase, from: before.state },
+    logger,
+  );
+  const startedAt = Date.now();
+  const transcript = await createTranscriptWriter(
+    root,
+    itemId,
+    phase,
+    (err) => logger.debug(`Failed to write transcript for ${itemId}: ${err}`),
+  );
+
+  const runs: AgentUsage[] = [];
+  const budget: AgentBudget | undefined = config.budget && {
+    limits: config.budget,
+    prices: config.prices,
+    getSpent: () => {
+      const phaseRuns = runs.reduce(
+        (sum, usage) => sum + (computeCost(usage, config.prices) ?? 0),
+        0,
+      );
+      return {
+        item: (getItemUsage(before)?.cost_usd ?? 0) + phaseRuns,
+        phase: (before.usage?.[phase]?.cost_usd ?? 0) + phaseRuns,
+        session: options.getSessionCostUsd?.() ?? 0,
+      };
+    },
+  };
+  let agent = null as string | null;
+  const fallbacks: Parameters<typeof appendHistory>[2][] = [];
+  const tracked: WorkflowOptions = {
+    ...options,
+    budget,
+    stall: getStallLimits(config, phase),
+    pathPolicy: getPhasePathPolicy(config, phase, itemId),
+    bashPolicy: getBashPolicy(config),
+    onAgentEvent: (event) => {
+      transcript.write(event);
+      if (event.type === "usage") {
+        runs.push(event.usage);
+      } else if (event.type === "agent_started") {
+        agent = event.agent;
+      } else if (event.type === "agent_fallback") {
+        fallbacks.push({
+          ts: new Date().toISOString(),
+          event: "agent_fallback",
+          actor: "system",
+          phase,
+          agent: event.to,
+          reason: `${event.from} ${event.reason}`,
+        });
+      }
+      options.onAgentEvent?.(event);
+    },
+  };
+  const recordFallbacks = async () => {
+    for (const entry of fallbacks) {
+      await recordHistory(root, itemId, entry, logger);
+    }
+  };
+
+  let result: PhaseResult;
+  try {
+    result = await run(itemId, tracked);
+  } catch (err) {
+    await transcript.close();
+    const overBudget = err instanceof BudgetExceededError;
+    if (runs.length > 0 || overBudget) {
+      try {
+        const latest = withPhaseUsage(
+          await loadItem(root, itemId),
+          phase,
+          runs,
+          config,
+        );
+        await saveItem(
+          root,
+          err instanceof BudgetExceededError
+            ? { ...latest, last_error: err.message }
+            : latest,
+        );
+      } catch (saveErr) {
+        logger.debug(`Failed to record usage for ${itemId}: ${saveErr}`);
+      }
+    }
+    await recordFallbacks();
+    await recordHistory(
+      root,
+      itemId,
+      {
+        event: "phase_failed",
+        actor,
+        phase,
+        ...(agent ? { agent } : {}),
+        duration_ms: Date.now() - startedAt,
+        error: err instanceof Error ? err.message : String(err),
+        error_code:
+          err instanceof WreckitError ? err.code : ErrorCodes.PHASE_FAILED,
+      },
+      logger,
+    );
+    throw err;
+  }
+
+  await transcript.close();
+  if (runs.length > 0) {
+    const item = withPhaseUsage(result.item, phase, runs, config);
+    await saveItem(root, item);
+    result = { ...result, item };
+  }
+  if (agent) {
+    result = { ...result, agent };
+  }
+  await recordFallbacks();
+
+  const durationMs = Date.now() - startedAt;
+  const after = result.item.state;
+  const pipeline = getItemPipeline(result.item, config);
+  if (after !== before.state) {
+    if (getStateIndex(after, pipeline) > getStateIndex(before.state, pipeline)) {
+      for (const entry of createAdvanceEntries(
+        before.state,
+        after,
+        actor,
+        pipeline,
+        { phase },
+      )) {
+        await recordHistory(root, itemId, entry, logger);
+      }
+    }
+  }
+
+  if (result.success) {
+    await recordRevisions(root, itemId, phase, logger);
+    await recordHistory(
+      root,
+      itemId,
+      {
+        event: "phase_succeeded",
+        actor,
+        phase,
+        ...(agent ? { agent } : {}),
+        to: after,
+        duration_ms: durationMs,
+      },
+      logger,
+    );
+
+    // Gate on the phase succeeding rather than on landing exactly on its
+    // target state: implement hands off straight to critique, and a passing
+    // critique leaves the state unchanged.
+    const target = getStateIndex(PHASE_TARGET_STATES[phase], pipeline);
+    if (
+      target >= 0 &&
+      getStateIndex(after, pipeline) >= target &&
+      requiresApproval(config, phase)
+    ) {
+      const gated: Item = { ...result.item, pending_approval: phase };
+      await saveItem(root, gated);
+      await recordHistory(
+        root,
+        itemId,
+        { event: "approval_requested", actor: "system", phase },
+        logger,
+      );
+      logger.info(
+        `${phase} output for ${itemId} awaits approval: wreckit approve ${itemId} / wreckit reject ${itemId} --comment "..."`,
+      );
+      result = { ...result, item: gated };
+    }
+  } else {
+    await recordHistory(
+      root,
+      itemId,
+      {
+        event: "phase_failed",
+        actor,
+        phase,
+        ...(agent ? { agent } : {}),
+        to: after,
+        duration_ms: durationMs,
+        error:
+          result.error instanceof WreckitError
+            ? result.error.message
+            : result.error,
+        error_code:
+          result.error instanceof WreckitError
+            ? result.error.code
+            : ErrorCodes.PHASE_FAILED,
+      },
+      logger,
+    );
+  }
+
+  return result;
+}
+
 async function buildPromptVariables(
   root: string,
   item: Item,
   config: ConfigResolved,
-  phase?: string, // Add optional phase parameter for skill loading
+  phase?: PhaseName, // Add optional phase parameter for skill loading
 ): Promise<PromptVariables> {
   const itemDir = getItemDir(root, item.id);
   const branchName = `${config.branch_prefix}${item.id.replace("/", "-")}`;
@@ -198,7 +730,7 @@ async function buildPromptVariables(
   const progress = await readFileIfExists(getProgressLogPath(root, item.id));
 
   // Determine completion_signal and sdk_mode based on agent kind
-  const agent = config.agent;
+  const agent = phase ? getPhaseSettings(config, phase).agent : config.agent;
   const isProcessMode = agent.kind === "process";
   const completionSignal = isProcessMode
     ? agent.completion_signal
@@ -248,6 +780,10 @@ Scope Limits (Enforced):
 - Max Bytes: ${config.story_scope.max_diff_bytes}
 - Excluded Patterns: ${config.story_scope.exclude_patterns.join(", ")}
     `.trim();
+    if (config.story_scope.live_check) {
+      scopeLimits +=
+        "\nYour run is stopped as soon as the story's changes exceed these limits.";
+    }
   }
 
   return {
@@ -266,12 +802,27 @@ Scope Limits (Enforced):
     progress,
     skill_context: skillContext,
     scope_limits: scopeLimits,
+    review_feedback: phase ? formatReviewFeedback(item, phase) : undefined,
+    kind: item.kind ?? "feature",
+    reproduction_command: item.reproduction?.command,
+    reproduction_tests: item.reproduction?.test_files.join(", "),
+    tests_command: item.story_tests?.command,
+    story_tests: item.story_tests
+      ? formatStoryTests(item.story_tests)
+      : undefined,
   };
 }
 
 export async function runPhaseResearch(
   itemId: string,
   options: WorkflowOptions,
+): Promise<PhaseResult> {
+  return recordPhase("research", itemId, options, researchPhase);
+}
+
+async function researchPhase(
+  itemId: string,
+  options: WorkflowOptions,
 ): Promise<PhaseResult> {
   const {
     root,
@@ -287,8 +838,15 @@ export async function runPhaseResearch(
 
   let item = await loadItem(root, itemId);
   const researchPath = getResearchPath(root, item.id);
-
-  if (!force && (await pathExists(researchPath))) {
+  // Spikes answer their question in report.md alongside research.md
+  const reportPath =
+    item.kind === "spike" ? getReportPath(root, item.id) : null;
+
+  if (
+    !force &&
+    (await pathExists(researchPath)) &&
+    (!reportPath || (await pathExists(reportPath)))
+  ) {
     logger.info(`Research already exists for ${itemId}, skipping`);
     if (item.state === "idea") {
       item = { ...item, state: "researched" };
@@ -312,7 +870,12 @@ export async function runPhaseResearch(
     item = { ...item, state: "idea" };
   }
 
-  const template = await loadPromptTemplate(root, "research");
+  const { pipeline, stage } = resolvePhaseStage(config, item, "research");
+  const template = await loadPromptTemplate(
+    root,
+    stage?.prompt ?? "research",
+    item.kind,
+  );
   const baseVariables = await buildPromptVariables(
     root,
     item,
@@ -321,17 +884,21 @@ export async function runPhaseResearch(
   ); // Add phase
 
   const itemDir = getItemDir(root, item.id);
-  const agentConfig = getAgentConfigUnion(config);
+  const agentConfig = getAgentConfigUnion(config, "research");
 
   // Load skills for research phase (Item 033)
-  const skillResult = loadSkillsForPhase("research", config.skills);
+  const skillResult = loadSkillsForPhase(
+    "research",
+    config.skills,
+    stage?.allowedTools,
+  );
 
   // Capture git status before running agent for read-only enforcement
   const beforeStatus: GitFileChange[] =
     dryRun || mockAgent ? [] : await getGitStatus({ cwd: root, logger });
 
   let attempt = 0;
-  const maxAttempts = 3;
+  const maxAttempts = getPhaseSettings(config, "research").max_iterations;
   let validationError: string | null = null;
   let lastError: string | null = null;
 
@@ -371,10 +938,15 @@ export async function runPhaseResearch(
         logger,
         dryRun,
         mockAgent,
-        timeoutSeconds: config.timeout_seconds,
+        timeoutSeconds: getPhaseSettings(config, "research").timeout_seconds,
         onStdoutChunk: onAgentOutput,
         onStderrChunk: onAgentOutput,
         onAgentEvent,
+        budget: options.budget,
+        stall: options.stall,
+        pathPolicy: options.pathPolicy,
+        bashPolicy: options.bashPolicy,
+        fallbacks: getPhaseSettings(config, "research").agent_fallbacks,
         // Merge skill MCP servers (Item 033)
         mcpServers: {
           ...(skillResult.mcpServers || {}),
@@ -397,9 +969,7 @@ export async function runPhaseResearch(
     }
 
     if (!result.success) {
-      lastError = result.timedOut
-        ? "Agent timed out"
-        : `Agent failed with exit code ${result.exitCode}`;
+      lastError = describeAgentFailure(result);
       validationError = null; // System error, not validation error
       // Don't retry on system errors (unless we want to?) - for now, break
       break;
@@ -426,12 +996,30 @@ export async function runPhaseResearch(
         `${qualityResult.summaryLength} char summary, ${qualityResult.analysisLength} char analysis`,
     );
 
+    if (reportPath) {
+      if (!(await pathExists(reportPath))) {
+        validationError = "Agent did not create report.md";
+        lastError = validationError;
+        continue; // Retry
+      }
+      const reportResult = validateSpikeReport(
+        await fs.readFile(reportPath, "utf-8"),
+      );
+      if (!reportResult.valid) {
+        validationError = `Spike report validation failed:\n${reportResult.errors.join("\n")}`;
+        lastError = validationError;
+        continue; // Retry
+      }
+    }
+
     // Enforce read-only behavior: check for unauthorized file modifications
     const allowedResearchPath = `.wreckit/items/${item.id}/research.md`;
     const compareOptions: StatusCompareOptions = {
       cwd: root,
       logger,
-      allowedPaths: [allowedResearchPath],
+      allowedPaths: reportPath
+        ? [allowedResearchPath, `.wreckit/items/${item.id}/report.md`]
+        : [allowedResearchPath],
     };
 
     const comparison = await compareGitStatus(beforeStatus, compareOptions);
@@ -445,7 +1033,12 @@ export async function runPhaseResearch(
 
     // Validation passed!
     const newCtx = await buildValidationContext(root, item);
-    const validation = validateTransition(item.state, targetState, newCtx);
+    const validation = validateTransition(
+      item.state,
+      targetState,
+      newCtx,
+      pipeline,
+    );
     if (!validation.valid) {
       const error = validation.reason ?? "Validation failed";
       item = { ...item, last_error: error };
@@ -466,7 +1059,27 @@ export async function runPhaseResearch(
   return { success: false, item, error: finalError };
 }
 
-export async function runPhasePlan(
+export async function runPhaseReproduce(
+  itemId: string,
+  options: WorkflowOptions,
+): Promise<PhaseResult> {
+  return recordPhase("reproduce", itemId, options, reproducePhase);
+}
+
+/** Keep retry feedback short; test runners can be very chatty */
+function tailOutput(output: string, maxLines = 40): string {
+  const lines = output.split("\n");
+  return lines.length > maxLines
+    ? lines.slice(-maxLines).join("\n")
+    : output;
+}
+
+/**
+ * Bug items: the agent writes a failing test and reports the command that
+ * runs it. wreckit runs the command itself and only accepts the
+ * reproduction if it fails.
+ */
+async function reproducePhase(
   itemId: string,
   options: WorkflowOptions,
 ): Promise<PhaseResult> {
@@ -482,87 +1095,105 @@ export async function runPhasePlan(
   } = options;
 
   let item = await loadItem(root, itemId);
-  const planPath = getPlanPath(root, item.id);
-  const prdPath = getPrdPath(root, item.id);
+  const targetState: WorkflowState = "reproduced";
+  const { pipeline, stage, entryState } = resolvePhaseStage(
+    config,
+    item,
+    "reproduce",
+  );
+  if (entryState === null) {
+    return {
+      success: false,
+      item,
+      error: `Pipeline '${pipeline.name}' has no reproduce stage`,
+    };
+  }
 
-  if (!force && (await pathExists(planPath)) && (await pathExists(prdPath))) {
-    logger.info(`Plan already exists for ${itemId}, skipping`);
-    if (item.state === "researched") {
-      const prd = await loadPrdSafe(getItemDir(root, item.id));
-      if (prd) {
-        item = { ...item, state: "planned" };
-        await saveItem(root, item);
-      }
+  if (!force && item.reproduction?.failing_run) {
+    logger.info(`Reproduction already recorded for ${itemId}, skipping`);
+    if (item.state === entryState) {
+      item = { ...item, state: targetState };
+      await saveItem(root, item);
     }
     return { success: true, item };
   }
 
-  if (item.state !== "researched" && !force) {
+  if (item.state !== entryState && !force) {
     return {
       success: false,
       item,
-      error: `Item is in state ${item.state}, expected 'researched' for plan phase`,
+      error: `Item is in state ${item.state}, expected '${entryState}' for reproduce phase`,
     };
   }
 
-  const template = await loadPromptTemplate(root, "plan");
-  const baseVariables = await buildPromptVariables(root, item, config, "plan"); // Add phase
+  if (force && item.state !== entryState) {
+    item = { ...item, state: entryState };
+  }
 
+  const template = await loadPromptTemplate(
+    root,
+    stage?.prompt ?? "reproduce",
+    item.kind,
+  );
+  const variables = await buildPromptVariables(
+    root,
+    item,
+    config,
+    "reproduce",
+  );
   const itemDir = getItemDir(root, item.id);
-  const agentConfig = getAgentConfigUnion(config);
-
-  // Capture git status before running agent for design-only enforcement
-  const beforeStatus: GitFileChange[] =
-    dryRun || mockAgent ? [] : await getGitStatus({ cwd: root, logger });
+  const agentConfig = getAgentConfigUnion(config, "reproduce");
+  const phaseSettings = getPhaseSettings(config, "reproduce");
+  const skillResult = loadSkillsForPhase(
+    "reproduce",
+    config.skills,
+    stage?.allowedTools,
+  );
 
-  let attempt = 0;
-  const maxAttempts = 3;
-  let validationError: string | null = null;
+  const maxAttempts = phaseSettings.max_iterations;
+  let feedback: string | null = null;
   let lastError: string | null = null;
 
-  while (attempt < maxAttempts) {
-    attempt++;
+  for (let attempt = 1; attempt <= maxAttempts; attempt++) {
     if (attempt > 1) {
       logger.warn(
-        `Plan validation failed (attempt ${attempt - 1}/${maxAttempts}). Retrying...`,
+        `Reproduction failed (attempt ${attempt - 1}/${maxAttempts}). Retrying...`,
       );
     }
 
-    // Append validation feedback to prompt if this is a retry
-    let prompt = renderPrompt(template, baseVariables);
-    if (validationError) {
-      prompt += `\n\nCRITICAL: Your previous attempt failed validation with the following errors:\n${validationError}\n\nYou MUST fix these issues in this attempt. Ensure you strictly follow all format requirements, section headers, and JSON schemas.`;
+    let prompt = renderPrompt(template, variables);
+    if (feedback) {
+      prompt += `\n\nCRITICAL: Your previous attempt did not reproduce the bug:\n${feedback}\n\nYou MUST fix this in this attempt.`;
     }
 
-    // Create MCP server to capture PRD via tool call
-    let capturedPrd: Prd | null = null;
+    let saved: ReproductionData | null = null;
     const wreckitServer = createWreckitMcpServer({
-      onSavePrd: (prd) => {
-        capturedPrd = prd;
+      onSaveReproduction: (data) => {
+        saved = data;
       },
     });
 
-    // Load skills for plan phase (Item 033)
-    const skillResult = loadSkillsForPhase("plan", config.skills);
-
     const result = await runAgentUnion({
-      itemId: itemId,
+      itemId,
       config: agentConfig,
       cwd: itemDir,
       prompt,
       logger,
       dryRun,
       mockAgent,
-      timeoutSeconds: config.timeout_seconds,
+      timeoutSeconds: phaseSettings.timeout_seconds,
       onStdoutChunk: onAgentOutput,
       onStderrChunk: onAgentOutput,
       onAgentEvent,
-      // Merge wreckit MCP server with skill MCP servers (Item 033)
+      budget: options.budget,
+      stall: options.stall,
+      pathPolicy: options.pathPolicy,
+      bashPolicy: options.bashPolicy,
+      fallbacks: phaseSettings.agent_fallbacks,
       mcpServers: {
         wreckit: wreckitServer,
         ...(skillResult.mcpServers || {}),
       },
-      // Use skill-merged tool allowlist (or phase tools if no skills)
       allowedTools: skillResult.allowedTools,
     });
 
@@ -571,30 +1202,231 @@ export async function runPhasePlan(
     }
 
     if (mockAgent) {
-      item = { ...item, state: "planned", last_error: null };
+      // The mock agent writes no test; record a stand-in failing run
+      const reproduction = recordTestRun(
+        {
+          command: "mock-reproduction",
+          test_files: [],
+          failing_run: null,
+          passing_run: null,
+        },
+        1,
+      );
+      item = { ...item, state: targetState, reproduction, last_error: null };
       await saveItem(root, item);
       return { success: true, item };
     }
 
     if (!result.success) {
-      lastError = result.timedOut
-        ? "Agent timed out"
-        : `Agent failed with exit code ${result.exitCode}`;
-      validationError = null; // System error
+      lastError = describeAgentFailure(result);
       break;
     }
 
-    if (!(await pathExists(planPath))) {
-      validationError = "Agent did not create plan.md";
-      lastError = validationError;
+    const data = saved as ReproductionData | null;
+    if (!data) {
+      lastError = "Agent did not save a reproduction test";
+      feedback = "You did not call the save_reproduction tool.";
       continue;
     }
 
-    // Validate plan document quality (Gap 2: Plan Content Quality Validation)
-    const planContent = await fs.readFile(planPath, "utf-8");
-    const planQualityResult = validatePlanQuality(planContent);
-
-    if (!planQualityResult.valid) {
+    const run = await runTestCommand(data.command, { cwd: root, logger });
+    if (run.error) {
+      lastError = `Reproduction command could not be run: ${run.error}`;
+      feedback = `\`${data.command}\` could not be started (${run.error}).`;
+      continue;
+    }
+    if (run.passed) {
+      lastError = `Reproduction test passed, so it does not show the bug: ${data.command}`;
+      feedback = `\`${data.command}\` passed. The test must fail while the bug exists.\n\nOutput:\n${tailOutput(run.output)}`;
+      continue;
+    }
+
+    const reproduction: Reproduction = recordTestRun(
+      { ...data, failing_run: null, passing_run: null },
+      run.exitCode,
+    );
+    const ctx = await buildValidationContext(root, { ...item, reproduction });
+    const validation = validateTransition(
+      item.state,
+      targetState,
+      ctx,
+      pipeline,
+    );
+    if (!validation.valid) {
+      const error = validation.reason ?? "Validation failed";
+      item = { ...item, last_error: error };
+      await saveItem(root, item);
+      return { success: false, item, error };
+    }
+
+    logger.info(
+      `Reproduced bug for ${itemId}: '${data.command}' failed with exit code ${run.exitCode}`,
+    );
+    item = { ...item, state: targetState, reproduction, last_error: null };
+    await saveItem(root, item);
+    return { success: true, item };
+  }
+
+  const finalError = lastError ?? "Reproduce phase failed after max attempts";
+  item = { ...item, last_error: finalError };
+  await saveItem(root, item);
+  return { success: false, item, error: finalError };
+}
+
+export async function runPhasePlan(
+  itemId: string,
+  options: WorkflowOptions,
+): Promise<PhaseResult> {
+  return recordPhase("plan", itemId, options, planPhase);
+}
+
+async function planPhase(
+  itemId: string,
+  options: WorkflowOptions,
+): Promise<PhaseResult> {
+  const {
+    root,
+    config,
+    logger,
+    force = false,
+    dryRun = false,
+    mockAgent = false,
+    onAgentOutput,
+    onAgentEvent,
+  } = options;
+
+  let item = await loadItem(root, itemId);
+  const planPath = getPlanPath(root, item.id);
+  const prdPath = getPrdPath(root, item.id);
+
+  const { pipeline, stage, entryState } = resolvePhaseStage(
+    config,
+    item,
+    "plan",
+  );
+  const planEntryState = entryState ?? "researched";
+
+  if (!force && (await pathExists(planPath)) && (await pathExists(prdPath))) {
+    logger.info(`Plan already exists for ${itemId}, skipping`);
+    if (item.state === planEntryState) {
+      const prd = await loadPrdSafe(getItemDir(root, item.id));
+      if (prd) {
+        item = { ...item, state: "planned" };
+        await saveItem(root, item);
+      }
+    }
+    return { success: true, item };
+  }
+
+  if (item.state !== planEntryState && !force) {
+    return {
+      success: false,
+      item,
+      error: `Item is in state ${item.state}, expected '${planEntryState}' for plan phase`,
+    };
+  }
+
+  const template = await loadPromptTemplate(
+    root,
+    stage?.prompt ?? "plan",
+    item.kind,
+  );
+  const baseVariables = await buildPromptVariables(root, item, config, "plan"); // Add phase
+
+  const itemDir = getItemDir(root, item.id);
+  const agentConfig = getAgentConfigUnion(config, "plan");
+
+  // Capture git status before running agent for design-only enforcement
+  const beforeStatus: GitFileChange[] =
+    dryRun || mockAgent ? [] : await getGitStatus({ cwd: root, logger });
+
+  let attempt = 0;
+  const maxAttempts = getPhaseSettings(config, "plan").max_iterations;
+  let validationError: string | null = null;
+  let lastError: string | null = null;
+
+  while (attempt < maxAttempts) {
+    attempt++;
+    if (attempt > 1) {
+      logger.warn(
+        `Plan validation failed (attempt ${attempt - 1}/${maxAttempts}). Retrying...`,
+      );
+    }
+
+    // Append validation feedback to prompt if this is a retry
+    let prompt = renderPrompt(template, baseVariables);
+    if (validationError) {
+      prompt += `\n\nCRITICAL: Your previous attempt failed validation with the following errors:\n${validationError}\n\nYou MUST fix these issues in this attempt. Ensure you strictly follow all format requirements, section headers, and JSON schemas.`;
+    }
+
+    // Create MCP server to capture PRD via tool call
+    let capturedPrd: Prd | null = null;
+    const wreckitServer = createWreckitMcpServer({
+      onSavePrd: (prd) => {
+        capturedPrd = prd;
+      },
+    });
+
+    // Load skills for plan phase (Item 033)
+    const skillResult = loadSkillsForPhase(
+      "plan",
+      config.skills,
+      stage?.allowedTools,
+    );
+
+    const result = await runAgentUnion({
+      itemId: itemId,
+      config: agentConfig,
+      cwd: itemDir,
+      prompt,
+      logger,
+      dryRun,
+      mockAgent,
+      timeoutSeconds: getPhaseSettings(config, "plan").timeout_seconds,
+      onStdoutChunk: onAgentOutput,
+      onStderrChunk: onAgentOutput,
+      onAgentEvent,
+      budget: options.budget,
+      stall: options.stall,
+      pathPolicy: options.pathPolicy,
+      bashPolicy: options.bashPolicy,
+      fallbacks: getPhaseSettings(config, "plan").agent_fallbacks,
+      // Merge wreckit MCP server with skill MCP servers (Item 033)
+      mcpServers: {
+        wreckit: wreckitServer,
+        ...(skillResult.mcpServers || {}),
+      },
+      // Use skill-merged tool allowlist (or phase tools if no skills)
+      allowedTools: skillResult.allowedTools,
+    });
+
+    if (dryRun) {
+      return { success: true, item };
+    }
+
+    if (mockAgent) {
+      item = { ...item, state: "planned", last_error: null };
+      await saveItem(root, item);
+      return { success: true, item };
+    }
+
+    if (!result.success) {
+      lastError = describeAgentFailure(result);
+      validationError = null; // System error
+      break;
+    }
+
+    if (!(await pathExists(planPath))) {
+      validationError = "Agent did not create plan.md";
+      lastError = validationError;
+      continue;
+    }
+
+    // Validate plan document quality (Gap 2: Plan Content Quality Validation)
+    const planContent = await fs.readFile(planPath, "utf-8");
+    const planQualityResult = validatePlanQuality(planContent);
+
+    if (!planQualityResult.valid) {
       validationError = `Plan quality validation failed:\n${planQualityResult.errors.join("\n")}`;
       lastError = validationError;
       continue;
@@ -663,7 +1495,12 @@ export async function runPhasePlan(
     // Validation passed!
     const targetState: WorkflowState = "planned";
     const newCtx = await buildValidationContext(root, item);
-    const validation = validateTransition(item.state, targetState, newCtx);
+    const validation = validateTransition(
+      item.state,
+      targetState,
+      newCtx,
+      pipeline,
+    );
     if (!validation.valid) {
       const error = validation.reason ?? "Validation failed";
       item = { ...item, last_error: error };
@@ -684,9 +1521,243 @@ export async function runPhasePlan(
   return { success: false, item, error: finalError };
 }
 
+export async function runPhaseTests(
+  itemId: string,
+  options: WorkflowOptions,
+): Promise<PhaseResult> {
+  return recordPhase("tests", itemId, options, testsPhase);
+}
+
+/**
+ * Optional tests phase: the agent writes tests for every story's
+ * acceptance criteria before implementation. wreckit runs them and only
+ * accepts them if they compile but fail.
+ */
+async function testsPhase(
+  itemId: string,
+  options: WorkflowOptions,
+): Promise<PhaseResult> {
+  const {
+    root,
+    config,
+    logger,
+    force = false,
+    dryRun = false,
+    mockAgent = false,
+    onAgentOutput,
+    onAgentEvent,
+  } = options;
+
+  let item = await loadItem(root, itemId);
+  const targetState: WorkflowState = "tests_written";
+  const { pipeline, stage, entryState } = resolvePhaseStage(
+    config,
+    item,
+    "tests",
+  );
+  if (entryState === null) {
+    return {
+      success: false,
+      item,
+      error: `Pipeline '${pipeline.name}' has no tests stage`,
+    };
+  }
+
+  if (!force && item.story_tests?.failing_run) {
+    logger.info(`Story tests already recorded for ${itemId}, skipping`);
+    if (item.state === entryState) {
+      item = { ...item, state: targetState };
+      await saveItem(root, item);
+    }
+    return { success: true, item };
+  }
+
+  if (item.state !== entryState && !force) {
+    return {
+      success: false,
+      item,
+      error: `Item is in state ${item.state}, expected '${entryState}' for tests phase`,
+    };
+  }
+
+  if (force && item.state !== entryState) {
+    item = { ...item, state: entryState };
+  }
+
+  const template = await loadPromptTemplate(
+    root,
+    stage?.prompt ?? "tests",
+    item.kind,
+  );
+  const variables = await buildPromptVariables(root, item, config, "tests");
+  const itemDir = getItemDir(root, item.id);
+  const agentConfig = getAgentConfigUnion(config, "tests");
+  const phaseSettings = getPhaseSettings(config, "tests");
+  const skillResult = loadSkillsForPhase(
+    "tests",
+    config.skills,
+    stage?.allowedTools,
+  );
+
+  const maxAttempts = phaseSettings.max_iterations;
+  let feedback: string | null = null;
+  let lastError: string | null = null;
+
+  for (let attempt = 1; attempt <= maxAttempts; attempt++) {
+    if (attempt > 1) {
+      logger.warn(
+        `Story tests rejected (attempt ${attempt - 1}/${maxAttempts}). Retrying...`,
+      );
+    }
+
+    let prompt = renderPrompt(template, variables);
+    if (feedback) {
+      prompt += `\n\nCRITICAL: Your previous attempt was rejected:\n${feedback}\n\nYou MUST fix this in this attempt.`;
+    }
+
+    let saved: StoryTestsData | null = null;
+    const wreckitServer = createWreckitMcpServer({
+      onSaveStoryTests: (data) => {
+        saved = data;
+      },
+    });
+
+    const result = await runAgentUnion({
+      itemId,
+      config: agentConfig,
+      cwd: itemDir,
+      prompt,
+      logger,
+      dryRun,
+      mockAgent,
+      timeoutSeconds: phaseSettings.timeout_seconds,
+      onStdoutChunk: onAgentOutput,
+      onStderrChunk: onAgentOutput,
+      onAgentEvent,
+      budget: options.budget,
+      stall: options.stall,
+      pathPolicy: options.pathPolicy,
+      bashPolicy: options.bashPolicy,
+      fallbacks: phaseSettings.agent_fallbacks,
+      mcpServers: {
+        wreckit: wreckitServer,
+        ...(skillResult.mcpServers || {}),
+      },
+      allowedTools: skillResult.allowedTools,
+    });
+
+    if (dryRun) {
+      return { success: true, item };
+    }
+
+    if (mockAgent) {
+      // The mock agent writes no tests; record a stand-in failing run
+      const prd = await loadPrdSafe(itemDir);
+      const storyTests: StoryTests = {
+        command: "mock-story-tests",
+        stories: (prd?.user_stories ?? []).map((story) => ({
+          story_id: story.id,
+          test_files: [],
+          tests: [`${story.id} acceptance criteria`],
+        })),
+        failing_run: {
+          at: new Date().toISOString(),
+          exit_code: 1,
+          passed: false,
+        },
+      };
+      item = {
+        ...item,
+        state: targetState,
+        story_tests: storyTests,
+        last_error: null,
+      };
+      await saveItem(root, item);
+      return { success: true, item };
+    }
+
+    if (!result.success) {
+      lastError = describeAgentFailure(result);
+      break;
+    }
+
+    const data = saved as StoryTestsData | null;
+    if (!data) {
+      lastError = "Agent did not save its story tests";
+      feedback = "You did not call the save_story_tests tool.";
+      continue;
+    }
+
+    const run = await runTestCommand(data.command, { cwd: root, logger });
+    if (run.error) {
+      lastError = `Story test command could not be run: ${run.error}`;
+      feedback = `\`${data.command}\` could not be started (${run.error}).`;
+      continue;
+    }
+
+    const outcomes = parseTestOutput(run.output);
+    const problem = checkTestsFailBeforeImplementation(outcomes, run.passed);
+    if (problem) {
+      lastError = `Story tests rejected: ${problem}`;
+      feedback = `\`${data.command}\`: ${problem}.\n\nOutput:\n${tailOutput(run.output)}`;
+      continue;
+    }
+    if (outcomes.length === 0) {
+      logger.warn(
+        `No per-test results in the output of '${data.command}'; cannot tell failing tests from compile errors`,
+      );
+    }
+
+    const storyTests: StoryTests = {
+      ...data,
+      failing_run: {
+        at: new Date().toISOString(),
+        exit_code: run.exitCode,
+        passed: false,
+      },
+    };
+    const validation = validateTransition(
+      item.state,
+      targetState,
+      await buildValidationContext(root, { ...item, story_tests: storyTests }),
+      pipeline,
+    );
+    if (!validation.valid) {
+      // Stories without tests are for the agent to fix, so retry
+      lastError = validation.reason ?? "Validation failed";
+      feedback = lastError;
+      continue;
+    }
+
+    logger.info(
+      `Wrote failing tests for ${data.stories.length} stories of ${itemId}`,
+    );
+    item = {
+      ...item,
+      state: targetState,
+      story_tests: storyTests,
+      last_error: null,
+    };
+    await saveItem(root, item);
+    return { success: true, item };
+  }
+
+  const finalError = lastError ?? "Tests phase failed after max attempts";
+  item = { ...item, last_error: finalError };
+  await saveItem(root, item);
+  return { success: false, item, error: finalError };
+}
+
 export async function runPhaseImplement(
   itemId: string,
   options: WorkflowOptions,
+): Promise<PhaseResult> {
+  return recordPhase("implement", itemId, options, implementPhase);
+}
+
+async function implementPhase(
+  itemId: string,
+  options: WorkflowOptions,
 ): Promise<PhaseResult> {
   const {
     root,
@@ -704,12 +1775,23 @@ export async function runPhaseImplement(
 
   let item = await loadItem(root, itemId);
   const itemDir = getItemDir(root, item.id);
-
-  if (item.state !== "planned" && item.state !== "implementing" && !force) {
+  const { pipeline, stage, entryState } = resolvePhaseStage(
+    config,
+    item,
+    "implement",
+  );
+  const implementEntryState = entryState ?? "planned";
+  const implementTemplateName = stage?.prompt ?? "implement";
+
+  if (
+    item.state !== implementEntryState &&
+    item.state !== "implementing" &&
+    !force
+  ) {
     return {
       success: false,
       item,
-      error: `Item is in state ${item.state}, expected 'planned' or 'implementing' for implement phase`,
+      error: `Item is in state ${item.state}, expected '${implementEntryState}' or 'implementing' for implement phase`,
     };
   }
 
@@ -717,18 +1799,31 @@ export async function runPhaseImplement(
     item = { ...item, state: "implementing", last_error: null };
     await saveItem(root, item);
 
-    const template = await loadPromptTemplate(root, "implement");
+    const template = await loadPromptTemplate(
+      root,
+      implementTemplateName,
+      item.kind,
+    );
     const variables = await buildPromptVariables(
       root,
       item,
       config,
       "implement",
     ); // Add phase
+    const mockPrd = await loadPrdSafe(itemDir);
+    const mockStory = mockPrd ? getNextStory(mockPrd) : null;
+    if (mockStory) {
+      variables.current_story = `${mockStory.id}: ${mockStory.title}`;
+    }
     const prompt = renderPrompt(template, variables);
-    const agentConfig = getAgentConfigUnion(config);
+    const agentConfig = getAgentConfigUnion(config, "implement");
 
     // Load skills for implement phase (Item 033)
-    const skillResult = loadSkillsForPhase("implement", config.skills);
+    const skillResult = loadSkillsForPhase(
+      "implement",
+      config.skills,
+      stage?.allowedTools,
+    );
 
     await runAgentUnion({
       config: agentConfig,
@@ -737,10 +1832,15 @@ export async function runPhaseImplement(
       logger,
       dryRun,
       mockAgent,
-      timeoutSeconds: config.timeout_seconds,
+      timeoutSeconds: getPhaseSettings(config, "implement").timeout_seconds,
       onStdoutChunk: onAgentOutput,
       onStderrChunk: onAgentOutput,
       onAgentEvent,
+      budget: options.budget,
+      stall: options.stall,
+      pathPolicy: options.pathPolicy,
+      bashPolicy: options.bashPolicy,
+      fallbacks: getPhaseSettings(config, "implement").agent_fallbacks,
       // Merge skill MCP servers (implement phase has no wreckit server in mock mode)
       mcpServers: {
         ...(skillResult.mcpServers || {}),
@@ -761,7 +1861,7 @@ export async function runPhaseImplement(
 
   if (allStoriesDone(prd)) {
     logger.info(`All stories already done for ${itemId}`);
-    if (item.state === "planned") {
+    if (item.state === implementEntryState) {
       item = { ...item, state: "implementing" };
       await saveItem(root, item);
       onPhaseChanged?.("implementing");
@@ -769,42 +1869,94 @@ export async function runPhaseImplement(
     return { success: true, item };
   }
 
-  if (item.state === "planned") {
+  if (item.state === implementEntryState) {
     item = { ...item, state: "implementing" };
     await saveItem(root, item);
     onPhaseChanged?.("implementing");
   }
 
+  const baseline =
+    requiresBehaviorCheck(item.kind) && !dryRun
+      ? await ensureTestBaseline(item, prd, options)
+      : null;
+
+  if (config.parallel_stories?.enabled && !dryRun) {
+    const pendingBefore = getPendingStoryIds(prd);
+    const parallel = await implementInWorktrees(
+      item,
+      stage,
+      implementTemplateName,
+      config.parallel_stories.max_workers,
+      options,
+    );
+    if (!parallel.success) {
+      const error = parallel.error ?? "Parallel story implementation failed";
+      logger.error(error);
+      item = { ...item, last_error: error };
+      await saveItem(root, item);
+      return { success: false, item, error };
+    }
+    if (baseline) {
+      const blocked = await verifyBehavior(
+        itemId,
+        baseline,
+        getCompletedStoryIds(pendingBefore, await loadPrdSafe(itemDir)),
+        options,
+      );
+      if (blocked) return blocked;
+    }
+    return completeImplementation(itemId, pipeline, options);
+  }
+
   let iteration = 0;
-  const maxIterations = config.max_iterations;
+  const maxIterations = getPhaseSettings(config, "implement").max_iterations;
 
   while (hasPendingStories(prd) && iteration < maxIterations) {
     iteration++;
     onIterationChanged?.(iteration, maxIterations);
 
-    const pendingStories = prd.user_stories
-      .filter((s) => s.status === "pending")
-      .sort((a, b) => a.priority - b.priority);
-
-    if (pendingStories.length === 0) break;
+    const currentStory = getNextStory(prd);
+    if (!currentStory) {
+      const blocked = prd.user_stories
+        .filter((s) => s.status === "pending")
+        .map((s) => s.id);
+      const error = `No pending story has all its dependencies done (pending: ${blocked.join(", ")})`;
+      logger.error(error);
+      item = { ...item, last_error: error };
+      await saveItem(root, item);
+      return { success: false, item, error };
+    }
 
-    const currentStory = pendingStories[0];
     onStoryChanged?.({ id: currentStory.id, title: currentStory.title });
     logger.info(
       `Implementing story ${currentStory.id} (iteration ${iteration}/${maxIterations})`,
     );
 
+    const pendingBefore = getPendingStoryIds(prd);
+
     // Capture git status before running agent for scope enforcement (Gap 2)
     const beforeStatus: GitFileChange[] =
       dryRun || mockAgent ? [] : await getGitStatus({ cwd: root, logger });
-
-    const template = await loadPromptTemplate(root, "implement");
+    const liveScope =
+      dryRun || mockAgent
+        ? null
+        : await startLiveScope(config, currentStory.id, {
+            cwd: root,
+            logger,
+          });
+
+    const template = await loadPromptTemplate(
+      root,
+      implementTemplateName,
+      item.kind,
+    );
     const variables = await buildPromptVariables(
       root,
       item,
       config,
       "implement",
     ); // Add phase
+    variables.current_story = `${currentStory.id}: ${currentStory.title}`;
     const prompt = renderPrompt(template, variables);
 
     // Create MCP server to capture story status updates with verification
@@ -821,9 +1973,13 @@ export async function runPhaseImplement(
     });
 
     // Load skills for implement phase (Item 033)
-    const skillResult = loadSkillsForPhase("implement", config.skills);
+    const skillResult = loadSkillsForPhase(
+      "implement",
+      config.skills,
+      stage?.allowedTools,
+    );
 
-    const agentConfig = getAgentConfigUnion(config);
+    const agentConfig = getAgentConfigUnion(config, "implement");
     const result = await runAgentUnion({
       itemId: itemId,
       config: agentConfig,
@@ -832,10 +1988,16 @@ export async function runPhaseImplement(
       logger,
       dryRun,
       mockAgent,
-      timeoutSeconds: config.timeout_seconds,
+      timeoutSeconds: getPhaseSettings(config, "implement").timeout_seconds,
       onStdoutChunk: onAgentOutput,
       onStderrChunk: onAgentOutput,
       onAgentEvent,
+      budget: options.budget,
+      stall: options.stall,
+      pathPolicy: options.pathPolicy,
+      bashPolicy: options.bashPolicy,
+      scope: liveScope?.limits,
+      fallbacks: getPhaseSettings(config, "implement").agent_fallbacks,
       // Merge wreckit MCP server with skill MCP servers (Item 033)
       mcpServers: {
         wreckit: wreckitServer,
@@ -850,9 +2012,11 @@ export async function runPhaseImplement(
     }
 
     if (!result.success) {
-      const error = result.timedOut
-        ? "Agent timed out"
-        : `Agent failed with exit code ${result.exitCode}`;
+      await resetAfterScopeViolation(result, liveScope, config, {
+        cwd: root,
+        logger,
+      });
+      const error = describeAgentFailure(result);
       item = { ...item, last_error: error };
       await saveItem(root, item);
       return { success: false, item, error };
@@ -867,7 +2031,7 @@ export async function runPhaseImplement(
 
       // Validate against scope limits
       const scopeOptions = configToOptions(storyScopeConfig);
-      const scopeResult = validateGitDiffScope(diffStats, scopeOptions, currentStory.id);
+      const scopeResult = validateStoryScope(diffStats, scopeOptions, currentStory.id);
 
       // Log warnings if approaching thresholds
       if (scopeResult.warnings.length > 0) {
@@ -930,51 +2094,400 @@ export async function runPhaseImplement(
           logger.info(
             `Story ${update.storyId} marked as '${update.status}' via MCP`,
           );
-
-          // Log verification warnings (Gap 1: Acceptance Criteria Verification)
-          if (update.verification) {
-            for (const warning of update.verification.warnings) {
-              logger.warn(warning);
-            }
-            for (const error of update.verification.errors) {
-              logger.error(`Verification error: ${error}`);
-            }
-          }
+
+          // Log verification warnings (Gap 1: Acceptance Criteria Verification)
+          if (update.verification) {
+            for (const warning of update.verification.warnings) {
+              logger.warn(warning);
+            }
+            for (const error of update.verification.errors) {
+              logger.error(`Verification error: ${error}`);
+            }
+          }
+        }
+      }
+      await writePrd(itemDir, prd);
+    }
+
+    prd = await loadPrdSafe(itemDir);
+    if (!prd) {
+      const error = "prd.json became invalid during implementation";
+      item = { ...item, last_error: error };
+      await saveItem(root, item);
+      return { success: false, item, error };
+    }
+
+    if (baseline) {
+      const blocked = await verifyBehavior(
+        itemId,
+        baseline,
+        getCompletedStoryIds(pendingBefore, prd),
+        options,
+      );
+      if (blocked) return blocked;
+    }
+
+    const progressPath = getProgressLogPath(root, item.id);
+    const timestamp = new Date().toISOString();
+    const logEntry = `[${timestamp}] Completed iteration ${iteration} for story ${currentStory.id}\n`;
+    await fs.appendFile(progressPath, logEntry, "utf-8");
+  }
+
+  if (iteration >= maxIterations && hasPendingStories(prd)) {
+    const error = `Reached max iterations (${maxIterations}) with stories still pending`;
+    item = { ...item, last_error: error };
+    await saveItem(root, item);
+    return { success: false, item, error };
+  }
+
+  return completeImplementation(itemId, pipeline, options);
+}
+
+function getPendingStoryIds(prd: Prd): string[] {
+  return prd.user_stories
+    .filter((s) => s.status === "pending")
+    .map((s) => s.id);
+}
+
+/** Stories that were pending before and are done now. */
+function getCompletedStoryIds(
+  pendingBefore: string[],
+  prd: Prd | null,
+): string[] {
+  return (prd?.user_stories ?? [])
+    .filter((s) => s.status === "done" && pendingBefore.includes(s.id))
+    .map((s) => s.id);
+}
+
+/**
+ * Record per-test results for a refactor item before its first story is
+ * implemented. An existing baseline is reused when the phase is resumed.
+ */
+async function ensureTestBaseline(
+  item: Item,
+  prd: Prd,
+  options: WorkflowOptions,
+): Promise<TestBaseline | null> {
+  const { root, config, logger } = options;
+  const itemDir = getItemDir(root, item.id);
+
+  const existing = await readTestBaseline(itemDir);
+  if (existing) {
+    return existing;
+  }
+  if (config.pr_checks.commands.length === 0) {
+    logger.warn(
+      `No pr_checks.commands configured, cannot check that refactor ${item.id} preserves behavior`,
+    );
+    return null;
+  }
+  if (prd.user_stories.some((s) => s.status === "done")) {
+    logger.warn(
+      `Stories of ${item.id} were implemented before a test baseline was recorded, skipping behavior checks`,
+    );
+    return null;
+  }
+
+  logger.info(`Recording test baseline for ${item.id}`);
+  const result = await runQualityChecks({
+    cwd: root,
+    logger,
+    checks: config.pr_checks,
+  });
+  const baseline: TestBaseline = {
+    schema_version: 1,
+    recorded_at: new Date().toISOString(),
+    commands: config.pr_checks.commands,
+    tests: result.tests ?? [],
+  };
+  await writeTestBaseline(itemDir, baseline);
+
+  const passing = baseline.tests.filter((t) => t.status === "passed").length;
+  logger.info(`Baseline: ${passing}/${baseline.tests.length} test(s) passing`);
+  return baseline;
+}
+
+/**
+ * Rerun the test commands after refactor stories were marked done. If a
+ * test that passed in the baseline now fails, the stories are put back to
+ * pending with the broken tests in their notes and the phase fails.
+ */
+async function verifyBehavior(
+  itemId: string,
+  baseline: TestBaseline,
+  storyIds: string[],
+  options: WorkflowOptions,
+): Promise<PhaseResult | null> {
+  if (storyIds.length === 0) {
+    return null;
+  }
+  const { root, config, logger } = options;
+
+  const result = await runQualityChecks({
+    cwd: root,
+    logger,
+    checks: config.pr_checks,
+  });
+  const { broken, missing } = compareWithBaseline(
+    baseline.tests,
+    result.tests ?? [],
+  );
+  if (missing.length > 0) {
+    logger.warn(
+      `${missing.length} baseline test(s) no longer reported: ${missing.join(", ")}`,
+    );
+  }
+  if (broken.length === 0) {
+    logger.info(`Behavior preserved after story ${storyIds.join(", ")}`);
+    return null;
+  }
+
+  const error = new BehaviorRegressionError(storyIds, broken);
+  logger.error(error.message);
+
+  const itemDir = getItemDir(root, itemId);
+  const prd = await loadPrdSafe(itemDir);
+  if (prd) {
+    const note = `Blocked: broke previously passing tests: ${broken.join(", ")}`;
+    for (const story of prd.user_stories) {
+      if (storyIds.includes(story.id)) {
+        story.status = "pending";
+        story.notes = [story.notes, note].filter(Boolean).join("\n");
+      }
+    }
+    await writePrd(itemDir, prd);
+  }
+
+  const item: Item = {
+    ...(await loadItem(root, itemId)),
+    last_error: error.message,
+  };
+  await saveItem(root, item);
+  return { success: false, item, error };
+}
+
+async function completeImplementation(
+  itemId: string,
+  pipeline: Pipeline,
+  options: WorkflowOptions,
+): Promise<PhaseResult> {
+  const { root, logger, onStoryChanged } = options;
+  // Clear story when implementation completes
+  onStoryChanged?.(null);
+
+  let item = await loadItem(root, itemId);
+  // Auto-transition to critique phase to trigger the adversarial gate.
+  // Pipelines without a critique stage stay in 'implementing' so the next
+  // stage's phase picks the item up.
+  const completedState: WorkflowState =
+    getNextState("implementing", pipeline) === "critique"
+      ? "critique"
+      : "implementing";
+
+  // Bug fixes must make the reproduction test pass
+  if (item.reproduction && requiresReproduction(item.kind, pipeline)) {
+    const { command } = item.reproduction;
+    // The mock agent's reproduction is a stand-in that cannot be run
+    const run: TestCommandResult = options.mockAgent
+      ? { passed: true, exitCode: 0, output: "" }
+      : await runTestCommand(command, { cwd: root, logger });
+    if (run.error) {
+      const error = `Reproduction command could not be run: ${run.error}`;
+      item = { ...item, last_error: error };
+      await saveItem(root, item);
+      return { success: false, item, error };
+    }
+    item = {
+      ...item,
+      reproduction: recordTestRun(item.reproduction, run.exitCode),
+    };
+    if (!run.passed) {
+      const error = `Reproduction test still fails: ${command}`;
+      logger.error(error);
+      if (run.output) {
+        logger.error(`Output: ${tailOutput(run.output)}`);
+      }
+      item = { ...item, last_error: error };
+      await saveItem(root, item);
+      return { success: false, item, error };
+    }
+    logger.info(`Reproduction test passes: ${command}`);
+
+    if (completedState !== item.state) {
+      const validation = validateTransition(
+        item.state,
+        completedState,
+        await buildValidationContext(root, item),
+        pipeline,
+      );
+      if (!validation.valid) {
+        const error = validation.reason ?? "Validation failed";
+        item = { ...item, last_error: error };
+        await saveItem(root, item);
+        return { success: false, item, error };
+      }
+    }
+  }
+
+  item = { ...item, state: completedState, last_error: null };
+  await saveItem(root, item);
+
+  return { success: true, item };
+}
+
+const WORKTREE_PROMPT_NOTE = `
+
+## Parallel Implementation
+
+Other stories of this item are being implemented at the same time in
+separate git worktrees. Only implement the current story, do not edit
+prd.json or progress.log, and report its status with the wreckit MCP tool.`;
+
+/**
+ * Opt-in parallel mode (`parallel_stories`): implement independent stories
+ * in separate git worktrees, each with its own agent. Pending changes are
+ * committed on the item branch first so every worktree starts from them.
+ */
+async function implementInWorktrees(
+  item: Item,
+  stage: PipelineStage | undefined,
+  templateName: string,
+  maxWorkers: number,
+  options: WorkflowOptions,
+): Promise<ParallelStoriesResult> {
+  const { root, config, logger, onAgentOutput, onAgentEvent, onStoryChanged } =
+    options;
+  const gitOptions = { cwd: root, logger };
+  const itemSlug = item.id.replace("/", "-");
+
+  const { branchName } = await ensureBranch(
+    config.base_branch,
+    config.branch_prefix,
+    itemSlug,
+    gitOptions,
+  );
+  if (await hasUncommittedChanges(gitOptions)) {
+    await commitAll(`chore(${itemSlug}): checkpoint stories`, gitOptions);
+  }
+
+  const template = await loadPromptTemplate(root, templateName, item.kind);
+  const skillResult = loadSkillsForPhase(
+    "implement",
+    config.skills,
+    stage?.allowedTools,
+  );
+  const phaseSettings = getPhaseSettings(config, "implement");
+
+  const runStory = async (
+    story: Story,
+    worktree: { path: string; itemDir: string },
+  ): Promise<StoryRunResult> => {
+    // Paths and artifacts from the worktree, so the agent edits its own copy
+    // rather than the shared root checkout
+    const variables = await buildPromptVariables(
+      worktree.path,
+      item,
+      config,
+      "implement",
+    );
+    variables.current_story = `${story.id}: ${story.title}`;
+    variables.parallel_worktree = true;
+    const prompt = renderPrompt(template, variables) + WORKTREE_PROMPT_NOTE;
+
+    // Agents may only report on their own story; the PRD is updated after
+    // the story branch has been merged
+    const prd = await loadPrdSafe(getItemDir(root, item.id));
+    let status: StoryStatus | null = null;
+    const wreckitServer = createWreckitMcpServer({
+      getPrd: () => prd,
+      onUpdateStoryStatus: (storyId, nextStatus, verification) => {
+        if (storyId !== story.id) {
+          logger.warn(
+            `Ignoring status update for ${storyId} from story ${story.id}`,
+          );
+          return;
         }
-      }
-      await writePrd(itemDir, prd);
-    }
+        status = nextStatus;
+        for (const warning of verification?.warnings ?? []) {
+          logger.warn(warning);
+        }
+        for (const error of verification?.errors ?? []) {
+          logger.error(`Verification error: ${error}`);
+        }
+      },
+    });
 
-    prd = await loadPrdSafe(itemDir);
-    if (!prd) {
-      const error = "prd.json became invalid during implementation";
-      item = { ...item, last_error: error };
-      await saveItem(root, item);
-      return { success: false, item, error };
+    const storyGitOptions = { cwd: worktree.path, logger };
+    const liveScope = await startLiveScope(config, story.id, storyGitOptions);
+    const result = await runAgentUnion({
+      itemId: item.id,
+      config: getAgentConfigUnion(config, "implement"),
+      cwd: worktree.itemDir,
+      prompt,
+      logger,
+      dryRun: false,
+      timeoutSeconds: phaseSettings.timeout_seconds,
+      onStdoutChunk: onAgentOutput,
+      onStderrChunk: onAgentOutput,
+      onAgentEvent,
+      budget: options.budget,
+      stall: options.stall,
+      pathPolicy: options.pathPolicy,
+      bashPolicy: options.bashPolicy,
+      scope: liveScope?.limits,
+      fallbacks: phaseSettings.agent_fallbacks,
+      mcpServers: {
+        wreckit: wreckitServer,
+        ...(skillResult.mcpServers || {}),
+      },
+      allowedTools: skillResult.allowedTools,
+    });
+    if (!result.success) {
+      await resetAfterScopeViolation(
+        result,
+        liveScope,
+        config,
+        storyGitOptions,
+      );
+      return {
+        success: false,
+        status: null,
+        error: describeAgentFailure(result),
+      };
     }
 
-    const progressPath = getProgressLogPath(root, item.id);
-    const timestamp = new Date().toISOString();
-    const logEntry = `[${timestamp}] Completed iteration ${iteration} for story ${currentStory.id}\n`;
-    await fs.appendFile(progressPath, logEntry, "utf-8");
-  }
-
-  if (iteration >= maxIterations && hasPendingStories(prd)) {
-    const error = `Reached max iterations (${maxIterations}) with stories still pending`;
-    item = { ...item, last_error: error };
-    await saveItem(root, item);
-    return { success: false, item, error };
-  }
-
-  // Clear story when implementation completes
-  onStoryChanged?.(null);
+    if (config.story_scope?.enabled) {
+      const diffStats = await getWorkingTreeDiffStats(storyGitOptions);
+      const scopeResult = validateStoryScope(
+        diffStats,
+        configToOptions(config.story_scope),
+        story.id,
+      );
+      for (const warning of scopeResult.warnings) {
+        logger.warn(`Story ${story.id}: ${warning}`);
+      }
+      if (!scopeResult.valid) {
+        return {
+          success: false,
+          status: null,
+          error: formatScopeViolations(scopeResult, story.id),
+        };
+      }
+    }
 
-  item = await loadItem(root, itemId);
-  // Auto-transition to critique phase to trigger the adversarial gate
-  item = { ...item, state: "critique", last_error: null };
-  await saveItem(root, item);
+    return { success: true, status };
+  };
 
-  return { success: true, item };
+  return implementStoriesInParallel({
+    root,
+    itemId: item.id,
+    itemBranch: branchName,
+    maxWorkers,
+    maxIterations: phaseSettings.max_iterations,
+    logger,
+    runStory,
+    onStoryChanged,
+  });
 }
 
 function formatPreflightErrors(errors: GitPreflightError[]): string {
@@ -1012,9 +2525,242 @@ function parsePrJson(output: string): { title: string; body: string } | null {
   }
 }
 
+export async function runPhaseDocument(
+  itemId: string,
+  options: WorkflowOptions,
+): Promise<PhaseResult> {
+  return recordPhase("document", itemId, options, documentPhase);
+}
+
+/** Longest branch diff included in the document prompt */
+const MAX_BRANCH_DIFF_CHARS = 100_000;
+
+/**
+ * Optional document phase: after implementation, the agent updates the
+ * user-facing docs and the changelog. The implementation is committed
+ * first, so the agent's edits are the only changes left in the working
+ * tree, and they are only accepted if every changed path matches the
+ * configured documentation globs.
+ */
+async function documentPhase(
+  itemId: string,
+  options: WorkflowOptions,
+): Promise<PhaseResult> {
+  const {
+    root,
+    config,
+    logger,
+    force = false,
+    dryRun = false,
+    mockAgent = false,
+    onAgentOutput,
+    onAgentEvent,
+  } = options;
+
+  let item = await loadItem(root, itemId);
+  const targetState: WorkflowState = "documented";
+  const { pipeline, stage, entryState } = resolvePhaseStage(
+    config,
+    item,
+    "document",
+  );
+  if (entryState === null) {
+    return {
+      success: false,
+      item,
+      error: `Pipeline '${pipeline.name}' has no document stage`,
+    };
+  }
+
+  if (!force && item.documentation) {
+    logger.info(`Documentation already updated for ${itemId}, skipping`);
+    if (item.state === entryState) {
+      item = { ...item, state: targetState };
+      await saveItem(root, item);
+    }
+    return { success: true, item };
+  }
+
+  if (item.state !== entryState && !force) {
+    return {
+      success: false,
+      item,
+      error: `Item is in state ${item.state}, expected '${entryState}' for document phase`,
+    };
+  }
+
+  if (force && item.state !== entryState) {
+    item = { ...item, state: entryState };
+  }
+
+  const itemDir = getItemDir(root, item.id);
+  const prd = await loadPrdSafe(itemDir);
+  if (!allStoriesDone(prd)) {
+    const error = "Not all stories are done";
+    item = { ...item, last_error: error };
+    await saveItem(root, item);
+    return { success: false, item, error };
+  }
+
+  const docPaths = config.document?.paths ?? DEFAULT_DOCUMENTATION_PATHS;
+  const gitOptions = { cwd: root, logger, dryRun };
+  let branchDiff = "";
+  if (!dryRun && !mockAgent) {
+    const itemSlug = item.id.replace("/", "-");
+    await ensureBranch(
+      config.base_branch,
+      config.branch_prefix,
+      itemSlug,
+      gitOptions,
+    );
+    if (await hasUncommittedChanges(gitOptions)) {
+      await commitAll(
+        `chore(${itemSlug}): checkpoint implementation`,
+        gitOptions,
+      );
+    }
+    branchDiff = await getBranchDiff(config.base_branch, gitOptions);
+    if (branchDiff.length > MAX_BRANCH_DIFF_CHARS) {
+      branchDiff = `${branchDiff.slice(0, MAX_BRANCH_DIFF_CHARS)}\n... (diff truncated)`;
+    }
+  }
+
+  const template = await loadPromptTemplate(
+    root,
+    stage?.prompt ?? "document",
+    item.kind,
+  );
+  const variables: PromptVariables = {
+    ...(await buildPromptVariables(root, item, config, "document")),
+    branch_diff: branchDiff,
+    doc_paths: docPaths.map((pattern) => `- \`${pattern}\``).join("\n"),
+  };
+  const agentConfig = getAgentConfigUnion(config, "document");
+  const phaseSettings = getPhaseSettings(config, "document");
+  const skillResult = loadSkillsForPhase(
+    "document",
+    config.skills,
+    stage?.allowedTools,
+  );
+
+  const maxAttempts = phaseSettings.max_iterations;
+  let feedback: string | null = null;
+  let lastError: string | null = null;
+
+  for (let attempt = 1; attempt <= maxAttempts; attempt++) {
+    if (attempt > 1) {
+      logger.warn(
+        `Documentation rejected (attempt ${attempt - 1}/${maxAttempts}). Retrying...`,
+      );
+    }
+
+    let prompt = renderPrompt(template, variables);
+    if (feedback) {
+      prompt += `\n\nCRITICAL: Your previous attempt was rejected:\n${feedback}\n\nYou MUST fix this in this attempt.`;
+    }
+
+    const result = await runAgentUnion({
+      itemId,
+      config: agentConfig,
+      cwd: root,
+      prompt,
+      logger,
+      dryRun,
+      mockAgent,
+      timeoutSeconds: phaseSettings.timeout_seconds,
+      onStdoutChunk: onAgentOutput,
+      onStderrChunk: onAgentOutput,
+      onAgentEvent,
+      budget: options.budget,
+      stall: options.stall,
+      pathPolicy: options.pathPolicy,
+      bashPolicy: options.bashPolicy,
+      fallbacks: phaseSettings.agent_fallbacks,
+      mcpServers: {
+        ...(skillResult.mcpServers || {}),
+      },
+      allowedTools: skillResult.allowedTools,
+    });
+
+    if (dryRun) {
+      return { success: true, item };
+    }
+
+    if (mockAgent) {
+      item = {
+        ...item,
+        state: targetState,
+        documentation: {
+          changed_files: [],
+          documented_at: new Date().toISOString(),
+        },
+        last_error: null,
+      };
+      await saveItem(root, item);
+      return { success: true, item };
+    }
+
+    if (!result.success) {
+      lastError = describeAgentFailure(result);
+      break;
+    }
+
+    // Renames are reported as "old -> new"; both sides must be docs
+    const changedFiles = (
+      await getGitStatus({ cwd: root, logger, untrackedFiles: "all" })
+    ).flatMap((change) => change.path.split(" -> "));
+    const outside = findNonDocumentationPaths(changedFiles, docPaths);
+    if (outside.length > 0) {
+      lastError = `Document phase changed files outside the documentation paths: ${outside.join(", ")}`;
+      feedback = `You changed files that are not documentation: ${outside.join(", ")}. Revert those changes and only edit files matching:\n${variables.doc_paths}`;
+      continue;
+    }
+
+    const documentation = {
+      changed_files: changedFiles.filter(
+        (file) => !file.startsWith(".wreckit/"),
+      ),
+      documented_at: new Date().toISOString(),
+    };
+    const validation = validateTransition(
+      item.state,
+      targetState,
+      await buildValidationContext(root, { ...item, documentation }),
+      pipeline,
+    );
+    if (!validation.valid) {
+      lastError = validation.reason ?? "Validation failed";
+      break;
+    }
+
+    if (documentation.changed_files.length === 0) {
+      logger.warn(`No documentation changes for ${itemId}`);
+    } else {
+      logger.info(
+        `Updated ${documentation.changed_files.length} documentation file(s) for ${itemId}`,
+      );
+    }
+    item = { ...item, state: targetState, documentation, last_error: null };
+    await saveItem(root, item);
+    return { success: true, item };
+  }
+
+  const finalError = lastError ?? "Document phase failed after max attempts";
+  item = { ...item, last_error: finalError };
+  await saveItem(root, item);
+  return { success: false, item, error: finalError };
+}
+
 export async function runPhasePr(
   itemId: string,
   options: WorkflowOptions,
+): Promise<PhaseResult> {
+  return recordPhase("pr", itemId, options, prPhase);
+}
+
+async function prPhase(
+  itemId: string,
+  options: WorkflowOptions,
 ): Promise<PhaseResult> {
   const {
     root,
@@ -1029,12 +2775,17 @@ export async function runPhasePr(
 
   let item = await loadItem(root, itemId);
   const itemDir = getItemDir(root, item.id);
+  const { stage: prStage, entryState } = resolvePhaseStage(config, item, "pr");
+  const prEntryState = entryState ?? "critique";
 
-  if (item.state !== "critique" && !force) {
+  if (item.state !== prEntryState && !force) {
     return {
       success: false,
       item,
-      error: `Item is in state ${item.state}, expected 'critique' for PR phase (Adversarial Gate)`,
+      error:
+        prEntryState === "critique"
+          ? `Item is in state ${item.state}, expected 'critique' for PR phase (Adversarial Gate)`
+          : `Item is in state ${item.state}, expected '${prEntryState}' for PR phase`,
     };
   }
 
@@ -1375,14 +3126,22 @@ export async function runPhasePr(
 
   if (!dryRun) {
     try {
-      const template = await loadPromptTemplate(root, "pr");
+      const template = await loadPromptTemplate(
+        root,
+        prStage?.prompt ?? "pr",
+        item.kind,
+      );
       const variables = await buildPromptVariables(root, item, config, "pr"); // Add phase
       const prompt = renderPrompt(template, variables);
 
       // Load skills for PR phase (Item 033)
-      const skillResult = loadSkillsForPhase("pr", config.skills);
+      const skillResult = loadSkillsForPhase(
+        "pr",
+        config.skills,
+        prStage?.allowedTools,
+      );
 
-      const agentConfig = getAgentConfigUnion(config);
+      const agentConfig = getAgentConfigUnion(config, "pr");
       const result = await runAgentUnion({
         itemId: itemId,
         config: agentConfig,
@@ -1391,10 +3150,15 @@ export async function runPhasePr(
         logger,
         dryRun: false,
         mockAgent,
-        timeoutSeconds: config.timeout_seconds,
+        timeoutSeconds: getPhaseSettings(config, "pr").timeout_seconds,
         onStdoutChunk: onAgentOutput,
         onStderrChunk: onAgentOutput,
         onAgentEvent,
+        budget: options.budget,
+        stall: options.stall,
+        pathPolicy: options.pathPolicy,
+        bashPolicy: options.bashPolicy,
+        fallbacks: getPhaseSettings(config, "pr").agent_fallbacks,
         // Merge skill MCP servers (Item 033)
         mcpServers: {
           ...(skillResult.mcpServers || {}),
@@ -1504,11 +3268,23 @@ export async function runPhasePr(
 export async function runPhaseComplete(
   itemId: string,
   options: WorkflowOptions,
+): Promise<PhaseResult> {
+  return recordPhase("complete", itemId, options, completePhase);
+}
+
+async function completePhase(
+  itemId: string,
+  options: WorkflowOptions,
 ): Promise<PhaseResult> {
   const { root, config, logger, dryRun = false } = options;
 
   let item = await loadItem(root, itemId);
 
+  const { pipeline, entryState } = resolvePhaseStage(config, item, "complete");
+  if (requiresReport(item.kind, pipeline)) {
+    return completeSpike(item, pipeline, entryState, options);
+  }
+
   if (item.state !== "in_pr") {
     return {
       success: false,
@@ -1644,43 +3420,88 @@ export async function runPhaseComplete(
   return { success: true, item };
 }
 
+/**
+ * Finish a spike once its report exists. Spikes never get a branch or PR,
+ * so there is nothing to merge or clean up.
+ */
+async function completeSpike(
+  item: Item,
+  pipeline: Pipeline,
+  entryState: WorkflowState | null,
+  options: WorkflowOptions,
+): Promise<PhaseResult> {
+  const { root, logger, dryRun = false } = options;
+
+  if (item.state !== entryState) {
+    return {
+      success: false,
+      item,
+      error: `Item is in state ${item.state}, expected '${entryState}' for complete phase`,
+    };
+  }
+
+  if (dryRun) {
+    logger.info(`[dry-run] Would complete spike ${item.id}`);
+    return { success: true, item };
+  }
+
+  const validation = validateTransition(
+    item.state,
+    "done",
+    await buildValidationContext(root, item),
+    pipeline,
+  );
+  if (!validation.valid) {
+    const error = validation.reason ?? "Validation failed";
+    item = { ...item, last_error: error };
+    await saveItem(root, item);
+    return { success: false, item, error };
+  }
+
+  const completedAt = new Date().toISOString();
+  item = {
+    ...item,
+    state: "done",
+    last_error: null,
+    completed_at: completedAt,
+  };
+  await saveItem(root, item);
+
+  await fs.appendFile(
+    getProgressLogPath(root, item.id),
+    `[${completedAt}] Completed spike: report at report.md\n`,
+    "utf-8",
+  );
+  logger.info(
+    `Completed spike ${item.id}; see ${getReportPath(root, item.id)} (follow-ups: wreckit spike-followups ${item.id})`,
+  );
+
+  return { success: true, item };
+}
+
 /**
  * Determines the next phase to execute based on an item's current state.
  *
- * This function encodes the workflow progression from states to phases. The mapping is:
- * - raw → research
+ * The mapping comes from the item's pipeline: the next phase is the phase of the
+ * stage after the item's current state. For the built-in pipeline:
+ * - idea → research
  * - researched → plan
  * - planned → implement
- * - implementing → pr
+ * - implementing → critique
+ * - critique → pr
  * - in_pr → complete
  * - done → null (terminal)
  *
- * IMPORTANT: This logic must stay synchronized with:
- * - src/domain/states.ts:3-10 (WORKFLOW_STATES array - defines state ordering)
- * - src/commands/phase.ts:26-65 (PHASE_CONFIG - defines phases and their target states)
+ * Spike items (built-in "spike" pipeline) go straight from researched to
+ * complete, which finishes them with their report instead of a PR.
  *
  * @param item - The item to evaluate
+ * @param pipeline - The item's pipeline (defaults to the built-in pipeline)
  * @returns The next phase name, or null if the workflow is complete
  */
 export function getNextPhase(
   item: Item,
-): "research" | "plan" | "implement" | "critique" | "pr" | "complete" | null {
-  switch (item.state) {
-    case "idea":
-      return "research";
-    case "researched":
-      return "plan";
-    case "planned":
-      return "implement";
-    case "implementing":
-      return "critique";
-    case "critique":
-      return "pr";
-    case "in_pr":
-      return "complete";
-    case "done":
-      return null;
-    default:
-      return null;
-  }
+  pipeline: Pipeline = DEFAULT_PIPELINE,
+): PhaseName | null {
+  return getNextPhaseInPipeline(pipeline, item.state);
 }
@@ -0,0 +1,285 @@
+import * as fs from "node:fs/promises";
+import * as os from "node:os";
+import * as path from "node:path";
+import type { Prd, Story, StoryStatus } from "../schemas";
+import type { Logger } from "../logging";
+import { StoryMergeConflictError } from "../errors";
+import { hasPendingStories, isStoryReady } from "../domain/validation";
+import { readPrd, writePrd } from "../fs/json";
+import { getItemDir, getPrdPath, getProgressLogPath } from "../fs/paths";
+import {
+  addWorktree,
+  cleanupBranch,
+  commitAll,
+  mergeBranch,
+  removeWorktree,
+} from "../git";
+import { runWorkerPool } from "./workerPool";
+
+export interface StoryRunResult {
+  success: boolean;
+  error?: string;
+  /** Status the agent reported for its story, if any */
+  status: StoryStatus | null;
+}
+
+export interface ParallelStoriesOptions {
+  root: string;
+  itemId: string;
+  /** Item branch checked out at `root`; story branches start from it */
+  itemBranch: string;
+  maxWorkers: number;
+  maxIterations: number;
+  logger: Logger;
+  /**
+   * Implement one story inside its worktree. `itemDir` is the item's
+   * directory within the worktree.
+   */
+  runStory: (
+    story: Story,
+    worktree: { path: string; itemDir: string },
+  ) => Promise<StoryRunResult>;
+  onStoryChanged?: (story: { id: string; title: string } | null) => void;
+}
+
+export interface ParallelStoriesResult {
+  success: boolean;
+  iterations: number;
+  error?: string;
+  /** Set when a story branch could not be merged into the item branch */
+  conflict?: StoryMergeConflictError;
+}
+
+export function getStoryBranchName(itemBranch: string, storyId: string) {
+  return `${itemBranch}-${storyId.toLowerCase()}`;
+}
+
+interface Bookkeeping {
+  prd: string | null;
+  progress: string | null;
+}
+
+async function readOptional(file: string): Promise<string | null> {
+  return fs.readFile(file, "utf-8").catch(() => null);
+}
+
+async function restoreFile(file: string, content: string | null) {
+  if (content === null) {
+    await fs.rm(file, { force: true });
+  } else {
+    await fs.writeFile(file, content, "utf-8");
+  }
+}
+
+async function readBookkeeping(
+  root: string,
+  itemId: string,
+): Promise<Bookkeeping> {
+  return {
+    prd: await readOptional(getPrdPath(root, itemId)),
+    progress: await readOptional(getProgressLogPath(root, itemId)),
+  };
+}
+
+/**
+ * Undo a story agent's edits to prd.json and progress.log in its worktree
+ * so story branches never touch the item's bookkeeping. Returns the notes
+ * the agent appended to progress.log, for the root to record on merge.
+ */
+async function takeBookkeeping(
+  worktreePath: string,
+  itemId: string,
+  before: Bookkeeping,
+  storyId: string,
+  logger: Logger,
+): Promise<string> {
+  const progressPath = getProgressLogPath(worktreePath, itemId);
+  const progress = await readOptional(progressPath);
+  let notes = "";
+  if (progress !== null && progress !== before.progress) {
+    const base = before.progress ?? "";
+    if (progress.startsWith(base)) {
+      notes = progress.slice(base.length);
+    } else {
+      logger.warn(`Story ${storyId} rewrote progress.log; dropping its edits`);
+    }
+  }
+
+  await restoreFile(progressPath, before.progress);
+  await restoreFile(getPrdPath(worktreePath, itemId), before.prd);
+  return notes;
+}
+
+/**
+ * Implement pending PRD stories in parallel, each in its own git worktree
+ * on a story branch. Finished stories are committed, merged back into the
+ * item branch one at a time, and only then marked done in the item's PRD.
+ * Story branches never carry prd.json or progress.log edits: notes an agent
+ * appends to progress.log are recorded by the root after the merge, and the
+ * bookkeeping is committed on the item branch before the next merge.
+ *
+ * Scheduling stops at the first failure; stories already running finish
+ * and are merged. A merge conflict keeps the story branch for manual
+ * resolution and is reported as `conflict`; other story branches are
+ * deleted once their worktree is removed.
+ */
+export async function implementStoriesInParallel(
+  options: ParallelStoriesOptions,
+): Promise<ParallelStoriesResult> {
+  const { root, itemId, itemBranch, logger, runStory, onStoryChanged } =
+    options;
+  const itemDir = getItemDir(root, itemId);
+  const relativeItemDir = path.relative(root, itemDir);
+  const gitOptions = { cwd: root, logger };
+
+  let prd: Prd = await readPrd(itemDir);
+  let iterations = 0;
+  let failure: Omit<ParallelStoriesResult, "success" | "iterations"> | null =
+    null;
+
+  // Merges and PRD updates touch the root checkout, so run them one by one
+  let rootQueue: Promise<unknown> = Promise.resolve();
+  const withRoot = <T>(fn: () => Promise<T>): Promise<T> => {
+    const next = rootQueue.then(fn);
+    rootQueue = next.catch(() => {});
+    return next;
+  };
+
+  const implementStory = async (story: Story): Promise<void> => {
+    iterations++;
+    const storyBranch = getStoryBranchName(itemBranch, story.id);
+    // Worktrees live outside the repository so agents cannot see each other
+    const tempDir = await fs.mkdtemp(
+      path.join(os.tmpdir(), `wreckit-${story.id.toLowerCase()}-`),
+    );
+    const worktreePath = path.join(tempDir, "worktree");
+
+    onStoryChanged?.({ id: story.id, title: story.title });
+    logger.info(`Implementing story ${story.id} in worktree ${worktreePath}`);
+
+    let keepBranch = false;
+    try {
+      await withRoot(() =>
+        addWorktree(worktreePath, storyBranch, itemBranch, gitOptions),
+      );
+      const bookkeeping = await readBookkeeping(worktreePath, itemId);
+
+      const result = await runStory(story, {
+        path: worktreePath,
+        itemDir: path.join(worktreePath, relativeItemDir),
+      });
+      if (!result.success) {
+        failure ??= { error: `Story ${story.id}: ${result.error}` };
+        return;
+      }
+
+      const notes = await takeBookkeeping(
+        worktreePath,
+        itemId,
+        bookkeeping,
+        story.id,
+        logger,
+      );
+      await commitAll(`${story.id}: ${story.title}`, {
+        cwd: worktreePath,
+        logger,
+      });
+
+      await withRoot(async () => {
+        const merge = await mergeBranch(
+          storyBranch,
+          `Merge ${story.id}: ${story.title}`,
+          gitOptions,
+        );
+        if (!merge.merged) {
+          const conflict = new StoryMergeConflictError(
+            story.id,
+            storyBranch,
+            itemBranch,
+            merge.conflictedFiles,
+          );
+          failure ??= { error: conflict.message, conflict };
+          keepBranch = true;
+          return;
+        }
+
+        if (result.status) {
+          prd = await readPrd(itemDir);
+          const current = prd.user_stories.find((s) => s.id === story.id);
+          if (current) {
+            current.status = result.status;
+            await writePrd(itemDir, prd);
+            logger.info(`Story ${story.id} marked as '${result.status}'`);
+          }
+        }
+
+        const timestamp = new Date().toISOString();
+        const notesEntry =
+          notes && !notes.endsWith("\n") ? `${notes}\n` : notes;
+        await fs.appendFile(
+          getProgressLogPath(root, itemId),
+          `${notesEntry}[${timestamp}] Merged story ${story.id} from ${storyBranch}\n`,
+          "utf-8",
+        );
+        // Leave the root clean so the next story branch can merge
+        await commitAll(`Record ${story.id} progress`, gitOptions);
+      });
+    } catch (err) {
+      const message = err instanceof Error ? err.message : String(err);
+      failure ??= { error: `Story ${story.id}: ${message}` };
+    } finally {
+      await withRoot(() => removeWorktree(worktreePath, gitOptions));
+      await fs.rm(tempDir, { recursive: true, force: true });
+      if (!keepBranch) {
+        await withRoot(() =>
+          cleanupBranch(storyBranch, itemBranch, {
+            ...gitOptions,
+            deleteRemote: false,
+          }),
+        );
+      }
+    }
+  };
+
+  while (
+    hasPendingStories(prd) &&
+    !failure &&
+    iterations < options.maxIterations
+  ) {
+    const startedBefore = iterations;
+    await runWorkerPool(
+      prd.user_stories.filter((s) => s.status === "pending"),
+      {
+        concurrency: options.maxWorkers,
+        isReady: (story) =>
+          !failure &&
+          iterations < options.maxIterations &&
+          isStoryReady(prd, story),
+        run: implementStory,
+      },
+    );
+    prd = await readPrd(itemDir);
+
+    if (iterations === startedBefore) {
+      const blocked = prd.user_stories
+        .filter((s) => s.status === "pending")
+        .map((s) => s.id);
+      failure ??= {
+        error: `No pending story has all its dependencies done (pending: ${blocked.join(", ")})`,
+      };
+    }
+  }
+  onStoryChanged?.(null);
+
+  if (failure) {
+    return { success: false, iterations, ...failure };
+  }
+  if (hasPendingStories(prd)) {
+    return {
+      success: false,
+      iterations,
+      error: `Reached max iterations (${options.maxIterations}) with stories still pending`,
+    };
+  }
+  return { success: true, iterations };
+}
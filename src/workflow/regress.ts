@@ -0,0 +1,209 @@
+import * as fs from "node:fs/promises";
+import * as path from "node:path";
+import type { Item, WorkflowState } from "../schemas";
+import type { ConfigResolved } from "../config";
+import type { Logger } from "../logging";
+import { TransitionError } from "../errors";
+import {
+  getItemDir,
+  getItemArchiveDir,
+  getProgressLogPath,
+} from "../fs/paths";
+import { pathExists } from "../fs/util";
+import { readItem, writeItem, readPrd, writePrd } from "../fs/json";
+import { appendHistory } from "../fs/history";
+import { applyRegression } from "../domain/transitions";
+import { validateRegression } from "../domain/validation";
+import { getItemPipeline, type Pipeline } from "../domain/pipeline";
+
+/**
+ * Artifacts produced when an item enters each state.
+ * Regressing past a state supersedes its artifacts.
+ */
+export const STATE_ARTIFACTS: Partial<Record<WorkflowState, string[]>> = {
+  researched: ["research.md", "report.md"],
+  planned: ["plan.md", "prd.json"],
+};
+
+export interface RegressOptions {
+  root: string;
+  config: ConfigResolved;
+  logger: Logger;
+  to: WorkflowState;
+  reason: string;
+  /** "human" for CLI reopen, or the phase that triggered the regression */
+  actor?: string;
+  /** Value for last_error after the regression (defaults to null) */
+  lastError?: string | null;
+  dryRun?: boolean;
+}
+
+export interface RegressResult {
+  item: Item;
+  /** Item-relative archive directory, or null if nothing was archived */
+  archiveDir: string | null;
+  archivedFiles: string[];
+  /** Number of stories reset to pending because implementation was superseded */
+  storiesReset: number;
+}
+
+/**
+ * States whose work is undone by moving from `from` back to `to`.
+ */
+export function getSupersededStates(
+  pipeline: Pipeline,
+  from: WorkflowState,
+  to: WorkflowState,
+): WorkflowState[] {
+  const states = pipeline.stages.map((stage) => stage.state);
+  const fromIndex = states.indexOf(from);
+  const toIndex = states.indexOf(to);
+  if (fromIndex === -1 || toIndex === -1 || toIndex >= fromIndex) {
+    return [];
+  }
+  return states.slice(toIndex + 1, fromIndex + 1);
+}
+
+function archiveTimestamp(iso: string): string {
+  return iso.replace(/[:.]/g, "-");
+}
+
+/**
+ * Send an item back to an earlier state.
+ *
+ * Artifacts of superseded states are moved into archive/<timestamp>-<from>-to-<to>/
+ * inside the item directory. If implementation is superseded but the plan is kept,
+ * prd.json is archived and its stories are reset to pending so the next implement
+ * run starts over.
+ *
+ * @throws TransitionError if the target is not an earlier state in the item's pipeline
+ */
+export async function regressItem(
+  itemId: string,
+  options: RegressOptions,
+): Promise<RegressResult> {
+  const {
+    root,
+    config,
+    logger,
+    to,
+    reason,
+    actor = "human",
+    lastError = null,
+    dryRun = false,
+  } = options;
+
+  const itemDir = getItemDir(root, itemId);
+  const item = await readItem(itemDir);
+  const pipeline = getItemPipeline(item, config);
+
+  const validation = validateRegression(item.state, to, pipeline);
+  if (!validation.valid) {
+    throw new TransitionError(
+      item.state,
+      to,
+      `Cannot reopen ${itemId}: ${validation.reason}`,
+    );
+  }
+
+  const superseded = getSupersededStates(pipeline, item.state, to);
+  const now = new Date().toISOString();
+  const archiveName = `${archiveTimestamp(now)}-${item.state}-to-${to}`;
+  const archivePath = path.join(getItemArchiveDir(root, itemId), archiveName);
+
+  const toArchive: string[] = [];
+  for (const state of superseded) {
+    for (const file of STATE_ARTIFACTS[state] ?? []) {
+      if (await pathExists(path.join(itemDir, file))) {
+        toArchive.push(file);
+      }
+    }
+  }
+
+  const resetStories =
+    superseded.includes("implementing") &&
+    !toArchive.includes("prd.json") &&
+    (await pathExists(path.join(itemDir, "prd.json")));
+
+  if (dryRun) {
+    logger.info(`[dry-run] Would reopen ${itemId}: ${item.state} → ${to}`);
+    for (const file of toArchive) {
+      logger.info(`[dry-run] Would archive ${file}`);
+    }
+    if (resetStories) {
+      logger.info("[dry-run] Would reset all stories to pending");
+    }
+    return {
+      item,
+      archiveDir: null,
+      archivedFiles: [],
+      storiesReset: 0,
+    };
+  }
+
+  const archivedFiles: string[] = [];
+  let storiesReset = 0;
+
+  if (toArchive.length > 0 || resetStories) {
+    await fs.mkdir(archivePath, { recursive: true });
+  }
+
+  for (const file of toArchive) {
+    await fs.rename(path.join(itemDir, file), path.join(archivePath, file));
+    archivedFiles.push(file);
+  }
+
+  if (resetStories) {
+    const prd = await readPrd(itemDir);
+    await fs.copyFile(
+      path.join(itemDir, "prd.json"),
+      path.join(archivePath, "prd.json"),
+    );
+    archivedFiles.push("prd.json");
+    storiesReset = prd.user_stories.filter((s) => s.status !== "pending").length;
+    await writePrd(itemDir, {
+      ...prd,
+      user_stories: prd.user_stories.map((story) => ({
+        ...story,
+        status: "pending",
+      })),
+    });
+  }
+
+  const archiveDir =
+    archivedFiles.length > 0 ? path.join("archive", archiveName) : null;
+
+  const transition = applyRegression(item, to, {
+    reason,
+    actor,
+    pipeline,
+    archiveDir,
+    lastError,
+    now,
+  });
+  if (!transition.nextItem) {
+    throw new TransitionError(
+      item.state,
+      to,
+      transition.error ?? "Regression failed",
+    );
+  }
+
+  await writeItem(itemDir, transition.nextItem);
+  await appendHistory(root, itemId, transition.history);
+
+  const progressPath = getProgressLogPath(root, itemId);
+  const logEntry =
+    `\n[${now}] REOPENED (${actor}): ${item.state} → ${to}\nReason: ${reason}\n` +
+    (archiveDir ? `Archived: ${archivedFiles.join(", ")} → ${archiveDir}\n` : "");
+  await fs.appendFile(progressPath, logEntry, "utf-8");
+
+  logger.info(`Reopened ${itemId}: ${item.state} → ${to}`);
+
+  return {
+    item: transition.nextItem,
+    archiveDir,
+    archivedFiles,
+    storiesReset,
+  };
+}
@@ -0,0 +1,56 @@
+export interface WorkerPoolOptions<T> {
+  /** Maximum number of items run at once */
+  concurrency: number;
+  /** Whether an item can start now (e.g. its dependencies are done) */
+  isReady: (item: T) => boolean;
+  /** Process one item; errors should be handled by the caller */
+  run: (item: T) => Promise<void>;
+}
+
+/**
+ * Run items through a fixed number of workers, starting each item once it
+ * is ready. Workers wake up whenever a running item finishes, and stop when
+ * the queue is empty or nothing is running that could unblock the rest.
+ *
+ * @returns Items that never became ready, in their original order
+ */
+export async function runWorkerPool<T>(
+  items: T[],
+  options: WorkerPoolOptions<T>,
+): Promise<T[]> {
+  const { isReady, run } = options;
+  const queue = [...items];
+  let active = 0;
+
+  let wake: () => void = () => {};
+  let changed = new Promise<void>((resolve) => (wake = resolve));
+
+  const worker = async (): Promise<void> => {
+    while (true) {
+      const index = queue.findIndex((item) => isReady(item));
+      if (index === -1) {
+        if (queue.length === 0 || active === 0) return;
+        await changed;
+        continue;
+      }
+
+      const [item] = queue.splice(index, 1);
+      active++;
+      try {
+        await run(item);
+      } finally {
+        active--;
+        const notify = wake;
+        changed = new Promise<void>((resolve) => (wake = resolve));
+        notify();
+      }
+    }
+  };
+
+  const workers = Array.from(
+    { length: Math.max(1, options.concurrency) },
+    () => worker(),
+  );
+  await Promise.all(workers);
+  return queue;
+}